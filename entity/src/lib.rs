@@ -0,0 +1,20 @@
+pub mod prelude;
+
+pub mod account_aliases;
+pub mod account_endorsements;
+pub mod audit_logs;
+pub mod blocklist_subscriptions;
+pub mod credentials;
+pub mod email_verifications;
+pub mod federation_domains;
+pub mod ip_blocks;
+pub mod legal_acceptances;
+pub mod legal_documents;
+pub mod notification_preferences;
+pub mod posting_preferences;
+pub mod refresh_tokens;
+pub mod report_notes;
+pub mod report_rules;
+pub mod reports;
+pub mod rules;
+pub mod users;
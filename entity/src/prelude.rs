@@ -0,0 +1,18 @@
+pub use super::account_aliases::Entity as AccountAliases;
+pub use super::account_endorsements::Entity as AccountEndorsements;
+pub use super::audit_logs::Entity as AuditLogs;
+pub use super::blocklist_subscriptions::Entity as BlocklistSubscriptions;
+pub use super::credentials::Entity as Credentials;
+pub use super::email_verifications::Entity as EmailVerifications;
+pub use super::federation_domains::Entity as FederationDomains;
+pub use super::ip_blocks::Entity as IpBlocks;
+pub use super::legal_acceptances::Entity as LegalAcceptances;
+pub use super::legal_documents::Entity as LegalDocuments;
+pub use super::notification_preferences::Entity as NotificationPreferences;
+pub use super::posting_preferences::Entity as PostingPreferences;
+pub use super::refresh_tokens::Entity as RefreshTokens;
+pub use super::report_notes::Entity as ReportNotes;
+pub use super::report_rules::Entity as ReportRules;
+pub use super::reports::Entity as Reports;
+pub use super::rules::Entity as Rules;
+pub use super::users::Entity as Users;
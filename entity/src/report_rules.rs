@@ -0,0 +1,45 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "report_rules")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub report_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub rule_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::reports::Entity",
+        from = "Column::ReportId",
+        to = "super::reports::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Reports,
+    #[sea_orm(
+        belongs_to = "super::rules::Entity",
+        from = "Column::RuleId",
+        to = "super::rules::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Rules,
+}
+
+impl Related<super::reports::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Reports.def()
+    }
+}
+
+impl Related<super::rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Rules.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "reports")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub reporter_id: Uuid,
+    pub target_account_id: Uuid,
+    pub category: String,
+    #[sea_orm(column_type = "Text")]
+    pub comment: String,
+    pub assigned_account_id: Option<Uuid>,
+    pub action_taken_at: Option<DateTimeWithTimeZone>,
+    pub action_taken_by_id: Option<Uuid>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::report_notes::Entity")]
+    ReportNotes,
+    #[sea_orm(has_many = "super::report_rules::Entity")]
+    ReportRules,
+}
+
+impl Related<super::report_notes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReportNotes.def()
+    }
+}
+
+impl Related<super::report_rules::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReportRules.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,53 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "users")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(unique)]
+    pub activity_id: String,
+    /// Lowercased username of local accounts, None for remote ones
+    #[sea_orm(unique)]
+    pub username: Option<String>,
+    pub name: String,
+    pub summary: String,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub icon: Option<Json>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub header: Option<Json>,
+    pub role: String,
+    pub disabled: bool,
+    pub silenced_at: Option<DateTimeWithTimeZone>,
+    pub suspended_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    /// Set when the account is deleted, the row stays as a tombstone until purged
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+    /// Deleted by the owner, who may reactivate it until it is purged
+    pub self_deleted: bool,
+    /// Set when an admin vouches for the account (verified badge)
+    pub verified_at: Option<DateTimeWithTimeZone>,
+    /// Accent color of the profile, `#rrggbb`
+    pub theme_color: Option<String>,
+    pub birthday: Option<Date>,
+    /// `hidden`, `month_day` or `full`
+    pub birthday_visibility: String,
+    pub location: Option<String>,
+    pub location_visible: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_one = "super::credentials::Entity")]
+    Credentials,
+}
+
+impl Related<super::credentials::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Credentials.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
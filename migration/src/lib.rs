@@ -0,0 +1,60 @@
+pub use sea_orm_migration::prelude::*;
+
+mod m20261015_000001_create_users;
+mod m20261015_000002_create_credentials;
+mod m20261015_000003_add_role_to_users;
+mod m20261015_000004_create_reports;
+mod m20261015_000005_add_moderation_to_users;
+mod m20261015_000006_create_audit_logs;
+mod m20261015_000007_create_rules;
+mod m20261015_000008_create_ip_blocks;
+mod m20261015_000009_create_notification_preferences;
+mod m20261015_000010_create_legal_documents;
+mod m20261015_000011_add_username_to_users;
+mod m20261015_000012_add_profile_to_users;
+mod m20261015_000013_add_deleted_at_to_users;
+mod m20261015_000014_create_account_aliases;
+mod m20261015_000015_create_posting_preferences;
+mod m20261015_000016_create_federation_domains;
+mod m20261015_000017_add_users_theme_color;
+mod m20261015_000018_add_users_profile_details;
+mod m20261015_000019_add_users_self_deleted;
+mod m20261015_000020_create_account_endorsements;
+mod m20261015_000021_add_users_verified_at;
+mod m20261015_000022_create_refresh_tokens;
+mod m20261015_000023_create_email_verifications;
+mod m20261015_000024_create_blocklist_subscriptions;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20261015_000001_create_users::Migration),
+            Box::new(m20261015_000002_create_credentials::Migration),
+            Box::new(m20261015_000003_add_role_to_users::Migration),
+            Box::new(m20261015_000004_create_reports::Migration),
+            Box::new(m20261015_000005_add_moderation_to_users::Migration),
+            Box::new(m20261015_000006_create_audit_logs::Migration),
+            Box::new(m20261015_000007_create_rules::Migration),
+            Box::new(m20261015_000008_create_ip_blocks::Migration),
+            Box::new(m20261015_000009_create_notification_preferences::Migration),
+            Box::new(m20261015_000010_create_legal_documents::Migration),
+            Box::new(m20261015_000011_add_username_to_users::Migration),
+            Box::new(m20261015_000012_add_profile_to_users::Migration),
+            Box::new(m20261015_000013_add_deleted_at_to_users::Migration),
+            Box::new(m20261015_000014_create_account_aliases::Migration),
+            Box::new(m20261015_000015_create_posting_preferences::Migration),
+            Box::new(m20261015_000016_create_federation_domains::Migration),
+            Box::new(m20261015_000017_add_users_theme_color::Migration),
+            Box::new(m20261015_000018_add_users_profile_details::Migration),
+            Box::new(m20261015_000019_add_users_self_deleted::Migration),
+            Box::new(m20261015_000020_create_account_endorsements::Migration),
+            Box::new(m20261015_000021_add_users_verified_at::Migration),
+            Box::new(m20261015_000022_create_refresh_tokens::Migration),
+            Box::new(m20261015_000023_create_email_verifications::Migration),
+            Box::new(m20261015_000024_create_blocklist_subscriptions::Migration),
+        ]
+    }
+}
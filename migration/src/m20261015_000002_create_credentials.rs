@@ -0,0 +1,50 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Credentials::Table)
+                    .if_not_exists()
+                    .col(uuid(Credentials::UserId).primary_key())
+                    .col(string_uniq(Credentials::ActivityId))
+                    .col(string(Credentials::PasswordHash))
+                    .col(string_uniq(Credentials::Email))
+                    .col(timestamp_with_time_zone(Credentials::CreatedAt))
+                    .col(timestamp_with_time_zone(Credentials::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_credentials_user_id")
+                            .from(Credentials::Table, Credentials::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Credentials::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Credentials {
+    Table,
+    UserId,
+    ActivityId,
+    PasswordHash,
+    Email,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,120 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Reports::Table)
+                    .if_not_exists()
+                    .col(uuid(Reports::Id).primary_key())
+                    .col(uuid(Reports::ReporterId))
+                    .col(uuid(Reports::TargetAccountId))
+                    .col(string(Reports::Category))
+                    .col(text(Reports::Comment))
+                    .col(uuid_null(Reports::AssignedAccountId))
+                    .col(timestamp_with_time_zone_null(Reports::ActionTakenAt))
+                    .col(uuid_null(Reports::ActionTakenById))
+                    .col(timestamp_with_time_zone(Reports::CreatedAt))
+                    .col(timestamp_with_time_zone(Reports::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_reporter_id")
+                            .from(Reports::Table, Reports::ReporterId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_target_account_id")
+                            .from(Reports::Table, Reports::TargetAccountId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_assigned_account_id")
+                            .from(Reports::Table, Reports::AssignedAccountId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_reports_action_taken_by_id")
+                            .from(Reports::Table, Reports::ActionTakenById)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReportNotes::Table)
+                    .if_not_exists()
+                    .col(uuid(ReportNotes::Id).primary_key())
+                    .col(uuid(ReportNotes::ReportId))
+                    .col(uuid(ReportNotes::AuthorId))
+                    .col(text(ReportNotes::Content))
+                    .col(timestamp_with_time_zone(ReportNotes::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_report_notes_report_id")
+                            .from(ReportNotes::Table, ReportNotes::ReportId)
+                            .to(Reports::Table, Reports::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_report_notes_author_id")
+                            .from(ReportNotes::Table, ReportNotes::AuthorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReportNotes::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Reports::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Reports {
+    Table,
+    Id,
+    ReporterId,
+    TargetAccountId,
+    Category,
+    Comment,
+    AssignedAccountId,
+    ActionTakenAt,
+    ActionTakenById,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum ReportNotes {
+    Table,
+    Id,
+    ReportId,
+    AuthorId,
+    Content,
+    CreatedAt,
+}
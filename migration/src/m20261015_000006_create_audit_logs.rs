@@ -0,0 +1,63 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLogs::Table)
+                    .if_not_exists()
+                    .col(uuid(AuditLogs::Id).primary_key())
+                    .col(uuid_null(AuditLogs::ActorId))
+                    .col(string(AuditLogs::Action))
+                    .col(string(AuditLogs::TargetType))
+                    .col(uuid(AuditLogs::TargetId))
+                    .col(text_null(AuditLogs::Reason))
+                    .col(timestamp_with_time_zone(AuditLogs::CreatedAt))
+                    // keep the trail when the moderator account goes away
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_audit_logs_actor_id")
+                            .from(AuditLogs::Table, AuditLogs::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_logs_created_at")
+                    .table(AuditLogs::Table)
+                    .col(AuditLogs::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditLogs::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AuditLogs {
+    Table,
+    Id,
+    ActorId,
+    Action,
+    TargetType,
+    TargetId,
+    Reason,
+    CreatedAt,
+}
@@ -0,0 +1,86 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000004_create_reports::Reports;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Rules::Table)
+                    .if_not_exists()
+                    .col(uuid(Rules::Id).primary_key())
+                    .col(text(Rules::Text))
+                    .col(text(Rules::Hint))
+                    .col(integer(Rules::Position))
+                    .col(timestamp_with_time_zone(Rules::CreatedAt))
+                    .col(timestamp_with_time_zone(Rules::UpdatedAt))
+                    // rules are soft deleted, reports keep pointing at what was violated
+                    .col(timestamp_with_time_zone_null(Rules::DeletedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReportRules::Table)
+                    .if_not_exists()
+                    .col(uuid(ReportRules::ReportId))
+                    .col(uuid(ReportRules::RuleId))
+                    .primary_key(
+                        Index::create()
+                            .col(ReportRules::ReportId)
+                            .col(ReportRules::RuleId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_report_rules_report_id")
+                            .from(ReportRules::Table, ReportRules::ReportId)
+                            .to(Reports::Table, Reports::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_report_rules_rule_id")
+                            .from(ReportRules::Table, ReportRules::RuleId)
+                            .to(Rules::Table, Rules::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReportRules::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Rules::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Rules {
+    Table,
+    Id,
+    Text,
+    Hint,
+    Position,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum ReportRules {
+    Table,
+    ReportId,
+    RuleId,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IpBlocks::Table)
+                    .if_not_exists()
+                    .col(uuid(IpBlocks::Id).primary_key())
+                    .col(string(IpBlocks::Network))
+                    .col(string(IpBlocks::Severity))
+                    .col(text(IpBlocks::Comment))
+                    .col(timestamp_with_time_zone_null(IpBlocks::ExpiresAt))
+                    .col(timestamp_with_time_zone(IpBlocks::CreatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IpBlocks::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum IpBlocks {
+    Table,
+    Id,
+    Network,
+    Severity,
+    Comment,
+    ExpiresAt,
+    CreatedAt,
+}
@@ -0,0 +1,57 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NotificationPreferences::Table)
+                    .if_not_exists()
+                    .col(uuid(NotificationPreferences::UserId).primary_key())
+                    .col(boolean(NotificationPreferences::EmailOnFollow))
+                    .col(boolean(NotificationPreferences::EmailOnMention))
+                    .col(boolean(NotificationPreferences::EmailOnReportResolved))
+                    .col(string(NotificationPreferences::Digest))
+                    .col(timestamp_with_time_zone(NotificationPreferences::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_notification_preferences_user_id")
+                            .from(
+                                NotificationPreferences::Table,
+                                NotificationPreferences::UserId,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(NotificationPreferences::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum NotificationPreferences {
+    Table,
+    UserId,
+    EmailOnFollow,
+    EmailOnMention,
+    EmailOnReportResolved,
+    Digest,
+    UpdatedAt,
+}
@@ -0,0 +1,91 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // every edit is a new row, older versions stay for acceptance history
+        manager
+            .create_table(
+                Table::create()
+                    .table(LegalDocuments::Table)
+                    .if_not_exists()
+                    .col(uuid(LegalDocuments::Id).primary_key())
+                    .col(string(LegalDocuments::Kind))
+                    .col(integer(LegalDocuments::Version))
+                    .col(text(LegalDocuments::Content))
+                    .col(timestamp_with_time_zone(LegalDocuments::PublishedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_legal_documents_kind_version")
+                    .table(LegalDocuments::Table)
+                    .col(LegalDocuments::Kind)
+                    .col(LegalDocuments::Version)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(LegalAcceptances::Table)
+                    .if_not_exists()
+                    .col(uuid(LegalAcceptances::UserId))
+                    .col(string(LegalAcceptances::Kind))
+                    .col(integer(LegalAcceptances::Version))
+                    .col(timestamp_with_time_zone(LegalAcceptances::AcceptedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(LegalAcceptances::UserId)
+                            .col(LegalAcceptances::Kind),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_legal_acceptances_user_id")
+                            .from(LegalAcceptances::Table, LegalAcceptances::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(LegalAcceptances::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(LegalDocuments::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum LegalDocuments {
+    Table,
+    Id,
+    Kind,
+    Version,
+    Content,
+    PublishedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum LegalAcceptances {
+    Table,
+    UserId,
+    Kind,
+    Version,
+    AcceptedAt,
+}
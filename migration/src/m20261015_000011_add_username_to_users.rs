@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use sea_orm_migration::{
+    prelude::*,
+    schema::*,
+    sea_orm::{ConnectionTrait, prelude::Uuid},
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // backfill local accounts from the last segment of the activity id
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([Users::Id, Users::ActivityId])
+                        .from(Users::Table),
+                ),
+            )
+            .await?;
+        let local_prefix = match rows.is_empty() {
+            true => String::new(),
+            false => format!("https://{}/users/", instance_host()?),
+        };
+        let mut usernames: Vec<(Uuid, String)> = Vec::with_capacity(rows.len());
+        let mut owners: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for row in rows {
+            let id: Uuid = row.try_get("", "id")?;
+            let activity_id: String = row.try_get("", "activity_id")?;
+            let Some(name) = activity_id.strip_prefix(&local_prefix) else {
+                continue;
+            };
+            let username = name.to_lowercase();
+            owners
+                .entry(username.clone())
+                .or_default()
+                .push(activity_id);
+            usernames.push((id, username));
+        }
+
+        // names differing only by case would break the unique index half way,
+        // they are renamed by hand before migrating
+        let collisions: Vec<String> = owners
+            .into_iter()
+            .filter(|(_, activity_ids)| activity_ids.len() > 1)
+            .map(|(username, activity_ids)| format!("{username}: {}", activity_ids.join(", ")))
+            .collect();
+        if !collisions.is_empty() {
+            return Err(DbErr::Migration(format!(
+                "usernames collide once lowercased, rename all but one account of each: {}",
+                collisions.join("; ")
+            )));
+        }
+
+        // lowercased username of local accounts, remote accounts keep it null
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .add_column(string_null(Users::Username))
+                    .to_owned(),
+            )
+            .await?;
+
+        for (id, username) in usernames {
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Users::Table)
+                        .value(Users::Username, username)
+                        .and_where(Expr::col(Users::Id).eq(id)),
+                ),
+            )
+            .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_users_username")
+                    .table(Users::Table)
+                    .col(Users::Username)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_users_username")
+                    .table(Users::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Users::Table)
+                    .drop_column(Users::Username)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// helper function that read the host local activity ids are minted under
+fn instance_host() -> Result<String, DbErr> {
+    std::env::var("INSTANCE_HOST").map_err(|_| {
+        DbErr::Migration("INSTANCE_HOST must be set to tell local accounts apart".to_string())
+    })
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+    ActivityId,
+    Username,
+}
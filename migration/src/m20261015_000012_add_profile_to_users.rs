@@ -0,0 +1,94 @@
+use sea_orm_migration::{
+    prelude::*,
+    schema::*,
+    sea_orm::{
+        ConnectionTrait,
+        prelude::{DateTimeWithTimeZone, Uuid},
+    },
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // one column per statement, SQLite refuses several alter options at once
+        for column in [
+            json_binary_null(Users::Header),
+            timestamp_with_time_zone(Users::CreatedAt)
+                .default(Expr::current_timestamp())
+                .to_owned(),
+            timestamp_with_time_zone(Users::UpdatedAt)
+                .default(Expr::current_timestamp())
+                .to_owned(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Users::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // local accounts were created along with their credential
+        let db = manager.get_connection();
+        let backend = manager.get_database_backend();
+        let rows = db
+            .query_all(
+                backend.build(
+                    Query::select()
+                        .columns([Credentials::UserId, Credentials::CreatedAt])
+                        .from(Credentials::Table),
+                ),
+            )
+            .await?;
+        for row in rows {
+            let user_id: Uuid = row.try_get("", "user_id")?;
+            let created_at: DateTimeWithTimeZone = row.try_get("", "created_at")?;
+            db.execute(
+                backend.build(
+                    Query::update()
+                        .table(Users::Table)
+                        .value(Users::CreatedAt, created_at)
+                        .value(Users::UpdatedAt, created_at)
+                        .and_where(Expr::col(Users::Id).eq(user_id)),
+                ),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [Users::Header, Users::CreatedAt, Users::UpdatedAt] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Users::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+    Header,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Credentials {
+    Table,
+    UserId,
+    CreatedAt,
+}
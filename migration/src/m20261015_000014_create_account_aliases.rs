@@ -0,0 +1,49 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AccountAliases::Table)
+                    .if_not_exists()
+                    .col(uuid(AccountAliases::UserId))
+                    .col(string(AccountAliases::Uri))
+                    .col(timestamp_with_time_zone(AccountAliases::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(AccountAliases::UserId)
+                            .col(AccountAliases::Uri),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_account_aliases_user_id")
+                            .from(AccountAliases::Table, AccountAliases::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AccountAliases::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AccountAliases {
+    Table,
+    UserId,
+    Uri,
+    CreatedAt,
+}
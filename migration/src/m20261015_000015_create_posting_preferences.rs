@@ -0,0 +1,50 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostingPreferences::Table)
+                    .if_not_exists()
+                    .col(uuid(PostingPreferences::UserId).primary_key())
+                    .col(string(PostingPreferences::DefaultVisibility))
+                    .col(string_null(PostingPreferences::DefaultLanguage))
+                    .col(boolean(PostingPreferences::DefaultSensitive))
+                    .col(boolean(PostingPreferences::MarkMediaSensitive))
+                    .col(timestamp_with_time_zone(PostingPreferences::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_posting_preferences_user_id")
+                            .from(PostingPreferences::Table, PostingPreferences::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostingPreferences::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum PostingPreferences {
+    Table,
+    UserId,
+    DefaultVisibility,
+    DefaultLanguage,
+    DefaultSensitive,
+    MarkMediaSensitive,
+    UpdatedAt,
+}
@@ -0,0 +1,51 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FederationDomains::Table)
+                    .if_not_exists()
+                    .col(uuid(FederationDomains::Id).primary_key())
+                    .col(string(FederationDomains::Domain))
+                    .col(string(FederationDomains::Policy))
+                    .col(text(FederationDomains::Comment))
+                    .col(timestamp_with_time_zone(FederationDomains::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_federation_domains_domain_policy")
+                    .table(FederationDomains::Table)
+                    .col(FederationDomains::Domain)
+                    .col(FederationDomains::Policy)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FederationDomains::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum FederationDomains {
+    Table,
+    Id,
+    Domain,
+    Policy,
+    Comment,
+    CreatedAt,
+}
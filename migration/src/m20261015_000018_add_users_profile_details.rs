@@ -0,0 +1,58 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // every detail is private until the owner opts in
+        // one column per statement, SQLite refuses several alter options at once
+        for column in [
+            date_null(Users::Birthday),
+            string(Users::BirthdayVisibility)
+                .default("hidden")
+                .to_owned(),
+            string_null(Users::Location),
+            boolean(Users::LocationVisible).default(false).to_owned(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Users::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [
+            Users::Birthday,
+            Users::BirthdayVisibility,
+            Users::Location,
+            Users::LocationVisible,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Users::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Birthday,
+    BirthdayVisibility,
+    Location,
+    LocationVisible,
+}
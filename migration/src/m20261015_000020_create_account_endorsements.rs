@@ -0,0 +1,57 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // removed with either account
+        manager
+            .create_table(
+                Table::create()
+                    .table(AccountEndorsements::Table)
+                    .if_not_exists()
+                    .col(uuid(AccountEndorsements::UserId))
+                    .col(uuid(AccountEndorsements::TargetId))
+                    .col(timestamp_with_time_zone(AccountEndorsements::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(AccountEndorsements::UserId)
+                            .col(AccountEndorsements::TargetId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_account_endorsements_user_id")
+                            .from(AccountEndorsements::Table, AccountEndorsements::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_account_endorsements_target_id")
+                            .from(AccountEndorsements::Table, AccountEndorsements::TargetId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AccountEndorsements::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum AccountEndorsements {
+    Table,
+    UserId,
+    TargetId,
+    CreatedAt,
+}
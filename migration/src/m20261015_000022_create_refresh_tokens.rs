@@ -0,0 +1,63 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // only the digest of the token is stored
+        manager
+            .create_table(
+                Table::create()
+                    .table(RefreshTokens::Table)
+                    .if_not_exists()
+                    .col(uuid(RefreshTokens::Id).primary_key())
+                    .col(uuid(RefreshTokens::UserId))
+                    .col(string_uniq(RefreshTokens::TokenHash))
+                    .col(uuid(RefreshTokens::FamilyId))
+                    .col(timestamp_with_time_zone(RefreshTokens::ExpiresAt))
+                    .col(timestamp_with_time_zone_null(RefreshTokens::RevokedAt))
+                    .col(timestamp_with_time_zone(RefreshTokens::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_refresh_tokens_user_id")
+                            .from(RefreshTokens::Table, RefreshTokens::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_refresh_tokens_family_id")
+                    .table(RefreshTokens::Table)
+                    .col(RefreshTokens::FamilyId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RefreshTokens::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum RefreshTokens {
+    Table,
+    Id,
+    UserId,
+    TokenHash,
+    FamilyId,
+    ExpiresAt,
+    RevokedAt,
+    CreatedAt,
+}
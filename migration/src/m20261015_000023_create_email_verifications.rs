@@ -0,0 +1,45 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20261015_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // existing accounts have no row, they count as verified
+        manager
+            .create_table(
+                Table::create()
+                    .table(EmailVerifications::Table)
+                    .if_not_exists()
+                    .col(uuid(EmailVerifications::UserId).primary_key())
+                    .col(string_uniq(EmailVerifications::TokenHash))
+                    .col(timestamp_with_time_zone(EmailVerifications::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_email_verifications_user_id")
+                            .from(EmailVerifications::Table, EmailVerifications::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(EmailVerifications::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum EmailVerifications {
+    Table,
+    UserId,
+    TokenHash,
+    CreatedAt,
+}
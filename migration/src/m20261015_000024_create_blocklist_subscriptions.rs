@@ -0,0 +1,49 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BlocklistSubscriptions::Table)
+                    .if_not_exists()
+                    .col(uuid(BlocklistSubscriptions::Id).primary_key())
+                    .col(string_uniq(BlocklistSubscriptions::Url))
+                    .col(boolean(BlocklistSubscriptions::Review))
+                    .col(integer(BlocklistSubscriptions::Pending).default(0))
+                    .col(timestamp_with_time_zone_null(
+                        BlocklistSubscriptions::LastSyncedAt,
+                    ))
+                    .col(text_null(BlocklistSubscriptions::LastError))
+                    .col(timestamp_with_time_zone(BlocklistSubscriptions::CreatedAt))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(BlocklistSubscriptions::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum BlocklistSubscriptions {
+    Table,
+    Id,
+    Url,
+    Review,
+    Pending,
+    LastSyncedAt,
+    LastError,
+    CreatedAt,
+}
@@ -0,0 +1,418 @@
+use std::path::PathBuf;
+
+use chrono::Duration;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use migration::{Migrator, MigratorTrait};
+use rand::{Rng, SeedableRng, distr::Alphanumeric, rngs::StdRng};
+use sea_orm::{DatabaseConnection, DbErr};
+use serde::Deserialize;
+
+use crate::{
+    config::{Config, ConfigError},
+    domain::{
+        error::RepositoryError,
+        models::{
+            credential::HashedPassword,
+            user::{DELETION_GRACE_DAYS, Role},
+        },
+        repositories::user_repository::UserRepository,
+        services::{password_service::PasswordHasher, token_service::INTROSPECT_SCOPE},
+    },
+    infrastructure::{
+        argon2_password_hasher::Argon2PasswordHasher,
+        audit_log_repository::PostgresAuditLogRepository,
+        credential_repository::PostgresCredentialRepository, database::DatabasePool,
+        jwt_token_generator::JwtTokenGenerator,
+        refresh_token_repository::PostgresRefreshTokenRepository,
+        user_registration_repository::PostgresUserRegistrationRepository,
+        user_repository::PostgresUserRepository,
+    },
+    usecase::{admin_usecase::AdminUsecase, moderation_usecase::ModerationUsecase},
+};
+
+/// Command line interface of the api binary
+#[derive(Parser)]
+#[command(name = "cascade", about = "cascade API server")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Start the HTTP server (default)
+    Serve,
+    /// Manage database migrations
+    Migrate {
+        #[command(subcommand)]
+        action: Option<MigrateAction>,
+    },
+    /// Create a local account
+    CreateUser(CreateUserArgs),
+    /// Replace the password of a local account
+    ResetPassword(ResetPasswordArgs),
+    /// Create local accounts migrated from another server
+    ImportAccounts(ImportAccountsArgs),
+    /// Remove deleted accounts once their tombstone is no longer needed
+    PurgeDeleted(PurgeDeletedArgs),
+    /// Fill a development database with sample accounts
+    Seed(SeedArgs),
+    /// Generate a new JWT signing secret
+    RotateJwtSecret,
+    /// Issue a token allowed to introspect the tokens of any account, for a trusted service
+    IssueServiceToken(IssueServiceTokenArgs),
+    /// Check configuration, database and network, then exit
+    Doctor,
+}
+
+#[derive(Subcommand)]
+pub enum MigrateAction {
+    /// Apply all pending migrations (default)
+    Up,
+    /// Rollback the last applied migrations
+    Down {
+        #[arg(short, long, default_value_t = 1)]
+        steps: u32,
+    },
+    /// Show the status of every migration
+    Status,
+}
+
+#[derive(Args)]
+pub struct CreateUserArgs {
+    #[arg(long)]
+    pub username: String,
+    #[arg(long)]
+    pub email: String,
+    /// Defaults to the username
+    #[arg(long)]
+    pub display_name: Option<String>,
+    /// Generated and printed when omitted
+    #[arg(long)]
+    pub password: Option<String>,
+    #[arg(long, value_enum, default_value_t = RoleArg::User)]
+    pub role: RoleArg,
+}
+
+#[derive(Args)]
+pub struct ResetPasswordArgs {
+    #[arg(long)]
+    pub username: String,
+    /// Generated and printed when omitted
+    #[arg(long)]
+    pub password: Option<String>,
+}
+
+#[derive(Args)]
+pub struct ImportAccountsArgs {
+    /// CSV with a header row: `username,email,display_name,password_hash`
+    /// `display_name` defaults to the username, accounts without `password_hash` must reset it
+    #[arg(long)]
+    pub file: PathBuf,
+}
+
+/// A row of the accounts CSV
+#[derive(Deserialize)]
+struct ImportedAccount {
+    username: String,
+    email: String,
+    #[serde(default)]
+    display_name: Option<String>,
+    /// bcrypt (`$2b$...`, as stored by Mastodon) or Argon2id
+    #[serde(default)]
+    password_hash: Option<String>,
+}
+
+#[derive(Args)]
+pub struct PurgeDeletedArgs {
+    /// Keep accounts deleted less than this many days ago
+    #[arg(long, default_value_t = DELETION_GRACE_DAYS)]
+    pub days: i64,
+}
+
+#[derive(Args)]
+pub struct SeedArgs {
+    /// Number of accounts to create
+    #[arg(long, default_value_t = 50)]
+    pub users: u32,
+    /// The same seed creates the same accounts with the same password
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+}
+
+#[derive(Args)]
+pub struct IssueServiceTokenArgs {
+    /// Account the service acts as
+    #[arg(long)]
+    pub username: String,
+    /// Lifetime of the token
+    #[arg(long, default_value_t = 365)]
+    pub days: i64,
+}
+
+/// Words the display names of seeded accounts are made of
+const SEED_ADJECTIVES: [&str; 8] = [
+    "Quiet", "Sunny", "Lazy", "Brave", "Sleepy", "Curious", "Gentle", "Noisy",
+];
+const SEED_NOUNS: [&str; 8] = [
+    "Otter", "Heron", "Maple", "Comet", "Badger", "Willow", "Falcon", "Pebble",
+];
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum RoleArg {
+    User,
+    Moderator,
+    Admin,
+}
+
+impl From<RoleArg> for Role {
+    fn from(role: RoleArg) -> Self {
+        match role {
+            RoleArg::User => Role::User,
+            RoleArg::Moderator => Role::Moderator,
+            RoleArg::Admin => Role::Admin,
+        }
+    }
+}
+
+/// handler function for `migrate` subcommand
+pub async fn migrate(db: &DatabaseConnection, action: Option<MigrateAction>) -> Result<(), DbErr> {
+    match action.unwrap_or(MigrateAction::Up) {
+        MigrateAction::Up => Migrator::up(db, None).await,
+        MigrateAction::Down { steps } => Migrator::down(db, Some(steps)).await,
+        MigrateAction::Status => Migrator::status(db).await,
+    }
+}
+
+/// handler function for `create-user` subcommand
+pub async fn create_user(
+    db: &DatabasePool,
+    config: &Config,
+    args: CreateUserArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (password, generated) = password_or_generated(args.password);
+    let display_name = args.display_name.unwrap_or_else(|| args.username.clone());
+
+    let user = admin_usecase(db, config)?
+        .create_user(
+            args.username,
+            display_name,
+            password.clone(),
+            args.email,
+            args.role.into(),
+        )
+        .await?;
+
+    println!(
+        "Created {} ({})",
+        user.activity_id().as_str(),
+        user.role().as_str()
+    );
+    if generated {
+        println!("Password: {}", password);
+    }
+    Ok(())
+}
+
+/// handler function for `reset-password` subcommand
+pub async fn reset_password(
+    db: &DatabasePool,
+    config: &Config,
+    args: ResetPasswordArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (password, generated) = password_or_generated(args.password);
+
+    admin_usecase(db, config)?
+        .reset_password(args.username.clone(), password.clone())
+        .await?;
+
+    println!("Password of {} has been reset", args.username);
+    if generated {
+        println!("Password: {}", password);
+    }
+    Ok(())
+}
+
+/// handler function for `import-accounts` subcommand
+/// A row that fails is reported and skipped, the import goes on
+pub async fn import_accounts(
+    db: &DatabasePool,
+    config: &Config,
+    args: ImportAccountsArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let admin = admin_usecase(db, config)?;
+    let mut reader = csv::Reader::from_path(&args.file)?;
+    let (mut imported, mut failed) = (0, 0);
+
+    for (line, row) in reader.deserialize::<ImportedAccount>().enumerate() {
+        // the header is line 1
+        let line = line + 2;
+        let account = match row {
+            Ok(account) => account,
+            Err(e) => {
+                eprintln!("line {}: {}", line, e);
+                failed += 1;
+                continue;
+            }
+        };
+        let display_name = account
+            .display_name
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| account.username.clone());
+        let password_hash = account
+            .password_hash
+            .filter(|hash| !hash.is_empty())
+            .map(HashedPassword::new);
+
+        match admin
+            .import_user(
+                account.username.clone(),
+                display_name,
+                account.email,
+                password_hash,
+            )
+            .await
+        {
+            Ok(_) => imported += 1,
+            Err(e) => {
+                eprintln!("line {} ({}): {}", line, account.username, e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("Imported {} account(s), {} failed", imported, failed);
+    Ok(())
+}
+
+/// handler function for `purge-deleted` subcommand, meant to run periodically (cron, ...)
+pub async fn purge_deleted(
+    db: &DatabasePool,
+    args: PurgeDeletedArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let moderation = ModerationUsecase::new(
+        PostgresUserRepository::new(db.clone()),
+        PostgresAuditLogRepository::new(db.clone()),
+    );
+    let purged = moderation.purge_deleted(Duration::days(args.days)).await?;
+
+    println!("Purged {} deleted account(s)", purged);
+    Ok(())
+}
+
+/// handler function for `seed` subcommand
+/// Accounts are named after the seed, one that already exists is reported and skipped
+pub async fn seed(
+    db: &DatabasePool,
+    config: &Config,
+    args: SeedArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    let password = random_alphanumeric(&mut rng, 20);
+    // hashed once, every seeded account shares the password
+    let password_hash = Argon2PasswordHasher::new().hash(&password)?;
+    let admin = admin_usecase(db, config)?;
+    let (mut seeded, mut failed) = (0, 0);
+
+    for n in 0..args.users {
+        let username = format!("seed{}_{:04}", args.seed, n);
+        let display_name = format!(
+            "{} {}",
+            SEED_ADJECTIVES[rng.random_range(0..SEED_ADJECTIVES.len())],
+            SEED_NOUNS[rng.random_range(0..SEED_NOUNS.len())],
+        );
+        let email = format!("{}@example.com", username);
+
+        match admin
+            .import_user(
+                username.clone(),
+                display_name,
+                email,
+                Some(password_hash.clone()),
+            )
+            .await
+        {
+            Ok(_) => seeded += 1,
+            Err(e) => {
+                eprintln!("{}: {}", username, e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("Seeded {} account(s), {} failed", seeded, failed);
+    println!("Password: {}", password);
+    Ok(())
+}
+
+/// handler function for `rotate-jwt-secret` subcommand
+pub fn rotate_jwt_secret() {
+    println!("JWT_SECRET={}", generate_secret(64));
+    println!(
+        "Set it in the environment and restart; tokens signed with the old secret stop working."
+    );
+}
+
+/// handler function for `issue-service-token` subcommand
+pub async fn issue_service_token(
+    db: &DatabasePool,
+    jwt_secret: &str,
+    args: IssueServiceTokenArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let user = PostgresUserRepository::new(db.clone())
+        .find_by_username(&args.username)
+        .await?
+        .ok_or(RepositoryError::NotFound)?;
+
+    let token = JwtTokenGenerator::with_expiration(jwt_secret.to_string(), args.days * 24)
+        .generate_with_scopes(&user, &[INTROSPECT_SCOPE])?;
+
+    println!("Token: {}", token.value);
+    println!("Expires at: {}", token.expires_at);
+    Ok(())
+}
+
+/// helper function that build the admin usecase over the database
+fn admin_usecase(
+    db: &DatabasePool,
+    config: &Config,
+) -> Result<
+    AdminUsecase<
+        PostgresUserRegistrationRepository,
+        PostgresCredentialRepository,
+        Argon2PasswordHasher,
+        PostgresAuditLogRepository,
+        PostgresRefreshTokenRepository,
+    >,
+    ConfigError,
+> {
+    Ok(AdminUsecase::new(
+        PostgresUserRegistrationRepository::new(db.clone()),
+        PostgresCredentialRepository::new(db.primary_only()),
+        Argon2PasswordHasher::new(),
+        PostgresAuditLogRepository::new(db.clone()),
+        PostgresRefreshTokenRepository::new(db.clone()),
+        config.require_instance_host()?.to_string(),
+    ))
+}
+
+/// helper function that return the given password, or a random one
+fn password_or_generated(password: Option<String>) -> (String, bool) {
+    match password {
+        Some(password) => (password, false),
+        None => (generate_secret(20), true),
+    }
+}
+
+/// helper function that generate a random alphanumeric string
+fn generate_secret(len: usize) -> String {
+    random_alphanumeric(&mut rand::rng(), len)
+}
+
+/// helper function that draw an alphanumeric string from the given generator
+fn random_alphanumeric(rng: &mut impl Rng, len: usize) -> String {
+    rng.sample_iter(&Alphanumeric)
+        .take(len)
+        .map(char::from)
+        .collect()
+}
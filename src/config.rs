@@ -0,0 +1,332 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::domain::models::federation::FederationMode;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Missing environment variable: {0}")]
+    Missing(&'static str),
+
+    #[error("Invalid value for {key}: {value}")]
+    Invalid { key: &'static str, value: String },
+}
+
+/// Placeholder secret used when JWT_SECRET is unset, never use it in production
+pub const DEFAULT_JWT_SECRET: &str = "testtoken";
+
+/// Software name and version announced to remote servers
+pub const SOFTWARE: &str = concat!("cascade/", env!("CARGO_PKG_VERSION"));
+
+/// Output format of the log subscriber
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+/// Backend used to deliver emails
+#[derive(Debug, Clone)]
+pub enum MailConfig {
+    /// Write emails to the log (development)
+    Log,
+    Smtp {
+        host: String,
+        port: u16,
+        credentials: Option<(String, String)>,
+        from: String,
+    },
+}
+
+/// Connection pool settings, applied to the primary and every replica
+#[derive(Debug, Clone)]
+pub struct PoolConfig {
+    pub max_connections: u32,
+    pub min_connections: u32,
+    /// How long a request waits for a free connection before failing with 503
+    pub acquire_timeout: Duration,
+    pub idle_timeout: Duration,
+    /// Server side statement timeout (Postgres only)
+    pub statement_timeout: Option<Duration>,
+    /// Statements slower than this are logged at warn level
+    pub slow_query_threshold: Duration,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: 10,
+            min_connections: 1,
+            acquire_timeout: Duration::from_secs(8),
+            idle_timeout: Duration::from_secs(600),
+            statement_timeout: None,
+            slow_query_threshold: Duration::from_secs(1),
+        }
+    }
+}
+
+impl PoolConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        let default = Self::default();
+        Ok(Self {
+            max_connections: number("DB_MAX_CONNECTIONS", default.max_connections)?,
+            min_connections: number("DB_MIN_CONNECTIONS", default.min_connections)?,
+            acquire_timeout: Duration::from_millis(number(
+                "DB_ACQUIRE_TIMEOUT_MS",
+                default.acquire_timeout.as_millis() as u64,
+            )?),
+            idle_timeout: Duration::from_millis(number(
+                "DB_IDLE_TIMEOUT_MS",
+                default.idle_timeout.as_millis() as u64,
+            )?),
+            statement_timeout: match dotenvy::var("DB_STATEMENT_TIMEOUT_MS") {
+                Ok(_) => Some(Duration::from_millis(number("DB_STATEMENT_TIMEOUT_MS", 0)?)),
+                Err(_) => default.statement_timeout,
+            },
+            slow_query_threshold: Duration::from_millis(number(
+                "DB_SLOW_QUERY_MS",
+                default.slow_query_threshold.as_millis() as u64,
+            )?),
+        })
+    }
+}
+
+/// Limits of outbound HTTP calls (federation, media proxy, ...)
+#[derive(Debug, Clone)]
+pub struct OutboundConfig {
+    /// Timeout of a single attempt
+    pub request_timeout: Duration,
+    /// Upper bound for the whole call, retries included
+    pub deadline: Duration,
+    pub max_retries: u32,
+    pub max_redirects: usize,
+}
+
+impl Default for OutboundConfig {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(10),
+            deadline: Duration::from_secs(30),
+            max_retries: 3,
+            max_redirects: 5,
+        }
+    }
+}
+
+impl OutboundConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        let default = Self::default();
+        Ok(Self {
+            request_timeout: Duration::from_millis(number(
+                "HTTP_TIMEOUT_MS",
+                default.request_timeout.as_millis() as u64,
+            )?),
+            deadline: Duration::from_millis(number(
+                "HTTP_DEADLINE_MS",
+                default.deadline.as_millis() as u64,
+            )?),
+            max_retries: number("HTTP_MAX_RETRIES", default.max_retries)?,
+            max_redirects: number("HTTP_MAX_REDIRECTS", default.max_redirects)?,
+        })
+    }
+}
+
+/// Thresholds past which requests are answered 503 right away instead of queueing
+#[derive(Debug, Clone)]
+pub struct LoadShedConfig {
+    /// Requests handled at once
+    pub max_in_flight: usize,
+    /// Requests handled at once while every connection to the primary database is busy
+    pub max_in_flight_db_saturated: usize,
+    /// Emails waiting for delivery, past it requests that may send one are refused
+    pub max_email_backlog: usize,
+}
+
+impl Default for LoadShedConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: 1024,
+            max_in_flight_db_saturated: 64,
+            max_email_backlog: 10_000,
+        }
+    }
+}
+
+impl LoadShedConfig {
+    fn from_env() -> Result<Self, ConfigError> {
+        let default = Self::default();
+        Ok(Self {
+            max_in_flight: number("LOAD_SHED_MAX_IN_FLIGHT", default.max_in_flight)?,
+            max_in_flight_db_saturated: number(
+                "LOAD_SHED_MAX_IN_FLIGHT_DB_SATURATED",
+                default.max_in_flight_db_saturated,
+            )?,
+            max_email_backlog: number("LOAD_SHED_MAX_EMAIL_BACKLOG", default.max_email_backlog)?,
+        })
+    }
+}
+
+/// Application settings read from the environment (`../.env` in development)
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub database_url: String,
+    /// Read replicas (comma separated), read-only queries are spread over them
+    pub replica_urls: Vec<String>,
+    pub pool: PoolConfig,
+    pub load_shed: LoadShedConfig,
+    /// Apply pending migrations before the server starts accepting requests
+    pub run_migrations: bool,
+    pub log_format: LogFormat,
+    /// OTLP collector endpoint, traces and metrics are exported only when set
+    pub otlp_endpoint: Option<String>,
+    pub mail: MailConfig,
+    /// Secret used to sign access tokens
+    pub jwt_secret: String,
+    /// Secret used to sign media proxy URLs, defaults to the JWT secret
+    pub media_proxy_secret: String,
+    /// Largest remote file the media proxy relays
+    pub media_proxy_max_bytes: usize,
+    /// Public host name of the instance, without scheme (e.g. social.example.com)
+    pub instance_host: Option<String>,
+    /// `User-Agent` of every outbound request, lets remote admins identify and reach the instance
+    pub user_agent: String,
+    pub outbound: OutboundConfig,
+    /// Let outbound requests reach private addresses (local federation testing only)
+    pub allow_private_outbound: bool,
+    /// `blocklist` federates with every domain not blocked, `allowlist` only with allowed ones
+    pub federation_mode: FederationMode,
+    /// Read the client address from `X-Forwarded-For`, enable only behind a reverse proxy
+    pub trust_proxy_headers: bool,
+    /// Sign-ups with an email under these domains wait for moderator approval
+    pub spam_held_email_domains: Vec<String>,
+    /// Flag sign-ups whose display name carries a link
+    pub spam_flag_display_name_links: bool,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let jwt_secret =
+            dotenvy::var("JWT_SECRET").unwrap_or_else(|_| DEFAULT_JWT_SECRET.to_string());
+        let instance_host = dotenvy::var("INSTANCE_HOST").ok();
+        Ok(Self {
+            database_url: required("DATABASE_URL")?,
+            replica_urls: list("DATABASE_REPLICA_URLS"),
+            pool: PoolConfig::from_env()?,
+            load_shed: LoadShedConfig::from_env()?,
+            run_migrations: flag("RUN_MIGRATIONS", true)?,
+            log_format: log_format("LOG_FORMAT")?,
+            otlp_endpoint: dotenvy::var("OTEL_EXPORTER_OTLP_ENDPOINT").ok(),
+            mail: mail("MAIL_BACKEND")?,
+            media_proxy_secret: dotenvy::var("MEDIA_PROXY_SECRET")
+                .unwrap_or_else(|_| jwt_secret.clone()),
+            media_proxy_max_bytes: number("MEDIA_PROXY_MAX_BYTES", 40 * 1024 * 1024)?,
+            user_agent: dotenvy::var("USER_AGENT")
+                .unwrap_or_else(|_| default_user_agent(instance_host.as_deref())),
+            instance_host,
+            outbound: OutboundConfig::from_env()?,
+            allow_private_outbound: flag("ALLOW_PRIVATE_OUTBOUND", false)?,
+            federation_mode: federation_mode("FEDERATION_MODE")?,
+            trust_proxy_headers: flag("TRUST_PROXY_HEADERS", false)?,
+            spam_held_email_domains: list("SPAM_HELD_EMAIL_DOMAINS"),
+            spam_flag_display_name_links: flag("SPAM_FLAG_DISPLAY_NAME_LINKS", true)?,
+            jwt_secret,
+        })
+    }
+
+    /// host local accounts are minted under, an unset host would mint ids nobody can resolve
+    pub fn require_instance_host(&self) -> Result<&str, ConfigError> {
+        self.instance_host
+            .as_deref()
+            .ok_or(ConfigError::Missing("INSTANCE_HOST"))
+    }
+}
+
+/// helper function that build `cascade/<version> (+https://<host>/)`, the convention of fediverse servers
+fn default_user_agent(instance_host: Option<&str>) -> String {
+    match instance_host {
+        Some(host) => format!("{} (+https://{}/)", SOFTWARE, host),
+        None => SOFTWARE.to_string(),
+    }
+}
+
+/// helper function that read a mandatory variable
+fn required(key: &'static str) -> Result<String, ConfigError> {
+    dotenvy::var(key).map_err(|_| ConfigError::Missing(key))
+}
+
+/// helper function that read a comma separated variable, empty when unset
+fn list(key: &'static str) -> Vec<String> {
+    dotenvy::var(key)
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// helper function that read a boolean variable, falling back to `default` when unset
+fn flag(key: &'static str, default: bool) -> Result<bool, ConfigError> {
+    match dotenvy::var(key) {
+        Ok(value) => match value.to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Ok(true),
+            "0" | "false" | "no" | "off" => Ok(false),
+            _ => Err(ConfigError::Invalid { key, value }),
+        },
+        Err(_) => Ok(default),
+    }
+}
+
+/// helper function that read the log format, `pretty` when unset
+fn log_format(key: &'static str) -> Result<LogFormat, ConfigError> {
+    match dotenvy::var(key) {
+        Ok(value) => match value.to_ascii_lowercase().as_str() {
+            "pretty" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(ConfigError::Invalid { key, value }),
+        },
+        Err(_) => Ok(LogFormat::Pretty),
+    }
+}
+
+/// helper function that read the federation mode, `blocklist` when unset
+fn federation_mode(key: &'static str) -> Result<FederationMode, ConfigError> {
+    match dotenvy::var(key) {
+        Ok(value) => FederationMode::parse(&value.to_ascii_lowercase())
+            .map_err(|_| ConfigError::Invalid { key, value }),
+        Err(_) => Ok(FederationMode::Blocklist),
+    }
+}
+
+/// helper function that read a numeric variable, falling back to `default` when unset
+fn number<N: std::str::FromStr>(key: &'static str, default: N) -> Result<N, ConfigError> {
+    match dotenvy::var(key) {
+        Ok(value) => value
+            .parse()
+            .map_err(|_| ConfigError::Invalid { key, value }),
+        Err(_) => Ok(default),
+    }
+}
+
+/// helper function that read the mail backend, `log` when unset
+fn mail(key: &'static str) -> Result<MailConfig, ConfigError> {
+    match dotenvy::var(key) {
+        Ok(value) => match value.to_ascii_lowercase().as_str() {
+            "log" => Ok(MailConfig::Log),
+            "smtp" => Ok(MailConfig::Smtp {
+                host: required("SMTP_HOST")?,
+                port: number("SMTP_PORT", 587)?,
+                credentials: dotenvy::var("SMTP_USERNAME")
+                    .ok()
+                    .zip(dotenvy::var("SMTP_PASSWORD").ok()),
+                from: required("MAIL_FROM")?,
+            }),
+            _ => Err(ConfigError::Invalid { key, value }),
+        },
+        Err(_) => Ok(MailConfig::Log),
+    }
+}
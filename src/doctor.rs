@@ -0,0 +1,162 @@
+use migration::{Migrator, MigratorTrait};
+use thiserror::Error;
+
+use crate::{
+    config::{Config, DEFAULT_JWT_SECRET},
+    infrastructure::{
+        database::DatabasePool,
+        http_client::{HttpClientSettings, ResilientHttpClient},
+    },
+};
+
+/// URL fetched to confirm outbound HTTPS works (overridable with DOCTOR_PROBE_URL)
+const DEFAULT_PROBE_URL: &str = "https://example.com/";
+
+#[derive(Debug, Error)]
+#[error("{0} check(s) failed")]
+pub struct ChecksFailed(pub usize);
+
+#[derive(Debug)]
+pub enum Status {
+    Ok,
+    Warning(String),
+    Failure(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+}
+
+/// Run every diagnostic
+/// `outbound` additionally probes outbound HTTPS, which is skipped at startup
+pub async fn run(config: &Config, db: &DatabasePool, outbound: bool) -> Vec<Check> {
+    let mut checks = vec![
+        check_database(db).await,
+        check_schema(db).await,
+        check_jwt_secret(config),
+        check_instance_host(config),
+    ];
+    if outbound {
+        checks.push(check_outbound_https(config).await);
+    }
+    checks
+}
+
+/// Log every check, failing when any of them failed
+pub fn report(checks: &[Check]) -> Result<(), ChecksFailed> {
+    let mut failures = 0;
+    for check in checks {
+        match &check.status {
+            Status::Ok => tracing::info!(check = check.name, "ok"),
+            Status::Warning(message) => tracing::warn!(check = check.name, "{}", message),
+            Status::Failure(message) => {
+                failures += 1;
+                tracing::error!(check = check.name, "{}", message);
+            }
+        }
+    }
+
+    if failures == 0 {
+        Ok(())
+    } else {
+        Err(ChecksFailed(failures))
+    }
+}
+
+async fn check_database(db: &DatabasePool) -> Check {
+    let status = match db.writer().ping().await {
+        Err(e) => Status::Failure(format!("Cannot reach the primary database (DATABASE_URL): {}", e)),
+        Ok(()) => {
+            let mut unreachable = 0;
+            for replica in db.replicas() {
+                if replica.ping().await.is_err() {
+                    unreachable += 1;
+                }
+            }
+            if unreachable == 0 {
+                Status::Ok
+            } else {
+                Status::Warning(format!(
+                    "{} read replica(s) unreachable, check DATABASE_REPLICA_URLS",
+                    unreachable
+                ))
+            }
+        }
+    };
+    Check {
+        name: "database",
+        status,
+    }
+}
+
+async fn check_schema(db: &DatabasePool) -> Check {
+    let status = match Migrator::get_pending_migrations(db.writer()).await {
+        Ok(pending) if pending.is_empty() => Status::Ok,
+        Ok(pending) => Status::Failure(format!(
+            "{} pending migration(s), run `migrate up` or set RUN_MIGRATIONS=true",
+            pending.len()
+        )),
+        Err(e) => Status::Failure(format!("Cannot read the schema version: {}", e)),
+    };
+    Check {
+        name: "schema",
+        status,
+    }
+}
+
+fn check_jwt_secret(config: &Config) -> Check {
+    let status = if config.jwt_secret == DEFAULT_JWT_SECRET {
+        Status::Failure(
+            "JWT_SECRET is unset and the built-in default is used, generate one with `rotate-jwt-secret`"
+                .to_string(),
+        )
+    } else if config.jwt_secret.len() < 32 {
+        Status::Warning("JWT_SECRET is shorter than 32 characters".to_string())
+    } else {
+        Status::Ok
+    };
+    Check {
+        name: "jwt_secret",
+        status,
+    }
+}
+
+fn check_instance_host(config: &Config) -> Check {
+    let status = match &config.instance_host {
+        Some(host) if !host.is_empty() && !host.contains('/') => Status::Ok,
+        Some(host) => Status::Failure(format!(
+            "INSTANCE_HOST must be a bare host name (e.g. social.example.com), got {:?}",
+            host
+        )),
+        None => Status::Failure("INSTANCE_HOST is not set".to_string()),
+    };
+    Check {
+        name: "instance_host",
+        status,
+    }
+}
+
+async fn check_outbound_https(config: &Config) -> Check {
+    let url = dotenvy::var("DOCTOR_PROBE_URL").unwrap_or_else(|_| DEFAULT_PROBE_URL.to_string());
+    tracing::info!(user_agent = %config.user_agent, %url, "probing outbound HTTPS");
+    let settings = HttpClientSettings {
+        user_agent: config.user_agent.clone(),
+        ..Default::default()
+    };
+    let status = match ResilientHttpClient::new(settings) {
+        Err(e) => Status::Failure(format!("Cannot build the HTTP client: {}", e)),
+        Ok(client) => match client.get(&url).await {
+            Ok(_) => Status::Ok,
+            Err(e) => Status::Failure(format!(
+                "Outbound HTTPS to {} failed, federation will not work: {}",
+                url, e
+            )),
+        },
+    };
+    Check {
+        name: "outbound_https",
+        status,
+    }
+}
@@ -1,5 +1,8 @@
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
+use crate::domain::models::tombstone::Tombstone;
+
 #[derive(Debug, Error)]
 pub enum DomainError {
     #[error("Repository error: {0}")]
@@ -17,8 +20,94 @@ pub enum DomainError {
     #[error("Empty display name")]
     EmptyDisplayName,
 
+    #[error("Invalid theme color")]
+    InvalidThemeColor,
+
+    #[error("Invalid profile details")]
+    InvalidProfileDetails,
+
+    #[error("Invalid email address")]
+    InvalidEmail,
+
     #[error("Invalid activity ID")]
     InvalidActivityId,
+
+    #[error("Invalid username")]
+    InvalidUsername,
+
+    #[error("Invalid role")]
+    InvalidRole,
+
+    #[error("Permission denied")]
+    Forbidden,
+
+    #[error("Account disabled")]
+    AccountDisabled,
+
+    /// The address given at sign-up has not been confirmed yet
+    #[error("Email address not verified")]
+    EmailNotVerified,
+
+    #[error("Gone: {}", .0.id)]
+    Gone(Tombstone),
+
+    /// Deleted by the owner, reactivatable until the given time
+    #[error("Account pending deletion until {0}")]
+    PendingDeletion(DateTime<Utc>),
+
+    #[error("Invalid alias")]
+    InvalidAlias,
+
+    #[error("Invalid endorsement")]
+    InvalidEndorsement,
+
+    #[error("Invalid report")]
+    InvalidReport,
+
+    #[error("Invalid rule")]
+    InvalidRule,
+
+    #[error("Invalid IP block")]
+    InvalidIpBlock,
+
+    #[error("Invalid federation domain")]
+    InvalidFederationDomain,
+
+    #[error("Invalid blocklist URL")]
+    InvalidBlocklistUrl,
+
+    #[error("Domain not federated: {0}")]
+    DomainNotFederated(String),
+
+    #[error("Invalid notification preferences")]
+    InvalidNotificationPreferences,
+
+    #[error("Invalid posting preferences")]
+    InvalidPostingPreferences,
+
+    #[error("Invalid legal document")]
+    InvalidLegalDocument,
+
+    #[error("The terms must be accepted")]
+    AgreementRequired,
+
+    #[error("A newer version of the document has been published")]
+    OutdatedAgreement,
+
+    #[error("Email delivery failed: {0}")]
+    EmailDelivery(String),
+
+    #[error("Invalid signature")]
+    InvalidSignature,
+
+    #[error("Remote fetch failed: {0}")]
+    RemoteFetch(String),
+
+    #[error("Unsupported media type")]
+    UnsupportedMediaType,
+
+    #[error("Media too large")]
+    MediaTooLarge,
 }
 
 #[derive(Debug, Error)]
@@ -28,4 +117,15 @@ pub enum RepositoryError {
 
     #[error("Database error: {0}")]
     DatabaseError(String),
+
+    #[error("Database unavailable")]
+    Unavailable,
+
+    /// A unique value (username, email, ...) is already in use
+    #[error("{field} already taken")]
+    Conflict { field: &'static str },
+
+    /// The record changed since it was read (optimistic locking)
+    #[error("Record modified concurrently")]
+    Outdated,
 }
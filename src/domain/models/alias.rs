@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    error::DomainError,
+    models::user::{ActivityId, User, UserId},
+};
+
+/// Most aliases an account may list
+pub const MAX_ALIASES: usize = 10;
+
+/// Another account of the same person, published in the actor's `alsoKnownAs`
+/// Moving an account requires the destination to list the origin as an alias
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountAlias {
+    pub user_id: UserId,
+    /// ActivityPub id of the other account
+    pub uri: ActivityId,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AccountAlias {
+    pub fn new(user: &User, uri: ActivityId) -> Result<Self, DomainError> {
+        if &uri == user.activity_id() {
+            return Err(DomainError::InvalidAlias);
+        }
+        Ok(Self {
+            user_id: user.id(),
+            uri,
+            created_at: Utc::now(),
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::models::user::UserId;
+
+/// Object a moderation action was applied to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditTarget {
+    Account(UserId),
+    Report(Uuid),
+    Rule(Uuid),
+    IpBlock(Uuid),
+    LegalDocument(Uuid),
+    FederationDomain(Uuid),
+    BlocklistSubscription(Uuid),
+}
+
+impl AuditTarget {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Account(_) => "account",
+            Self::Report(_) => "report",
+            Self::Rule(_) => "rule",
+            Self::IpBlock(_) => "ip_block",
+            Self::LegalDocument(_) => "legal_document",
+            Self::FederationDomain(_) => "federation_domain",
+            Self::BlocklistSubscription(_) => "blocklist_subscription",
+        }
+    }
+
+    pub fn id(&self) -> Uuid {
+        match self {
+            Self::Account(id) => id.as_uuid(),
+            Self::Report(id)
+            | Self::Rule(id)
+            | Self::IpBlock(id)
+            | Self::LegalDocument(id)
+            | Self::FederationDomain(id)
+            | Self::BlocklistSubscription(id) => *id,
+        }
+    }
+}
+
+/// One row of the moderation audit trail, never updated once written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub id: Uuid,
+    /// None when run from the command line
+    pub actor_id: Option<UserId>,
+    /// `<target kind>.<verb>`, e.g. `account.suspend`
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Uuid,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditLogEntry {
+    pub fn new(
+        actor_id: Option<UserId>,
+        verb: &str,
+        target: AuditTarget,
+        reason: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            actor_id,
+            action: format!("{}.{}", target.kind(), verb),
+            target_type: target.kind().to_string(),
+            target_id: target.id(),
+            reason: reason.filter(|reason| !reason.trim().is_empty()),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Criteria of the audit log listing, newest entries first
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogFilter {
+    pub actor_id: Option<UserId>,
+    pub action: Option<String>,
+    pub target_id: Option<Uuid>,
+    /// Only entries created before this instant (pagination)
+    pub before: Option<DateTime<Utc>>,
+    pub limit: u64,
+}
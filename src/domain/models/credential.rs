@@ -1,8 +1,21 @@
 use chrono::{DateTime, Utc};
-use sea_orm::prelude::Uuid;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::{error::DomainError, models::user::ActivityId};
+use crate::domain::{
+    error::DomainError,
+    models::{
+        email::EmailAddress,
+        user::{ActivityId, UserId},
+    },
+};
+
+/// Hash formats understood when verifying, new hashes are always Argon2id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    Argon2id,
+    /// Accounts imported from other software
+    Bcrypt,
+}
 
 /// Value object representing a hashed password
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -18,41 +31,58 @@ impl HashedPassword {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Format of the hash, read from its prefix (PHC string or modular crypt format)
+    pub fn algorithm(&self) -> Option<HashAlgorithm> {
+        let prefix = self.0.split('$').nth(1)?;
+        match prefix {
+            "argon2id" => Some(HashAlgorithm::Argon2id),
+            "2a" | "2b" | "2y" => Some(HashAlgorithm::Bcrypt),
+            _ => None,
+        }
+    }
 }
 
+/// Login secret of a local account, there is one per user and it has no identity of its own
 #[derive(Debug, Clone)]
 pub struct Credential {
-    id: Uuid,
-    user_id: ActivityId,
+    user_id: UserId,
+    activity_id: ActivityId,
     password_hash: HashedPassword,
-    created_at: DateTime<Utc>,
+    email: EmailAddress,
     updated_at: DateTime<Utc>,
 }
 
 impl Credential {
-    pub fn new(id: Uuid, user_id: ActivityId, password_hash: HashedPassword) -> Self {
-        let now = Utc::now();
+    /// Only the test doubles build a fresh credential, sign-ups go through `UserRegistrationRepository`
+    #[cfg(test)]
+    pub fn new(
+        user_id: UserId,
+        activity_id: ActivityId,
+        password_hash: HashedPassword,
+        email: EmailAddress,
+    ) -> Self {
         Self {
-            id,
             user_id,
+            activity_id,
             password_hash,
-            created_at: now,
-            updated_at: now,
+            email,
+            updated_at: Utc::now(),
         }
     }
 
     pub fn reconstruct(
-        id: Uuid,
-        user_id: ActivityId,
+        user_id: UserId,
+        activity_id: ActivityId,
         password_hash: HashedPassword,
-        created_at: DateTime<Utc>,
+        email: EmailAddress,
         updated_at: DateTime<Utc>,
     ) -> Self {
         Self {
-            id,
             user_id,
+            activity_id,
             password_hash,
-            created_at,
+            email,
             updated_at,
         }
     }
@@ -70,23 +100,57 @@ impl Credential {
         self.updated_at = Utc::now();
     }
 
-    pub fn id(&self) -> Uuid {
-        self.id
+    /// Account the credential belongs to
+    pub fn user_id(&self) -> UserId {
+        self.user_id
     }
 
-    pub fn user_id(&self) -> &ActivityId {
-        &self.user_id
+    /// The database looks credentials up by column, only the in-memory repository reads it
+    #[allow(dead_code)]
+    pub fn activity_id(&self) -> &ActivityId {
+        &self.activity_id
     }
 
     pub fn password_hash(&self) -> &HashedPassword {
         &self.password_hash
     }
 
-    pub fn created_at(&self) -> DateTime<Utc> {
-        self.created_at
+    pub fn email(&self) -> &EmailAddress {
+        &self.email
     }
 
     pub fn updated_at(&self) -> DateTime<Utc> {
         self.updated_at
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_algorithm_is_detected_from_prefix() {
+        for (hash, algorithm) in [
+            (
+                "$argon2id$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA",
+                Some(HashAlgorithm::Argon2id),
+            ),
+            (
+                "$2b$12$R9h/cIPz0gi.URNNX3kh2OPST9/PgBkqquzi.Ss7KIUgO2t0jWMUW",
+                Some(HashAlgorithm::Bcrypt),
+            ),
+            (
+                "$2y$10$R9h/cIPz0gi.URNNX3kh2OPST9/PgBkqquzi.Ss7KIUgO2t0jWMUW",
+                Some(HashAlgorithm::Bcrypt),
+            ),
+            ("$argon2i$v=19$m=19456,t=2,p=1$c2FsdA$aGFzaA", None),
+            ("plain", None),
+        ] {
+            assert_eq!(
+                algorithm,
+                HashedPassword::new(hash.to_string()).algorithm(),
+                "{hash}"
+            );
+        }
+    }
+}
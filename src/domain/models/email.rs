@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::DomainError;
+
+/// Longest address accepted by SMTP (RFC 5321)
+const MAX_LENGTH: usize = 254;
+const MAX_LOCAL_LENGTH: usize = 64;
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Value object representing a syntactically valid, lowercased email address
+/// Deliverability is not checked here, see `MailDomainVerifier`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EmailAddress(String);
+
+impl EmailAddress {
+    pub fn new(value: String) -> Result<Self, DomainError> {
+        let value = value.trim().to_lowercase();
+        if value.len() > MAX_LENGTH {
+            return Err(DomainError::InvalidEmail);
+        }
+        let Some((local, domain)) = value.rsplit_once('@') else {
+            return Err(DomainError::InvalidEmail);
+        };
+        if !is_valid_local_part(local) || !is_valid_domain(domain) {
+            return Err(DomainError::InvalidEmail);
+        }
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Part after the `@`
+    pub fn domain(&self) -> &str {
+        self.0
+            .rsplit_once('@')
+            .map(|(_, domain)| domain)
+            .unwrap_or_default()
+    }
+}
+
+/// helper function that check the dot-atom form of the local part (quoted strings are refused)
+fn is_valid_local_part(local: &str) -> bool {
+    !local.is_empty()
+        && local.len() <= MAX_LOCAL_LENGTH
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '.' || "!#$%&'*+/=?^_`{|}~-".contains(c))
+}
+
+/// helper function that check the domain is a dotted host name (address literals are refused)
+fn is_valid_domain(domain: &str) -> bool {
+    domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= MAX_LABEL_LENGTH
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Addresses in the accepted dot-atom form, mixed case
+    fn valid_email() -> impl Strategy<Value = String> {
+        (
+            "[A-Za-z0-9+_-]{1,20}(\\.[A-Za-z0-9+_-]{1,20}){0,2}",
+            "[A-Za-z0-9]{1,20}(-[A-Za-z0-9]{1,10})?(\\.[A-Za-z]{2,10}){1,3}",
+        )
+            .prop_map(|(local, domain)| format!("{local}@{domain}"))
+    }
+
+    proptest! {
+        #[test]
+        fn test_valid_email_round_trips(value in valid_email()) {
+            let email = EmailAddress::new(value.clone()).unwrap();
+
+            prop_assert_eq!(value.to_lowercase(), email.as_str());
+            prop_assert_eq!(&email, &EmailAddress::new(email.as_str().to_string()).unwrap());
+            let json = serde_json::to_string(&email).unwrap();
+            prop_assert_eq!(&email, &serde_json::from_str::<EmailAddress>(&json).unwrap());
+        }
+
+        #[test]
+        fn test_accepted_email_is_normalized(value in "\\PC{0,80}") {
+            if let Ok(email) = EmailAddress::new(value) {
+                let (local, domain) = email.as_str().rsplit_once('@').unwrap();
+                prop_assert!(email.as_str().len() <= MAX_LENGTH);
+                prop_assert_eq!(email.as_str().to_lowercase(), email.as_str());
+                prop_assert!(is_valid_local_part(local));
+                prop_assert!(is_valid_domain(domain));
+            }
+        }
+
+        #[test]
+        fn test_email_without_at_is_rejected(value in "[^@]{0,80}") {
+            prop_assert!(EmailAddress::new(value).is_err());
+        }
+    }
+
+    #[test]
+    fn test_email_is_normalized() {
+        let email = EmailAddress::new("  Alice.Smith+tag@Example.COM ".to_string()).unwrap();
+
+        assert_eq!("alice.smith+tag@example.com", email.as_str());
+        assert_eq!("example.com", email.domain());
+    }
+
+    #[test]
+    fn test_email_rejects_invalid_syntax() {
+        for value in [
+            "",
+            "alice",
+            "@example.com",
+            "alice@",
+            "alice@localhost",
+            "alice@@example.com",
+            ".alice@example.com",
+            "al..ice@example.com",
+            "alice smith@example.com",
+            "alice@-example.com",
+            "alice@example..com",
+            "alice@[127.0.0.1]",
+        ] {
+            assert!(
+                matches!(
+                    EmailAddress::new(value.to_string()),
+                    Err(DomainError::InvalidEmail)
+                ),
+                "{value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_email_rejects_overlong_local_part() {
+        let value = format!("{}@example.com", "a".repeat(MAX_LOCAL_LENGTH + 1));
+
+        assert!(EmailAddress::new(value).is_err());
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::{secret, user::UserId};
+
+/// Pending confirmation of the address given at sign-up
+/// The account cannot log in while it exists, it is removed once the link is opened
+#[derive(Debug, Clone)]
+pub struct EmailVerification {
+    pub user_id: UserId,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl EmailVerification {
+    /// New verification for the account, returned along with the token sent by email
+    pub fn issue(user_id: UserId) -> (Self, String) {
+        let value = secret::generate();
+        let verification = Self {
+            user_id,
+            token_hash: secret::digest(&value),
+            created_at: Utc::now(),
+        };
+        (verification, value)
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    error::DomainError,
+    models::user::{User, UserId},
+};
+
+/// Most accounts a user may feature on their profile
+pub const MAX_ENDORSEMENTS: usize = 20;
+
+/// Another account featured on a user's profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endorsement {
+    pub user_id: UserId,
+    pub target_id: UserId,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Endorsement {
+    pub fn new(user: &User, target: &User) -> Result<Self, DomainError> {
+        if user.id() == target.id() {
+            return Err(DomainError::InvalidEndorsement);
+        }
+        Ok(Self {
+            user_id: user.id(),
+            target_id: target.id(),
+            created_at: Utc::now(),
+        })
+    }
+}
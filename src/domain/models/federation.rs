@@ -0,0 +1,284 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+
+/// Which remote domains the instance talks to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FederationMode {
+    /// Every domain except the blocked ones (default)
+    Blocklist,
+    /// Only allowed domains, blocks still apply to their subdomains
+    Allowlist,
+}
+
+impl FederationMode {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "blocklist" => Ok(Self::Blocklist),
+            "allowlist" => Ok(Self::Allowlist),
+            _ => Err(DomainError::InvalidFederationDomain),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Blocklist => "blocklist",
+            Self::Allowlist => "allowlist",
+        }
+    }
+
+    /// Whether `host` may be federated with, given the admin-managed domain list
+    pub fn allows(&self, host: &str, domains: &[FederationDomain]) -> bool {
+        let host = normalize_domain(host);
+        let covered = |policy| {
+            domains
+                .iter()
+                .any(|domain| domain.policy == policy && domain.covers(&host))
+        };
+        if covered(DomainPolicy::Block) {
+            return false;
+        }
+        match self {
+            Self::Blocklist => true,
+            Self::Allowlist => covered(DomainPolicy::Allow),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DomainPolicy {
+    Block,
+    Allow,
+}
+
+impl DomainPolicy {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "block" => Ok(Self::Block),
+            "allow" => Ok(Self::Allow),
+            _ => Err(DomainError::InvalidFederationDomain),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Block => "block",
+            Self::Allow => "allow",
+        }
+    }
+}
+
+/// Admin-managed entry of the block or allow list, covering the domain and its subdomains
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FederationDomain {
+    pub id: Uuid,
+    pub domain: String,
+    pub policy: DomainPolicy,
+    pub comment: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FederationDomain {
+    pub fn new(domain: &str, policy: DomainPolicy, comment: String) -> Result<Self, DomainError> {
+        let domain = normalize_domain(domain);
+        if !is_valid_domain(&domain) {
+            return Err(DomainError::InvalidFederationDomain);
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            domain,
+            policy,
+            comment,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Whether a normalized host is the domain itself or one of its subdomains
+    pub fn covers(&self, host: &str) -> bool {
+        host == self.domain
+            || host
+                .strip_suffix(self.domain.as_str())
+                .is_some_and(|prefix| prefix.ends_with('.'))
+    }
+}
+
+/// Shared blocklist followed by the instance, synced periodically
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlocklistSubscription {
+    pub id: Uuid,
+    /// CSV in the format of a Mastodon domain block export
+    pub url: String,
+    /// New entries wait for an admin instead of being applied by the periodic sync
+    pub review: bool,
+    /// Entries of the last sync that are not blocked yet, while `review` holds them back
+    pub pending: usize,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    /// Why the last sync failed, cleared by the next successful one
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl BlocklistSubscription {
+    pub fn new(url: &str, review: bool) -> Result<Self, DomainError> {
+        Ok(Self {
+            id: Uuid::new_v4(),
+            url: blocklist_url(url)?,
+            review,
+            pending: 0,
+            last_synced_at: None,
+            last_error: None,
+            created_at: Utc::now(),
+        })
+    }
+
+    pub fn record_sync(&mut self, pending: usize) {
+        self.pending = pending;
+        self.last_synced_at = Some(Utc::now());
+        self.last_error = None;
+    }
+
+    pub fn record_failure(&mut self, error: String) {
+        self.last_synced_at = Some(Utc::now());
+        self.last_error = Some(error);
+    }
+}
+
+/// Row of a shared blocklist, the headers of a Mastodon domain block export are accepted
+#[derive(Deserialize)]
+struct BlocklistRow {
+    #[serde(alias = "#domain")]
+    domain: String,
+    /// `suspend` when omitted, `silence` and `noop` rows are left out
+    #[serde(default, alias = "#severity")]
+    severity: Option<String>,
+    #[serde(default, alias = "#public_comment")]
+    public_comment: Option<String>,
+}
+
+/// Read the `(domain, comment)` pairs to block from a CSV blocklist
+pub fn parse_blocklist(body: &str) -> Result<Vec<(String, String)>, DomainError> {
+    let mut reader = csv::Reader::from_reader(body.as_bytes());
+    let mut entries = Vec::new();
+    for row in reader.deserialize::<BlocklistRow>() {
+        let row = row.map_err(|_| DomainError::InvalidFederationDomain)?;
+        if row.severity.as_deref().is_some_and(|s| s != "suspend") {
+            continue;
+        }
+        entries.push((row.domain, row.public_comment.unwrap_or_default()));
+    }
+    Ok(entries)
+}
+
+/// Check the address a blocklist is fetched from, only absolute http(s) URLs are accepted
+/// Private destinations are refused later by the outbound client
+pub fn blocklist_url(url: &str) -> Result<String, DomainError> {
+    let url = url.trim();
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .and_then(|rest| rest.split(['/', '?', '#']).next())
+        .ok_or(DomainError::InvalidBlocklistUrl)?;
+    if host.is_empty() || url.len() > 2048 || url.chars().any(char::is_whitespace) {
+        return Err(DomainError::InvalidBlocklistUrl);
+    }
+    Ok(url.to_string())
+}
+
+/// helper function that lowercase a host name and drop the root dot
+fn normalize_domain(value: &str) -> String {
+    value.trim().trim_end_matches('.').to_lowercase()
+}
+
+/// helper function that check a dotted host name, without scheme, port or path
+fn is_valid_domain(domain: &str) -> bool {
+    domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Description
+    /// Valid list entry for `domain`
+    fn entry(domain: &str, policy: DomainPolicy) -> FederationDomain {
+        FederationDomain::new(domain, policy, String::new()).unwrap()
+    }
+
+    #[test]
+    fn test_blocklist_covers_subdomains() {
+        let domains = [entry("Spam.Example.", DomainPolicy::Block)];
+
+        assert!(!FederationMode::Blocklist.allows("spam.example", &domains));
+        assert!(!FederationMode::Blocklist.allows("media.spam.example", &domains));
+        assert!(FederationMode::Blocklist.allows("notspam.example", &domains));
+    }
+
+    #[test]
+    fn test_allowlist_requires_allowed_domain() {
+        let domains = [
+            entry("friends.example", DomainPolicy::Allow),
+            entry("bad.friends.example", DomainPolicy::Block),
+        ];
+
+        assert!(FederationMode::Allowlist.allows("friends.example", &domains));
+        assert!(!FederationMode::Allowlist.allows("bad.friends.example", &domains));
+        assert!(!FederationMode::Allowlist.allows("stranger.example", &domains));
+    }
+
+    #[test]
+    fn test_parse_blocklist_keeps_suspensions() {
+        let csv = "#domain,#severity,#public_comment\n\
+                   spam.example,suspend,spam\n\
+                   noisy.example,silence,\n\
+                   bad.example,,\n";
+
+        let entries = parse_blocklist(csv).unwrap();
+
+        assert_eq!(
+            vec![
+                ("spam.example".to_string(), "spam".to_string()),
+                ("bad.example".to_string(), String::new()),
+            ],
+            entries
+        );
+    }
+
+    #[test]
+    fn test_invalid_blocklist_url_is_rejected() {
+        assert!(blocklist_url(" https://lists.example/blocks.csv ").is_ok());
+        for value in [
+            "",
+            "lists.example/blocks.csv",
+            "ftp://lists.example/blocks.csv",
+            "https:///blocks.csv",
+            "https://lists.example/some blocks.csv",
+        ] {
+            assert!(blocklist_url(value).is_err(), "{value} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_invalid_domain_is_rejected() {
+        for value in [
+            "",
+            "localhost",
+            "https://remote.example",
+            "remote.example:443",
+        ] {
+            assert!(
+                FederationDomain::new(value, DomainPolicy::Block, String::new()).is_err(),
+                "{value} should be rejected"
+            );
+        }
+    }
+}
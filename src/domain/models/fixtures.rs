@@ -0,0 +1,88 @@
+//! Builders producing valid domain models for tests
+
+use uuid::Uuid;
+
+use crate::domain::models::{
+    credential::{Credential, HashedPassword},
+    email::EmailAddress,
+    user::{ActivityId, User, UserId},
+};
+
+pub const TEST_HOST: &str = "example.com";
+
+/// helper function that build the activity id of a local user
+pub fn activity_id(host: &str, username: &str) -> ActivityId {
+    ActivityId::new(format!("https://{}/users/{}", host, username)).unwrap()
+}
+
+pub struct UserBuilder {
+    id: UserId,
+    activity_id: ActivityId,
+    display_name: String,
+    icon_url: Option<String>,
+}
+
+impl Default for UserBuilder {
+    fn default() -> Self {
+        Self {
+            id: UserId::new(Uuid::new_v4()),
+            activity_id: activity_id(TEST_HOST, "test_user"),
+            display_name: "テスト".to_string(),
+            icon_url: None,
+        }
+    }
+}
+
+impl UserBuilder {
+    pub fn activity_id(mut self, activity_id: ActivityId) -> Self {
+        self.activity_id = activity_id;
+        self
+    }
+
+    pub fn build(self) -> User {
+        User::new(self.id, self.activity_id, self.display_name, self.icon_url).unwrap()
+    }
+}
+
+pub struct CredentialBuilder {
+    user_id: UserId,
+    activity_id: ActivityId,
+    password_hash: HashedPassword,
+    email: EmailAddress,
+}
+
+impl Default for CredentialBuilder {
+    fn default() -> Self {
+        Self {
+            user_id: UserId::new(Uuid::new_v4()),
+            activity_id: activity_id(TEST_HOST, "test_user"),
+            password_hash: HashedPassword::new(String::new()),
+            email: EmailAddress::new("test@example.com".to_string()).unwrap(),
+        }
+    }
+}
+
+impl CredentialBuilder {
+    /// Belong to `user`, with its activity id
+    pub fn for_user(user: &User) -> Self {
+        Self {
+            user_id: user.id(),
+            activity_id: user.activity_id().clone(),
+            ..Self::default()
+        }
+    }
+
+    pub fn activity_id(mut self, activity_id: ActivityId) -> Self {
+        self.activity_id = activity_id;
+        self
+    }
+
+    pub fn password_hash(mut self, password_hash: HashedPassword) -> Self {
+        self.password_hash = password_hash;
+        self
+    }
+
+    pub fn build(self) -> Credential {
+        Credential::new(self.user_id, self.activity_id, self.password_hash, self.email)
+    }
+}
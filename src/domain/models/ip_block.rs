@@ -0,0 +1,145 @@
+use std::net::IpAddr;
+
+use chrono::{DateTime, Utc};
+use ipnet::IpNet;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+
+/// What a matching address is prevented from doing, ordered from mildest to strictest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum IpBlockSeverity {
+    /// Accounts created from the address wait for moderator approval
+    SignUpRequiresApproval,
+    SignUpBlock,
+    NoAccess,
+}
+
+impl IpBlockSeverity {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "sign_up_requires_approval" => Ok(Self::SignUpRequiresApproval),
+            "sign_up_block" => Ok(Self::SignUpBlock),
+            "no_access" => Ok(Self::NoAccess),
+            _ => Err(DomainError::InvalidIpBlock),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SignUpRequiresApproval => "sign_up_requires_approval",
+            Self::SignUpBlock => "sign_up_block",
+            Self::NoAccess => "no_access",
+        }
+    }
+}
+
+/// Admin-managed rule restricting an address range
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IpBlock {
+    pub id: Uuid,
+    pub network: IpNet,
+    pub severity: IpBlockSeverity,
+    pub comment: String,
+    /// Never expires when None
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl IpBlock {
+    /// `network` is a CIDR range or a single address
+    pub fn new(
+        network: &str,
+        severity: IpBlockSeverity,
+        comment: String,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, DomainError> {
+        let now = Utc::now();
+        if expires_at.is_some_and(|at| at <= now) {
+            return Err(DomainError::InvalidIpBlock);
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            network: parse_network(network)?,
+            severity,
+            comment,
+            expires_at,
+            created_at: now,
+        })
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_none_or(|at| now < at)
+    }
+
+    pub fn matches(&self, ip: IpAddr, now: DateTime<Utc>) -> bool {
+        self.is_active(now) && self.network.contains(&ip)
+    }
+}
+
+/// Parse a CIDR range, accepting a bare address as a single-host range
+pub fn parse_network(value: &str) -> Result<IpNet, DomainError> {
+    value
+        .parse::<IpNet>()
+        .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+        .map(|network| network.trunc())
+        .map_err(|_| DomainError::InvalidIpBlock)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_matches_range_positive() {
+        let block = IpBlock::new(
+            "203.0.113.0/24",
+            IpBlockSeverity::NoAccess,
+            String::new(),
+            None,
+        )
+        .unwrap();
+
+        assert!(block.matches("203.0.113.7".parse().unwrap(), Utc::now()));
+        assert!(!block.matches("203.0.114.7".parse().unwrap(), Utc::now()));
+    }
+
+    #[test]
+    fn test_single_address_positive() {
+        let block = IpBlock::new(
+            "2001:db8::1",
+            IpBlockSeverity::SignUpBlock,
+            String::new(),
+            None,
+        )
+        .unwrap();
+
+        assert!(block.matches("2001:db8::1".parse().unwrap(), Utc::now()));
+        assert!(!block.matches("2001:db8::2".parse().unwrap(), Utc::now()));
+    }
+
+    #[test]
+    fn test_expired_block_negative() {
+        let expires_at = Utc::now() + Duration::hours(1);
+        let block = IpBlock::new(
+            "203.0.113.0/24",
+            IpBlockSeverity::NoAccess,
+            String::new(),
+            Some(expires_at),
+        )
+        .unwrap();
+
+        assert!(!block.matches("203.0.113.7".parse().unwrap(), expires_at));
+    }
+
+    #[test]
+    fn test_invalid_network_negative() {
+        let result = IpBlock::new("not-an-ip", IpBlockSeverity::NoAccess, String::new(), None);
+
+        assert!(matches!(result, Err(DomainError::InvalidIpBlock)));
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{error::DomainError, models::user::UserId};
+
+/// Documents users agree to when signing up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LegalDocumentKind {
+    TermsOfService,
+    PrivacyPolicy,
+}
+
+impl LegalDocumentKind {
+    pub const ALL: [Self; 2] = [Self::TermsOfService, Self::PrivacyPolicy];
+
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "terms_of_service" => Ok(Self::TermsOfService),
+            "privacy_policy" => Ok(Self::PrivacyPolicy),
+            _ => Err(DomainError::InvalidLegalDocument),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TermsOfService => "terms_of_service",
+            Self::PrivacyPolicy => "privacy_policy",
+        }
+    }
+}
+
+/// Published version of a legal document, never edited in place
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegalDocument {
+    pub id: Uuid,
+    pub kind: LegalDocumentKind,
+    /// Starts at 1, incremented on every publication
+    pub version: i32,
+    pub content: String,
+    pub published_at: DateTime<Utc>,
+}
+
+impl LegalDocument {
+    /// Publish `content` as the version following `previous`
+    pub fn publish(
+        kind: LegalDocumentKind,
+        content: String,
+        previous: Option<&LegalDocument>,
+    ) -> Result<Self, DomainError> {
+        if content.trim().is_empty() {
+            return Err(DomainError::InvalidLegalDocument);
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            kind,
+            version: previous.map_or(1, |previous| previous.version + 1),
+            content,
+            published_at: Utc::now(),
+        })
+    }
+}
+
+/// Latest version of a document a user agreed to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegalAcceptance {
+    pub user_id: UserId,
+    pub kind: LegalDocumentKind,
+    pub version: i32,
+    pub accepted_at: DateTime<Utc>,
+}
+
+impl LegalAcceptance {
+    pub fn of(user_id: UserId, document: &LegalDocument) -> Self {
+        Self {
+            user_id,
+            kind: document.kind,
+            version: document.version,
+            accepted_at: Utc::now(),
+        }
+    }
+}
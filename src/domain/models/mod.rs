@@ -1,2 +1,21 @@
+pub mod alias;
+pub mod audit_log;
 pub mod credential;
+pub mod email;
+pub mod email_verification;
+pub mod endorsement;
+pub mod federation;
+#[cfg(test)]
+pub mod fixtures;
+pub mod ip_block;
+pub mod legal;
+pub mod moderation;
+pub mod notification_preferences;
+pub mod posting_preferences;
+pub mod profile_details;
+pub mod refresh_token;
+pub mod report;
+pub mod rule;
+pub mod secret;
+pub mod tombstone;
 pub mod user;
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Restrictions moderators placed on an account
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Moderation {
+    /// Login refused, content stays visible
+    pub disabled: bool,
+    /// Hidden from public timelines
+    pub silenced_at: Option<DateTime<Utc>>,
+    /// Hidden everywhere, activities from and to the account are rejected
+    pub suspended_at: Option<DateTime<Utc>>,
+}
+
+impl Moderation {
+    pub fn is_silenced(&self) -> bool {
+        self.silenced_at.is_some()
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    /// Whether the owner may log in or use an issued token
+    pub fn can_login(&self) -> bool {
+        !self.disabled && !self.is_suspended()
+    }
+
+    pub fn apply(&mut self, action: ModerationAction) {
+        let now = Utc::now();
+        match action {
+            ModerationAction::Disable => self.disabled = true,
+            ModerationAction::Enable => self.disabled = false,
+            ModerationAction::Silence => self.silenced_at = self.silenced_at.or(Some(now)),
+            ModerationAction::Unsilence => self.silenced_at = None,
+            ModerationAction::Suspend => self.suspended_at = self.suspended_at.or(Some(now)),
+            ModerationAction::Unsuspend => self.suspended_at = None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModerationAction {
+    Disable,
+    Enable,
+    Silence,
+    Unsilence,
+    Suspend,
+    Unsuspend,
+}
+
+impl ModerationAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::Enable => "enable",
+            Self::Silence => "silence",
+            Self::Unsilence => "unsilence",
+            Self::Suspend => "suspend",
+            Self::Unsuspend => "unsuspend",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspend_blocks_login() {
+        let mut moderation = Moderation::default();
+        assert!(moderation.can_login());
+
+        moderation.apply(ModerationAction::Suspend);
+        assert!(!moderation.can_login());
+
+        moderation.apply(ModerationAction::Unsuspend);
+        assert!(moderation.can_login());
+    }
+
+    #[test]
+    fn test_silence_keeps_first_timestamp() {
+        let mut moderation = Moderation::default();
+        moderation.apply(ModerationAction::Silence);
+        let first = moderation.silenced_at;
+
+        moderation.apply(ModerationAction::Silence);
+
+        assert_eq!(first, moderation.silenced_at);
+        assert!(moderation.can_login());
+    }
+}
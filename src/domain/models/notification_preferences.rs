@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::domain::{error::DomainError, models::user::UserId};
+
+/// How often pending notifications are bundled into a single email
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DigestFrequency {
+    /// Every enabled event is emailed as it happens
+    Never,
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "never" => Ok(Self::Never),
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            _ => Err(DomainError::InvalidNotificationPreferences),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Never => "never",
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+        }
+    }
+}
+
+/// Which events a user wants to hear about by email
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationPreferences {
+    pub user_id: UserId,
+    pub email_on_follow: bool,
+    pub email_on_mention: bool,
+    pub email_on_report_resolved: bool,
+    pub digest: DigestFrequency,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Partial update, omitted fields keep their value
+#[derive(Debug, Clone, Default)]
+pub struct NotificationPreferencesUpdate {
+    pub email_on_follow: Option<bool>,
+    pub email_on_mention: Option<bool>,
+    pub email_on_report_resolved: Option<bool>,
+    pub digest: Option<DigestFrequency>,
+}
+
+impl NotificationPreferences {
+    /// Preferences of a user who never changed them
+    pub fn default_for(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            email_on_follow: true,
+            email_on_mention: true,
+            email_on_report_resolved: true,
+            digest: DigestFrequency::Never,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn apply(&mut self, update: NotificationPreferencesUpdate) {
+        if let Some(value) = update.email_on_follow {
+            self.email_on_follow = value;
+        }
+        if let Some(value) = update.email_on_mention {
+            self.email_on_mention = value;
+        }
+        if let Some(value) = update.email_on_report_resolved {
+            self.email_on_report_resolved = value;
+        }
+        if let Some(value) = update.digest {
+            self.digest = value;
+        }
+        self.updated_at = Utc::now();
+    }
+}
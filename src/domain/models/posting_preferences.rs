@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{error::DomainError, models::user::UserId};
+
+/// Who can see a status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Visibility {
+    Public,
+    /// Public, but left out of public timelines
+    Unlisted,
+    /// Followers only
+    Private,
+    /// Mentioned accounts only
+    Direct,
+}
+
+impl Visibility {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "public" => Ok(Self::Public),
+            "unlisted" => Ok(Self::Unlisted),
+            "private" => Ok(Self::Private),
+            "direct" => Ok(Self::Direct),
+            _ => Err(DomainError::InvalidPostingPreferences),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Unlisted => "unlisted",
+            Self::Private => "private",
+            Self::Direct => "direct",
+        }
+    }
+}
+
+/// Settings applied to a new status when the client omits them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostingPreferences {
+    pub user_id: UserId,
+    pub default_visibility: Visibility,
+    /// ISO 639 code
+    pub default_language: Option<String>,
+    /// Statuses are marked sensitive, media hidden behind a warning
+    pub default_sensitive: bool,
+    /// Media is marked sensitive even on statuses that are not
+    pub mark_media_sensitive: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Partial update, omitted fields keep their value
+#[derive(Debug, Clone, Default)]
+pub struct PostingPreferencesUpdate {
+    pub default_visibility: Option<Visibility>,
+    /// An empty string clears the language
+    pub default_language: Option<String>,
+    pub default_sensitive: Option<bool>,
+    pub mark_media_sensitive: Option<bool>,
+}
+
+impl PostingPreferencesUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.default_visibility.is_none()
+            && self.default_language.is_none()
+            && self.default_sensitive.is_none()
+            && self.mark_media_sensitive.is_none()
+    }
+}
+
+impl PostingPreferences {
+    /// Preferences of a user who never changed them
+    pub fn default_for(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            default_visibility: Visibility::Public,
+            default_language: None,
+            default_sensitive: false,
+            mark_media_sensitive: false,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn apply(&mut self, update: PostingPreferencesUpdate) -> Result<(), DomainError> {
+        if let Some(language) = update.default_language {
+            let language = language.trim().to_lowercase();
+            if !language.is_empty() && !is_language_code(&language) {
+                return Err(DomainError::InvalidPostingPreferences);
+            }
+            self.default_language = Some(language).filter(|language| !language.is_empty());
+        }
+        if let Some(value) = update.default_visibility {
+            self.default_visibility = value;
+        }
+        if let Some(value) = update.default_sensitive {
+            self.default_sensitive = value;
+        }
+        if let Some(value) = update.mark_media_sensitive {
+            self.mark_media_sensitive = value;
+        }
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// helper function that check the shape of an ISO 639-1 or 639-3 code
+fn is_language_code(value: &str) -> bool {
+    (2..=3).contains(&value.len()) && value.chars().all(|c| c.is_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_language_is_normalized_and_cleared() {
+        let mut preferences = PostingPreferences::default_for(UserId::new(Uuid::new_v4()));
+
+        preferences
+            .apply(PostingPreferencesUpdate {
+                default_language: Some(" JA ".to_string()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(Some("ja"), preferences.default_language.as_deref());
+
+        preferences
+            .apply(PostingPreferencesUpdate {
+                default_language: Some(String::new()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(None, preferences.default_language);
+    }
+
+    #[test]
+    fn test_invalid_language_is_rejected() {
+        let mut preferences = PostingPreferences::default_for(UserId::new(Uuid::new_v4()));
+
+        let result = preferences.apply(PostingPreferencesUpdate {
+            default_language: Some("english".to_string()),
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            result,
+            Err(DomainError::InvalidPostingPreferences)
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::DomainError;
+
+const MAX_LOCATION_LENGTH: usize = 100;
+
+/// How much of the birthday others see
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BirthdayVisibility {
+    #[default]
+    Hidden,
+    /// Month and day, without the year
+    MonthDay,
+    Full,
+}
+
+impl BirthdayVisibility {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "hidden" => Ok(Self::Hidden),
+            "month_day" => Ok(Self::MonthDay),
+            "full" => Ok(Self::Full),
+            _ => Err(DomainError::InvalidProfileDetails),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hidden => "hidden",
+            Self::MonthDay => "month_day",
+            Self::Full => "full",
+        }
+    }
+}
+
+/// Optional structured profile fields, each one private until the owner opts in
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileDetails {
+    pub birthday: Option<NaiveDate>,
+    pub birthday_visibility: BirthdayVisibility,
+    pub location: Option<String>,
+    pub location_visible: bool,
+}
+
+impl ProfileDetails {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self
+            .birthday
+            .is_some_and(|birthday| birthday > Utc::now().date_naive())
+        {
+            return Err(DomainError::InvalidProfileDetails);
+        }
+        if self
+            .location
+            .as_ref()
+            .is_some_and(|location| location.chars().count() > MAX_LOCATION_LENGTH)
+        {
+            return Err(DomainError::InvalidProfileDetails);
+        }
+        Ok(())
+    }
+
+    /// Birthday as shown to others, `--MM-DD` when the year is hidden (ISO 8601)
+    pub fn public_birthday(&self) -> Option<String> {
+        let birthday = self.birthday?;
+        match self.birthday_visibility {
+            BirthdayVisibility::Hidden => None,
+            BirthdayVisibility::MonthDay => {
+                Some(format!("--{:02}-{:02}", birthday.month(), birthday.day()))
+            }
+            BirthdayVisibility::Full => Some(birthday.format("%Y-%m-%d").to_string()),
+        }
+    }
+
+    /// Location as shown to others
+    pub fn public_location(&self) -> Option<&str> {
+        self.location.as_deref().filter(|_| self.location_visible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// # Description
+    /// Details with a birthday and a location, both still hidden
+    fn details() -> ProfileDetails {
+        ProfileDetails {
+            birthday: NaiveDate::from_ymd_opt(1990, 4, 1),
+            location: Some("Tokyo".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_details_hidden_by_default() {
+        let details = details();
+
+        assert_eq!(None, details.public_birthday());
+        assert_eq!(None, details.public_location());
+    }
+
+    #[test]
+    fn test_details_shown_after_opt_in() {
+        let mut details = details();
+        details.birthday_visibility = BirthdayVisibility::MonthDay;
+        details.location_visible = true;
+        assert_eq!(Some("--04-01".to_string()), details.public_birthday());
+        assert_eq!(Some("Tokyo"), details.public_location());
+
+        details.birthday_visibility = BirthdayVisibility::Full;
+        assert_eq!(Some("1990-04-01".to_string()), details.public_birthday());
+    }
+
+    #[test]
+    fn test_details_reject_future_birthday() {
+        let details = ProfileDetails {
+            birthday: Some(Utc::now().date_naive() + chrono::Days::new(1)),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            details.validate(),
+            Err(DomainError::InvalidProfileDetails)
+        ));
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::models::{secret, user::UserId};
+
+/// How long a refresh token can be exchanged, every exchange issues a new one
+pub const REFRESH_TOKEN_TTL_DAYS: i64 = 30;
+
+/// Opaque token exchanged for a new access token, then revoked
+/// Only its digest is stored, the value is handed to the client once
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub token_hash: String,
+    /// Shared by the tokens rotated from the same login, revoked together on reuse
+    pub family_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RefreshToken {
+    /// First token of a login, returned along with its value
+    pub fn issue(user_id: UserId) -> (Self, String) {
+        Self::generate(user_id, Uuid::new_v4())
+    }
+
+    /// Successor of this token in its family, returned along with its value
+    pub fn rotate(&self) -> (Self, String) {
+        Self::generate(self.user_id, self.family_id)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    fn generate(user_id: UserId, family_id: Uuid) -> (Self, String) {
+        let value = secret::generate();
+        let now = Utc::now();
+        let token = Self {
+            id: Uuid::new_v4(),
+            user_id,
+            token_hash: secret::digest(&value),
+            family_id,
+            expires_at: now + Duration::days(REFRESH_TOKEN_TTL_DAYS),
+            revoked_at: None,
+            created_at: now,
+        };
+        (token, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_keeps_family() {
+        let (first, first_value) = RefreshToken::issue(UserId::from(Uuid::new_v4()));
+        let (second, second_value) = first.rotate();
+
+        assert_eq!(first.family_id, second.family_id);
+        assert_eq!(first.user_id, second.user_id);
+        assert_ne!(first_value, second_value);
+        assert_eq!(secret::digest(&second_value), second.token_hash);
+        assert!(!second.is_expired());
+    }
+}
@@ -0,0 +1,135 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{error::DomainError, models::user::UserId};
+
+/// Longest comment a reporter can attach
+pub const MAX_REPORT_COMMENT_LENGTH: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportCategory {
+    Spam,
+    Legal,
+    Violation,
+    Other,
+}
+
+impl ReportCategory {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "spam" => Ok(Self::Spam),
+            "legal" => Ok(Self::Legal),
+            "violation" => Ok(Self::Violation),
+            "other" => Ok(Self::Other),
+            _ => Err(DomainError::InvalidReport),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spam => "spam",
+            Self::Legal => "legal",
+            Self::Violation => "violation",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// Complaint about an account, handled by moderators
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub id: Uuid,
+    pub reporter_id: UserId,
+    pub target_account_id: UserId,
+    pub category: ReportCategory,
+    pub comment: String,
+    /// Rules allegedly violated, only with the `Violation` category
+    pub rule_ids: Vec<Uuid>,
+    pub assigned_account_id: Option<UserId>,
+    pub action_taken_at: Option<DateTime<Utc>>,
+    pub action_taken_by_id: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Report {
+    pub fn new(
+        reporter_id: UserId,
+        target_account_id: UserId,
+        category: ReportCategory,
+        comment: String,
+        rule_ids: Vec<Uuid>,
+    ) -> Result<Self, DomainError> {
+        if reporter_id == target_account_id
+            || comment.chars().count() > MAX_REPORT_COMMENT_LENGTH
+            // a violation names the rules, other categories cannot
+            || (category == ReportCategory::Violation) == rule_ids.is_empty()
+        {
+            return Err(DomainError::InvalidReport);
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            reporter_id,
+            target_account_id,
+            category,
+            comment,
+            rule_ids,
+            assigned_account_id: None,
+            action_taken_at: None,
+            action_taken_by_id: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.action_taken_at.is_some()
+    }
+
+    pub fn assign(&mut self, account_id: Option<UserId>) {
+        self.assigned_account_id = account_id;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn resolve(&mut self, moderator_id: UserId) {
+        let now = Utc::now();
+        self.action_taken_at = Some(now);
+        self.action_taken_by_id = Some(moderator_id);
+        self.updated_at = now;
+    }
+
+    pub fn reopen(&mut self) {
+        self.action_taken_at = None;
+        self.action_taken_by_id = None;
+        self.updated_at = Utc::now();
+    }
+}
+
+/// Internal note left by a moderator on a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportNote {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub author_id: UserId,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReportNote {
+    pub fn new(report_id: Uuid, author_id: UserId, content: String) -> Result<Self, DomainError> {
+        if content.trim().is_empty() {
+            return Err(DomainError::InvalidReport);
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            report_id,
+            author_id,
+            content,
+            created_at: Utc::now(),
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+
+/// Longest rule text, rules are meant to be short statements
+pub const MAX_RULE_TEXT_LENGTH: usize = 300;
+
+/// Server rule shown at signup and selectable when filing a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rule {
+    pub id: Uuid,
+    pub text: String,
+    /// Longer explanation shown under the rule
+    pub hint: String,
+    /// Display order, ascending
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Rule {
+    pub fn new(text: String, hint: String, position: i32) -> Result<Self, DomainError> {
+        validate_text(&text)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            text,
+            hint,
+            position,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn update(&mut self, text: String, hint: String, position: i32) -> Result<(), DomainError> {
+        validate_text(&text)?;
+
+        self.text = text;
+        self.hint = hint;
+        self.position = position;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// helper function that check the rule text is present and short
+fn validate_text(text: &str) -> Result<(), DomainError> {
+    if text.trim().is_empty() || text.chars().count() > MAX_RULE_TEXT_LENGTH {
+        return Err(DomainError::InvalidRule);
+    }
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+
+/// What remains of deleted content, served with 410 Gone so remote servers drop their copy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tombstone {
+    /// ActivityPub id of the deleted object
+    pub id: String,
+    /// ActivityStreams type the object had (`Person`, ...)
+    pub former_type: &'static str,
+    pub deleted_at: DateTime<Utc>,
+}
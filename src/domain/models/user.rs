@@ -1,11 +1,44 @@
+use chrono::{DateTime, Duration, SubsecRound, Utc};
 use sea_orm::prelude::Uuid;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::error::DomainError;
+use crate::domain::{
+    error::DomainError,
+    models::{moderation::Moderation, profile_details::ProfileDetails, tombstone::Tombstone},
+};
+
+/// Days an account deleted by its owner can be reactivated before it is purged
+pub const DELETION_GRACE_DAYS: i64 = 30;
 
 pub type IconUrl = String;
 pub type DisplayName = String;
 
+/// Identifier of an account, the primary key of `users`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UserId(Uuid);
+
+impl UserId {
+    pub fn new(value: Uuid) -> Self {
+        Self(value)
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for UserId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl std::fmt::Display for UserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ActivityId(String);
 
@@ -20,19 +53,131 @@ impl ActivityId {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Last path segment, the username of local accounts
+    pub fn username(&self) -> &str {
+        self.0.rsplit('/').next().unwrap_or_default()
+    }
+}
+
+/// Accent color of a profile, lowercased `#rrggbb`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThemeColor(String);
+
+impl ThemeColor {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        let value = value.trim().to_ascii_lowercase();
+        match value.strip_prefix('#') {
+            Some(hex) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                Ok(Self(value))
+            }
+            _ => Err(DomainError::InvalidThemeColor),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Longest username accepted, the limit most fediverse servers share
+const MAX_USERNAME_LENGTH: usize = 30;
+
+/// Name of a local account, the last segment of its activity id
+/// Only ASCII letters, digits and `_`, so it is safe in a URL and in a `@user@host` handle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Username(String);
+
+impl Username {
+    pub fn new(value: &str) -> Result<Self, DomainError> {
+        if value.is_empty()
+            || value.len() > MAX_USERNAME_LENGTH
+            || !value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return Err(DomainError::InvalidUsername);
+        }
+        Ok(Self(value.to_string()))
+    }
+
+    /// As chosen at sign-up, the case is kept in the activity id
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Usernames are unique and looked up regardless of case
+pub fn normalize_username(username: &str) -> String {
+    username.to_lowercase()
+}
+
+/// Permission level of a local account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    User,
+    Moderator,
+    Admin,
+}
+
+impl Role {
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "user" => Ok(Self::User),
+            "moderator" => Ok(Self::Moderator),
+            "admin" => Ok(Self::Admin),
+            _ => Err(DomainError::InvalidRole),
+        }
+    }
+
+    /// Whether the role may handle moderation work (reports, ...)
+    pub fn is_staff(&self) -> bool {
+        matches!(self, Self::Moderator | Self::Admin)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Moderator => "moderator",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+/// Profile fields edited by the owner, omitted fields are left unchanged
+#[derive(Debug, Clone, Default)]
+pub struct ProfileUpdate {
+    pub display_name: Option<DisplayName>,
+    pub summary: Option<String>,
+    /// `Some(None)` clears the color
+    pub theme_color: Option<Option<ThemeColor>>,
+    /// Replaces every detail at once
+    pub details: Option<ProfileDetails>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
-    id: Uuid,
+    id: UserId,
     activity_id: ActivityId,
     display_name: DisplayName,
+    /// Bio shown on the profile, may be empty
+    summary: String,
     icon_url: Option<IconUrl>,
+    header_url: Option<IconUrl>,
+    theme_color: Option<ThemeColor>,
+    details: ProfileDetails,
+    role: Role,
+    moderation: Moderation,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    /// Deleted by the owner, who may reactivate it during the grace period
+    self_deleted: bool,
+    /// Vouched for by an admin, unrelated to `rel=me` link verification
+    verified_at: Option<DateTime<Utc>>,
 }
 
 impl User {
     pub fn new(
-        id: Uuid,
+        id: UserId,
         activity_id: ActivityId,
         display_name: DisplayName,
         icon_url: Option<IconUrl>,
@@ -41,16 +186,186 @@ impl User {
             return Err(DomainError::EmptyDisplayName);
         }
 
+        let now = Utc::now();
         Ok(Self {
             id,
             activity_id,
             display_name,
+            summary: String::new(),
             icon_url,
+            header_url: None,
+            theme_color: None,
+            details: ProfileDetails::default(),
+            role: Role::User,
+            moderation: Moderation::default(),
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+            self_deleted: false,
+            verified_at: None,
         })
     }
 
+    pub fn with_profile(mut self, summary: String, header_url: Option<IconUrl>) -> Self {
+        self.summary = summary;
+        self.header_url = header_url;
+        self
+    }
+
+    pub fn with_theme_color(mut self, theme_color: Option<ThemeColor>) -> Self {
+        self.theme_color = theme_color;
+        self
+    }
+
+    pub fn with_details(mut self, details: ProfileDetails) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// Timestamps of a stored account, `new` stamps the current time
+    pub fn with_timestamps(
+        mut self,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        self.created_at = created_at;
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn with_deleted_at(mut self, deleted_at: Option<DateTime<Utc>>) -> Self {
+        self.deleted_at = deleted_at;
+        self
+    }
+
+    pub fn with_self_deleted(mut self, self_deleted: bool) -> Self {
+        self.self_deleted = self_deleted;
+        self
+    }
+
+    pub fn with_verified_at(mut self, verified_at: Option<DateTime<Utc>>) -> Self {
+        self.verified_at = verified_at;
+        self
+    }
+
+    pub fn with_role(mut self, role: Role) -> Self {
+        self.role = role;
+        self
+    }
+
+    pub fn with_moderation(mut self, moderation: Moderation) -> Self {
+        self.moderation = moderation;
+        self
+    }
+
+    /// Fail unless the account may handle moderation work
+    pub fn ensure_staff(&self) -> Result<(), DomainError> {
+        if !self.role.is_staff() {
+            return Err(DomainError::Forbidden);
+        }
+        Ok(())
+    }
+
+    /// Fail unless the account is an administrator
+    pub fn ensure_admin(&self) -> Result<(), DomainError> {
+        if self.role != Role::Admin {
+            return Err(DomainError::Forbidden);
+        }
+        Ok(())
+    }
+
+    /// Apply a profile edit and bump `updated_at`, the version checked when saving
+    pub fn update_profile(&mut self, update: ProfileUpdate) -> Result<(), DomainError> {
+        if let Some(display_name) = update.display_name {
+            if display_name.is_empty() {
+                return Err(DomainError::EmptyDisplayName);
+            }
+            self.display_name = display_name;
+        }
+        if let Some(summary) = update.summary {
+            self.summary = summary;
+        }
+        if let Some(theme_color) = update.theme_color {
+            self.theme_color = theme_color;
+        }
+        if let Some(details) = update.details {
+            details.validate()?;
+            self.details = details;
+        }
+        // microseconds, the precision Postgres stores, so the value read back compares equal
+        self.updated_at = Utc::now().trunc_subsecs(6);
+        Ok(())
+    }
+
+    /// Grant or revoke the verified badge, keeping the first verification time
+    pub fn set_verified(&mut self, verified: bool) {
+        self.verified_at = match verified {
+            true => self.verified_at.or(Some(Utc::now())),
+            false => None,
+        };
+    }
+
+    /// Mark the account as deleted, keeping the first deletion time
+    pub fn delete(&mut self) {
+        self.deleted_at = self.deleted_at.or(Some(Utc::now()));
+    }
+
+    /// Deletion asked by the owner, reversible until `purge_at`
+    pub fn request_deletion(&mut self) -> Result<(), DomainError> {
+        self.ensure_not_deleted()?;
+        self.delete();
+        self.self_deleted = true;
+        Ok(())
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// When the deleted account is removed for good by the purge job
+    pub fn purge_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+            .map(|deleted_at| deleted_at + Duration::days(DELETION_GRACE_DAYS))
+    }
+
+    /// Whether the owner may still undo the deletion, moderator deletions are final
+    pub fn can_reactivate(&self) -> bool {
+        self.self_deleted && self.purge_at().is_some_and(|purge_at| Utc::now() < purge_at)
+    }
+
+    /// Undo a deletion asked by the owner
+    pub fn reactivate(&mut self) -> Result<(), DomainError> {
+        if !self.can_reactivate() {
+            return Err(DomainError::Forbidden);
+        }
+        self.deleted_at = None;
+        self.self_deleted = false;
+        Ok(())
+    }
+
+    /// What remains visible of a deleted account
+    pub fn tombstone(&self) -> Option<Tombstone> {
+        self.deleted_at.map(|deleted_at| Tombstone {
+            id: self.activity_id.as_str().to_string(),
+            former_type: "Person",
+            deleted_at,
+        })
+    }
+
+    /// Fail with the tombstone when the account is deleted
+    pub fn ensure_not_deleted(&self) -> Result<(), DomainError> {
+        match self.tombstone() {
+            Some(tombstone) => Err(DomainError::Gone(tombstone)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn moderation_mut(&mut self) -> &mut Moderation {
+        &mut self.moderation
+    }
+
     // getterのみ提供
-    pub fn id(&self) -> Uuid {
+    pub fn id(&self) -> UserId {
         self.id
     }
     pub fn activity_id(&self) -> &ActivityId {
@@ -59,7 +374,120 @@ impl User {
     pub fn display_name(&self) -> &str {
         &self.display_name
     }
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
     pub fn icon_url(&self) -> Option<&str> {
         self.icon_url.as_deref()
     }
+    pub fn header_url(&self) -> Option<&str> {
+        self.header_url.as_deref()
+    }
+    pub fn theme_color(&self) -> Option<&ThemeColor> {
+        self.theme_color.as_ref()
+    }
+    pub fn details(&self) -> &ProfileDetails {
+        &self.details
+    }
+    pub fn role(&self) -> Role {
+        self.role
+    }
+    pub fn moderation(&self) -> &Moderation {
+        &self.moderation
+    }
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+    pub fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+    pub fn self_deleted(&self) -> bool {
+        self.self_deleted
+    }
+    pub fn verified_at(&self) -> Option<DateTime<Utc>> {
+        self.verified_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_username_length_is_bounded() {
+        assert!(matches!(
+            Username::new(""),
+            Err(DomainError::InvalidUsername)
+        ));
+        assert!(Username::new(&"a".repeat(MAX_USERNAME_LENGTH)).is_ok());
+        assert!(matches!(
+            Username::new(&"a".repeat(MAX_USERNAME_LENGTH + 1)),
+            Err(DomainError::InvalidUsername)
+        ));
+    }
+
+    #[test]
+    fn test_username_separators_are_rejected() {
+        for username in ["alice/admin", "alice@example.com", "alice bob", "../alice"] {
+            assert!(matches!(
+                Username::new(username),
+                Err(DomainError::InvalidUsername)
+            ));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_https_activity_id_round_trips(
+            host in "[a-z0-9]{1,20}(\\.[a-z]{2,10}){1,2}",
+            username in "[A-Za-z0-9_]{1,30}",
+        ) {
+            let value = format!("https://{host}/users/{username}");
+            let activity_id = ActivityId::new(value.clone()).unwrap();
+
+            prop_assert_eq!(value.as_str(), activity_id.as_str());
+            prop_assert_eq!(username.as_str(), activity_id.username());
+            let json = serde_json::to_string(&activity_id).unwrap();
+            prop_assert_eq!(activity_id, serde_json::from_str::<ActivityId>(&json).unwrap());
+        }
+
+        #[test]
+        fn test_non_https_activity_id_is_rejected(value in "\\PC{0,80}") {
+            prop_assume!(!value.starts_with("https://"));
+
+            prop_assert!(matches!(
+                ActivityId::new(value),
+                Err(DomainError::InvalidActivityId)
+            ));
+        }
+
+        #[test]
+        fn test_valid_username_is_kept(username in "[A-Za-z0-9_]{1,30}") {
+            let valid = Username::new(&username).unwrap();
+
+            prop_assert_eq!(username.as_str(), valid.as_str());
+        }
+
+        #[test]
+        fn test_username_outside_charset_is_rejected(
+            username in "[a-z]{0,10}[^A-Za-z0-9_][a-z]{0,10}",
+        ) {
+            prop_assert!(matches!(
+                Username::new(&username),
+                Err(DomainError::InvalidUsername)
+            ));
+        }
+
+        #[test]
+        fn test_normalize_username_is_idempotent(username in "\\PC{0,40}") {
+            let normalized = normalize_username(&username);
+
+            prop_assert_eq!(&normalized, &normalize_username(&normalized));
+        }
+    }
 }
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{
+        alias::AccountAlias,
+        user::{ActivityId, UserId},
+    },
+};
+
+#[async_trait]
+pub trait AccountAliasRepository {
+    /// Aliases of the user, oldest first
+    async fn list(&self, user_id: UserId) -> Result<Vec<AccountAlias>, RepositoryError>;
+    /// Fails with `Conflict` when the user already lists that account
+    async fn add(&self, alias: &AccountAlias) -> Result<(), RepositoryError>;
+    /// Fails with `NotFound` when the user does not list that account
+    async fn remove(&self, user_id: UserId, uri: &ActivityId) -> Result<(), RepositoryError>;
+}
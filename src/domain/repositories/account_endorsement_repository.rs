@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{endorsement::Endorsement, user::UserId},
+};
+
+#[async_trait]
+pub trait AccountEndorsementRepository {
+    /// Endorsements of the user, oldest first
+    async fn list(&self, user_id: UserId) -> Result<Vec<Endorsement>, RepositoryError>;
+    /// Fails with `Conflict` when the user already features that account
+    async fn add(&self, endorsement: &Endorsement) -> Result<(), RepositoryError>;
+    /// Fails with `NotFound` when the user does not feature that account
+    async fn remove(&self, user_id: UserId, target_id: UserId) -> Result<(), RepositoryError>;
+}
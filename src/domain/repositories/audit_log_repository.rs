@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::audit_log::{AuditLogEntry, AuditLogFilter},
+};
+
+/// Append-only store of moderation actions
+#[async_trait]
+pub trait AuditLogRepository {
+    async fn append(&self, entry: &AuditLogEntry) -> Result<(), RepositoryError>;
+    async fn list(&self, filter: &AuditLogFilter) -> Result<Vec<AuditLogEntry>, RepositoryError>;
+}
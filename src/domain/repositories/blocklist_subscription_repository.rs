@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{error::RepositoryError, models::federation::BlocklistSubscription};
+
+#[async_trait]
+pub trait BlocklistSubscriptionRepository {
+    /// Every subscription, oldest first
+    async fn list(&self) -> Result<Vec<BlocklistSubscription>, RepositoryError>;
+    async fn find(&self, id: Uuid) -> Result<Option<BlocklistSubscription>, RepositoryError>;
+    /// Fails with `Conflict` when the URL is already subscribed
+    async fn create(&self, subscription: &BlocklistSubscription) -> Result<(), RepositoryError>;
+    /// Store the outcome of the last sync
+    async fn update_sync(
+        &self,
+        subscription: &BlocklistSubscription,
+    ) -> Result<(), RepositoryError>;
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
+}
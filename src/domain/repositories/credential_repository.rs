@@ -1,22 +1,16 @@
 use async_trait::async_trait;
-use uuid::Uuid;
 
 use crate::domain::{
     error::RepositoryError,
-    models::{
-        credential::{Credential, HashedPassword},
-        user::ActivityId,
-    },
+    models::{credential::Credential, email::EmailAddress, user::ActivityId},
 };
 
 #[async_trait]
 pub trait CredentialRepository {
-    async fn get_credential(&self, user_id: ActivityId) -> Result<Credential, RepositoryError>;
-    async fn create_credential(
-        &self,
-        id: Uuid,
-        user_id: ActivityId,
-        password_hash: HashedPassword,
-        email: String,
-    ) -> Result<(), RepositoryError>;
+    /// Credential of the local account with the given activity id
+    async fn get_credential(&self, activity_id: &ActivityId) -> Result<Credential, RepositoryError>;
+    /// Credential registered with the given email, `None` when there is none
+    async fn find_by_email(&self, email: &EmailAddress) -> Result<Option<Credential>, RepositoryError>;
+    /// Persist the mutable part of a credential (password hash, updated_at)
+    async fn update_credential(&self, credential: &Credential) -> Result<(), RepositoryError>;
 }
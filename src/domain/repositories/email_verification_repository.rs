@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{email_verification::EmailVerification, user::UserId},
+};
+
+#[async_trait]
+pub trait EmailVerificationRepository {
+    async fn create(&self, verification: &EmailVerification) -> Result<(), RepositoryError>;
+    /// Whether the account has not confirmed its address yet
+    async fn is_pending(&self, user_id: UserId) -> Result<bool, RepositoryError>;
+    /// Remove the verification of that token, fails with `NotFound` when there is none
+    async fn confirm(&self, token_hash: &str) -> Result<UserId, RepositoryError>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{error::RepositoryError, models::federation::FederationDomain};
+
+#[async_trait]
+pub trait FederationDomainRepository {
+    /// Every blocked and allowed domain, newest first
+    async fn list(&self) -> Result<Vec<FederationDomain>, RepositoryError>;
+    /// Fails with `Conflict` when the domain is already listed with the same policy
+    async fn create(&self, domain: &FederationDomain) -> Result<(), RepositoryError>;
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
+}
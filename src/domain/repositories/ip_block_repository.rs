@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{error::RepositoryError, models::ip_block::IpBlock};
+
+#[async_trait]
+pub trait IpBlockRepository {
+    /// Every block, expired ones included, newest first
+    async fn list(&self) -> Result<Vec<IpBlock>, RepositoryError>;
+    async fn create(&self, block: &IpBlock) -> Result<(), RepositoryError>;
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{
+        legal::{LegalAcceptance, LegalDocument, LegalDocumentKind},
+        user::UserId,
+    },
+};
+
+#[async_trait]
+pub trait LegalDocumentRepository {
+    /// Highest published version of the document, None before the first publication
+    async fn current(
+        &self,
+        kind: LegalDocumentKind,
+    ) -> Result<Option<LegalDocument>, RepositoryError>;
+    async fn publish(&self, document: &LegalDocument) -> Result<(), RepositoryError>;
+    async fn acceptances(&self, user_id: UserId) -> Result<Vec<LegalAcceptance>, RepositoryError>;
+    /// Insert or replace the acceptance of the user for that kind of document
+    async fn accept(&self, acceptance: &LegalAcceptance) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{notification_preferences::NotificationPreferences, user::UserId},
+};
+
+#[async_trait]
+pub trait NotificationPreferencesRepository {
+    /// None when the user kept the defaults
+    async fn find(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<NotificationPreferences>, RepositoryError>;
+    /// Insert or replace the preferences of the user
+    async fn save(&self, preferences: &NotificationPreferences) -> Result<(), RepositoryError>;
+}
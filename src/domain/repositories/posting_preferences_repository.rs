@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{posting_preferences::PostingPreferences, user::UserId},
+};
+
+#[async_trait]
+pub trait PostingPreferencesRepository {
+    /// None when the user kept the defaults
+    async fn find(&self, user_id: UserId) -> Result<Option<PostingPreferences>, RepositoryError>;
+    /// Insert or replace the preferences of the user
+    async fn save(&self, preferences: &PostingPreferences) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{refresh_token::RefreshToken, user::UserId},
+};
+
+#[async_trait]
+pub trait RefreshTokenRepository {
+    async fn create(&self, token: &RefreshToken) -> Result<(), RepositoryError>;
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<RefreshToken>, RepositoryError>;
+    /// Mark the token as exchanged, false when it already was (by a concurrent request)
+    async fn revoke(&self, id: Uuid) -> Result<bool, RepositoryError>;
+    /// Revoke every token rotated from the same login
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), RepositoryError>;
+    /// Revoke every token of the account, ending all its sessions
+    async fn revoke_all_for_user(&self, user_id: UserId) -> Result<(), RepositoryError>;
+}
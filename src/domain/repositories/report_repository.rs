@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::report::{Report, ReportNote},
+};
+
+#[async_trait]
+pub trait ReportRepository {
+    async fn create(&self, report: &Report) -> Result<(), RepositoryError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Report>, RepositoryError>;
+    /// Newest first, `resolved` filters on the resolution state when given
+    async fn list(&self, resolved: Option<bool>) -> Result<Vec<Report>, RepositoryError>;
+    /// Persist the mutable part of a report (assignment, resolution)
+    async fn update(&self, report: &Report) -> Result<(), RepositoryError>;
+    async fn add_note(&self, note: &ReportNote) -> Result<(), RepositoryError>;
+    /// Oldest first
+    async fn notes(&self, report_id: Uuid) -> Result<Vec<ReportNote>, RepositoryError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{error::RepositoryError, models::rule::Rule};
+
+#[async_trait]
+pub trait RuleRepository {
+    /// Rules in display order, deleted ones excluded
+    async fn list(&self) -> Result<Vec<Rule>, RepositoryError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Rule>, RepositoryError>;
+    async fn create(&self, rule: &Rule) -> Result<(), RepositoryError>;
+    async fn update(&self, rule: &Rule) -> Result<(), RepositoryError>;
+    /// Soft delete, reports filed under the rule keep referencing it
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError>;
+}
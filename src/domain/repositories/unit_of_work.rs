@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    repositories::{
+        audit_log_repository::AuditLogRepository,
+        email_verification_repository::EmailVerificationRepository,
+        legal_document_repository::LegalDocumentRepository,
+        user_registration_repository::UserRegistrationRepository,
+    },
+};
+
+/// Source of transactions, for usecases whose writes span several repositories
+#[async_trait]
+pub trait UnitOfWork {
+    type Transaction: Transaction + Send + Sync;
+
+    async fn begin(&self) -> Result<Self::Transaction, RepositoryError>;
+}
+
+/// Repositories sharing one open transaction
+/// Their writes are applied on `commit`, and discarded when dropped without it
+#[async_trait]
+pub trait Transaction {
+    type Registrations: UserRegistrationRepository + Send + Sync;
+    type LegalDocuments: LegalDocumentRepository + Send + Sync;
+    type AuditLog: AuditLogRepository + Send + Sync;
+    type EmailVerifications: EmailVerificationRepository + Send + Sync;
+
+    fn registrations(&self) -> &Self::Registrations;
+    fn legal_documents(&self) -> &Self::LegalDocuments;
+    fn audit_log(&self) -> &Self::AuditLog;
+    fn email_verifications(&self) -> &Self::EmailVerifications;
+
+    async fn commit(self) -> Result<(), RepositoryError>;
+}
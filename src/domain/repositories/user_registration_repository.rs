@@ -4,7 +4,8 @@ use crate::domain::{
     error::RepositoryError,
     models::{
         credential::HashedPassword,
-        user::{ActivityId, User},
+        email::EmailAddress,
+        user::{ActivityId, Role, User},
     },
 };
 
@@ -12,11 +13,14 @@ use crate::domain::{
 #[async_trait]
 pub trait UserRegistrationRepository {
     /// Register a new user with credentials in a single transaction
+    /// `disabled` accounts cannot log in until a moderator enables them (sign-up approval)
     async fn register_user_with_credentials(
         &self,
         activity_id: &ActivityId,
         display_name: &str,
+        role: Role,
+        disabled: bool,
         password_hash: HashedPassword,
-        email: String,
+        email: EmailAddress,
     ) -> Result<User, RepositoryError>;
 }
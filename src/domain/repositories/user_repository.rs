@@ -1,17 +1,27 @@
 use crate::domain::{
     error::RepositoryError,
-    models::user::{ActivityId, User},
+    models::user::{User, UserId},
 };
 use async_trait::async_trait;
-use uuid::Uuid;
+use chrono::{DateTime, Utc};
 
 #[async_trait]
 pub trait UserRepository {
     async fn find_by_username(&self, username: &str) -> Result<Option<User>, RepositoryError>;
-    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, RepositoryError>;
-    async fn register_user(
+    async fn find_by_id(&self, id: UserId) -> Result<Option<User>, RepositoryError>;
+    /// Persist the moderation state of a user
+    async fn update_moderation(&self, user: &User) -> Result<(), RepositoryError>;
+    /// Persist whether an admin verified the user
+    async fn update_verification(&self, user: &User) -> Result<(), RepositoryError>;
+    /// Persist the profile of a user, failing with `Outdated` unless the stored
+    /// `updated_at` still equals `expected_updated_at`
+    async fn update_profile(
         &self,
-        activity_id: &ActivityId,
-        display_name: &str,
-    ) -> Result<Uuid, RepositoryError>;
+        user: &User,
+        expected_updated_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+    /// Persist the deletion state of a user, the row is kept as a tombstone
+    async fn mark_deleted(&self, user: &User) -> Result<(), RepositoryError>;
+    /// Remove users deleted before `before`, returning how many were removed
+    async fn purge_deleted(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
 }
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+
+/// Service for downloading a shared blocklist
+#[async_trait]
+pub trait BlocklistFetcher: Send + Sync {
+    /// The CSV served at `url`
+    async fn fetch(&self, url: &str) -> Result<String, DomainError>;
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+
+/// Plain text email ready to be delivered
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Emails sent by the instance
+#[derive(Debug, Clone)]
+pub enum EmailTemplate {
+    Confirmation {
+        display_name: String,
+        confirm_url: String,
+    },
+    NewLogin {
+        display_name: String,
+        logged_in_at: DateTime<Utc>,
+    },
+}
+
+impl EmailTemplate {
+    pub fn subject(&self) -> String {
+        match self {
+            Self::Confirmation { .. } => "Confirm your email address".to_string(),
+            Self::NewLogin { .. } => "New login to your account".to_string(),
+        }
+    }
+
+    pub fn body(&self) -> String {
+        match self {
+            Self::Confirmation {
+                display_name,
+                confirm_url,
+            } => format!(
+                "Hello {},\n\nPlease confirm your email address by opening the link below:\n{}\n",
+                display_name, confirm_url
+            ),
+            Self::NewLogin {
+                display_name,
+                logged_in_at,
+            } => format!(
+                "Hello {},\n\nYour account was logged in to at {}.\nIf this was not you, change your password immediately.\n",
+                display_name,
+                logged_in_at.to_rfc2822()
+            ),
+        }
+    }
+
+    /// Render the template for a recipient
+    pub fn to_message(&self, to: &str) -> EmailMessage {
+        EmailMessage {
+            to: to.to_string(),
+            subject: self.subject(),
+            body: self.body(),
+        }
+    }
+}
+
+/// Service for delivering emails
+#[async_trait]
+pub trait EmailSender: Send + Sync {
+    async fn send(&self, message: EmailMessage) -> Result<(), DomainError>;
+}
+
+/// Tells whether a domain can receive mail, checked before an address is accepted at sign-up
+#[async_trait]
+pub trait MailDomainVerifier: Clone + Send + Sync {
+    /// Lookup failures count as acceptance, a DNS hiccup must not block sign-ups
+    async fn accepts_mail(&self, domain: &str) -> bool;
+}
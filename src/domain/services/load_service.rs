@@ -0,0 +1,5 @@
+/// Shared resource (database pool, job queue) checked before a request is let in
+/// While one is saturated, requests are refused right away instead of queueing until they time out
+pub trait LoadGauge: Clone + Send + Sync {
+    fn is_saturated(&self) -> bool;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+
+/// Remote file fetched on behalf of a client
+#[derive(Debug, Clone)]
+pub struct RemoteMedia {
+    pub content_type: String,
+    pub body: Vec<u8>,
+}
+
+/// Service for checking proxied URLs so the proxy only serves URLs the instance signed
+pub trait UrlSigner: Clone {
+    /// Decode a signed path segment back into the original URL
+    fn verify(&self, signed: &str) -> Result<String, DomainError>;
+}
+
+/// Service for downloading remote media
+#[async_trait]
+pub trait MediaFetcher: Send + Sync {
+    async fn fetch(&self, url: &str) -> Result<RemoteMedia, DomainError>;
+}
@@ -1,2 +1,7 @@
+pub mod blocklist_service;
+pub mod email_service;
+pub mod load_service;
+pub mod media_proxy_service;
 pub mod password_service;
+pub mod spam_service;
 pub mod token_service;
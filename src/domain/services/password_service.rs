@@ -5,6 +5,14 @@ pub trait PasswordHasher: Clone {
     /// Hash a plain text password
     fn hash(&self, plain_password: &str) -> Result<HashedPassword, DomainError>;
 
-    /// Verify a plain text password against a hashed password
+    /// Verify a plain text password against a hashed password, in any supported format
     fn verify(&self, plain_password: &str, hashed_password: &HashedPassword) -> Result<bool, DomainError>;
+
+    /// Take as long as `verify` when there is no hash to check (unknown account)
+    /// so that the response time does not reveal whether an account exists
+    fn verify_dummy(&self, plain_password: &str);
+
+    /// Whether the hash is in another format than the one `hash` produces
+    /// and should be replaced once the plain password is known (at login)
+    fn needs_rehash(&self, hashed_password: &HashedPassword) -> bool;
 }
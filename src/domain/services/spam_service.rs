@@ -0,0 +1,23 @@
+/// Registration data inspected before the account is created
+#[derive(Debug, Clone, Copy)]
+pub struct RegistrationSubmission<'a> {
+    pub display_name: &'a str,
+    pub email: &'a str,
+}
+
+/// Outcome of a spam check, ordered from mildest to strictest
+/// Content is never rejected outright, moderators make the final call
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SpamVerdict {
+    Clean,
+    /// Let through, but recorded for moderators to look at
+    Flag(String),
+    /// Kept back until a moderator approves it
+    Hold(String),
+}
+
+/// Heuristic run on user submitted content
+/// Statuses are not stored by this server yet, registrations are the only hook so far
+pub trait SpamChecker: Clone + Send + Sync {
+    fn check_registration(&self, submission: &RegistrationSubmission) -> SpamVerdict;
+}
@@ -1,10 +1,60 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
-use crate::domain::{error::DomainError, models::user::User};
+use crate::domain::{
+    error::DomainError,
+    models::user::{User, UserId},
+};
 
-pub type Token = String;
+/// Scopes granted to tokens issued at login, full access to the account
+pub const DEFAULT_SCOPES: [&str; 2] = ["read", "write"];
+
+/// Scope of service credentials allowed to introspect tokens of any account, never granted at login
+pub const INTROSPECT_SCOPE: &str = "introspect";
+
+/// How the token is presented by clients, reported as `token_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenType {
+    Bearer,
+}
+
+impl TokenType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bearer => "Bearer",
+        }
+    }
+}
+
+/// Access token along with what it grants and until when
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub value: String,
+    pub token_type: TokenType,
+    /// Account the token was issued to
+    pub user_id: UserId,
+    pub expires_at: DateTime<Utc>,
+    pub scopes: Vec<String>,
+}
+
+impl Token {
+    /// Seconds left before the token expires, zero once expired
+    pub fn expires_in(&self) -> i64 {
+        (self.expires_at - Utc::now()).num_seconds().max(0)
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|granted| granted == scope)
+    }
+}
 
 #[async_trait]
 pub trait TokenGenerator: Send + Sync {
     fn generate(&self, user: &User) -> Result<Token, DomainError>;
 }
+
+/// Service for checking tokens issued by a `TokenGenerator`
+pub trait TokenVerifier: Send + Sync {
+    /// Return the token with the metadata it was issued with
+    fn verify(&self, token: &str) -> Result<Token, DomainError>;
+}
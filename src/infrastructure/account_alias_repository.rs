@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use entity::account_aliases;
+use sea_orm::{ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            alias::AccountAlias,
+            user::{ActivityId, UserId},
+        },
+        repositories::account_alias_repository::AccountAliasRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error, unique_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresAccountAliasRepository {
+    db: DatabasePool,
+}
+
+impl PostgresAccountAliasRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AccountAliasRepository for PostgresAccountAliasRepository {
+    #[instrument(skip(self), err)]
+    async fn list(&self, user_id: UserId) -> Result<Vec<AccountAlias>, RepositoryError> {
+        let models = account_aliases::Entity::find()
+            .filter(account_aliases::Column::UserId.eq(user_id.as_uuid()))
+            .order_by_asc(account_aliases::Column::CreatedAt)
+            .all(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        models
+            .into_iter()
+            .map(|model| {
+                Ok(AccountAlias {
+                    user_id: UserId::from(model.user_id),
+                    uri: ActivityId::new(model.uri)
+                        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?,
+                    created_at: model.created_at.naive_utc().and_utc(),
+                })
+            })
+            .collect()
+    }
+
+    #[instrument(skip_all, fields(user_id = %alias.user_id), err)]
+    async fn add(&self, alias: &AccountAlias) -> Result<(), RepositoryError> {
+        let model = account_aliases::ActiveModel {
+            user_id: Set(alias.user_id.as_uuid()),
+            uri: Set(alias.uri.as_str().to_string()),
+            created_at: Set(alias.created_at.fixed_offset()),
+        };
+        account_aliases::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| unique_error(e, "alias"))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn remove(&self, user_id: UserId, uri: &ActivityId) -> Result<(), RepositoryError> {
+        let result = account_aliases::Entity::delete_many()
+            .filter(account_aliases::Column::UserId.eq(user_id.as_uuid()))
+            .filter(account_aliases::Column::Uri.eq(uri.as_str()))
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use entity::account_endorsements;
+use sea_orm::{ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{endorsement::Endorsement, user::UserId},
+        repositories::account_endorsement_repository::AccountEndorsementRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error, unique_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresAccountEndorsementRepository {
+    db: DatabasePool,
+}
+
+impl PostgresAccountEndorsementRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AccountEndorsementRepository for PostgresAccountEndorsementRepository {
+    #[instrument(skip(self), err)]
+    async fn list(&self, user_id: UserId) -> Result<Vec<Endorsement>, RepositoryError> {
+        let models = account_endorsements::Entity::find()
+            .filter(account_endorsements::Column::UserId.eq(user_id.as_uuid()))
+            .order_by_asc(account_endorsements::Column::CreatedAt)
+            .all(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        Ok(models
+            .into_iter()
+            .map(|model| Endorsement {
+                user_id: UserId::from(model.user_id),
+                target_id: UserId::from(model.target_id),
+                created_at: model.created_at.naive_utc().and_utc(),
+            })
+            .collect())
+    }
+
+    #[instrument(skip_all, fields(user_id = %endorsement.user_id), err)]
+    async fn add(&self, endorsement: &Endorsement) -> Result<(), RepositoryError> {
+        let model = account_endorsements::ActiveModel {
+            user_id: Set(endorsement.user_id.as_uuid()),
+            target_id: Set(endorsement.target_id.as_uuid()),
+            created_at: Set(endorsement.created_at.fixed_offset()),
+        };
+        account_endorsements::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| unique_error(e, "endorsement"))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn remove(&self, user_id: UserId, target_id: UserId) -> Result<(), RepositoryError> {
+        let result = account_endorsements::Entity::delete_many()
+            .filter(account_endorsements::Column::UserId.eq(user_id.as_uuid()))
+            .filter(account_endorsements::Column::TargetId.eq(target_id.as_uuid()))
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        if result.rows_affected == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::LazyLock;
+
 use argon2::{
     Argon2, PasswordHash as Argon2Hash,
     password_hash::{PasswordHasher as Argon2Hasher, PasswordVerifier, SaltString, rand_core::OsRng},
@@ -5,10 +7,21 @@ use argon2::{
 
 use crate::domain::{
     error::DomainError,
-    models::credential::HashedPassword,
+    models::credential::{HashAlgorithm, HashedPassword},
     services::password_service::PasswordHasher,
 };
 
+/// Hash checked by `verify_dummy`, made once with the parameters of real hashes so it costs the same
+static DUMMY_HASH: LazyLock<HashedPassword> = LazyLock::new(|| {
+    let salt = SaltString::generate(OsRng);
+    let hash = Argon2::default()
+        .hash_password(b"not a password of anyone", &salt)
+        .map(|hash| hash.to_string())
+        .unwrap_or_default();
+    HashedPassword::new(hash)
+});
+
+/// Hashes with Argon2id, also verifies the bcrypt hashes of accounts imported from other software
 #[derive(Clone)]
 pub struct Argon2PasswordHasher;
 
@@ -43,6 +56,12 @@ impl PasswordHasher for Argon2PasswordHasher {
     }
 
     fn verify(&self, plain_password: &str, hashed_password: &HashedPassword) -> Result<bool, DomainError> {
+        if hashed_password.algorithm() == Some(HashAlgorithm::Bcrypt) {
+            return bcrypt::verify(plain_password, hashed_password.as_str())
+                .map_err(|_| DomainError::InvalidCredentials);
+        }
+
+        // other PHC strings (argon2i, argon2d) are still checked, then replaced at login
         let parsed_hash = Argon2Hash::new(hashed_password.as_str())
             .map_err(|_| DomainError::InvalidCredentials)?;
 
@@ -50,4 +69,36 @@ impl PasswordHasher for Argon2PasswordHasher {
             .verify_password(plain_password.as_bytes(), &parsed_hash)
             .is_ok())
     }
+
+    fn verify_dummy(&self, plain_password: &str) {
+        let _ = self.verify(plain_password, &DUMMY_HASH);
+    }
+
+    fn needs_rehash(&self, hashed_password: &HashedPassword) -> bool {
+        hashed_password.algorithm() != Some(HashAlgorithm::Argon2id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_bcrypt_hash_positive() {
+        let hasher = Argon2PasswordHasher::new();
+        let hash = HashedPassword::new(bcrypt::hash("test_password", 4).unwrap());
+
+        assert!(hasher.verify("test_password", &hash).unwrap());
+        assert!(!hasher.verify("invalid_password", &hash).unwrap());
+        assert!(hasher.needs_rehash(&hash));
+    }
+
+    #[test]
+    fn test_argon2_hash_needs_no_rehash_positive() {
+        let hasher = Argon2PasswordHasher::new();
+        let hash = hasher.hash("test_password").unwrap();
+
+        assert!(hasher.verify("test_password", &hash).unwrap());
+        assert!(!hasher.needs_rehash(&hash));
+    }
 }
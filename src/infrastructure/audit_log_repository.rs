@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use entity::audit_logs;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            audit_log::{AuditLogEntry, AuditLogFilter},
+            user::UserId,
+        },
+        repositories::audit_log_repository::AuditLogRepository,
+    },
+    infrastructure::database::{Connections, DatabasePool, db_error},
+};
+
+/// Runs on the pool by default, or on a `TransactionScope` inside a unit of work
+#[derive(Clone)]
+pub struct PostgresAuditLogRepository<C: Connections = DatabasePool> {
+    db: C,
+}
+
+impl<C: Connections> PostgresAuditLogRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl<C: Connections> AuditLogRepository for PostgresAuditLogRepository<C> {
+    #[instrument(skip_all, fields(action = %entry.action), err)]
+    async fn append(&self, entry: &AuditLogEntry) -> Result<(), RepositoryError> {
+        let model = audit_logs::ActiveModel {
+            id: Set(entry.id),
+            actor_id: Set(entry.actor_id.map(|id| id.as_uuid())),
+            action: Set(entry.action.clone()),
+            target_type: Set(entry.target_type.clone()),
+            target_id: Set(entry.target_id),
+            reason: Set(entry.reason.clone()),
+            created_at: Set(entry.created_at.fixed_offset()),
+        };
+        audit_logs::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list(&self, filter: &AuditLogFilter) -> Result<Vec<AuditLogEntry>, RepositoryError> {
+        let mut query = audit_logs::Entity::find()
+            .order_by_desc(audit_logs::Column::CreatedAt)
+            .limit(filter.limit);
+        if let Some(actor_id) = filter.actor_id {
+            query = query.filter(audit_logs::Column::ActorId.eq(actor_id.as_uuid()));
+        }
+        if let Some(action) = &filter.action {
+            query = query.filter(audit_logs::Column::Action.eq(action.as_str()));
+        }
+        if let Some(target_id) = filter.target_id {
+            query = query.filter(audit_logs::Column::TargetId.eq(target_id));
+        }
+        if let Some(before) = filter.before {
+            query = query.filter(audit_logs::Column::CreatedAt.lt(before.fixed_offset()));
+        }
+
+        let entries = query.all(self.db.reader()).await.map_err(db_error)?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| AuditLogEntry {
+                id: entry.id,
+                actor_id: entry.actor_id.map(UserId::from),
+                action: entry.action,
+                target_type: entry.target_type,
+                target_id: entry.target_id,
+                reason: entry.reason,
+                created_at: entry.created_at.naive_utc().and_utc(),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use entity::blocklist_subscriptions;
+use sea_orm::{ActiveValue::Set, EntityTrait, QueryOrder};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::RepositoryError, models::federation::BlocklistSubscription,
+        repositories::blocklist_subscription_repository::BlocklistSubscriptionRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error, unique_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresBlocklistSubscriptionRepository {
+    db: DatabasePool,
+}
+
+impl PostgresBlocklistSubscriptionRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl BlocklistSubscriptionRepository for PostgresBlocklistSubscriptionRepository {
+    #[instrument(skip(self), err)]
+    async fn list(&self) -> Result<Vec<BlocklistSubscription>, RepositoryError> {
+        let subscriptions = blocklist_subscriptions::Entity::find()
+            .order_by_asc(blocklist_subscriptions::Column::CreatedAt)
+            .all(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        Ok(subscriptions.into_iter().map(to_domain).collect())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn find(&self, id: Uuid) -> Result<Option<BlocklistSubscription>, RepositoryError> {
+        let subscription = blocklist_subscriptions::Entity::find_by_id(id)
+            .one(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        Ok(subscription.map(to_domain))
+    }
+
+    #[instrument(skip_all, fields(url = %subscription.url), err)]
+    async fn create(&self, subscription: &BlocklistSubscription) -> Result<(), RepositoryError> {
+        blocklist_subscriptions::Entity::insert(to_active_model(subscription))
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| unique_error(e, "url"))?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(subscription_id = %subscription.id), err)]
+    async fn update_sync(
+        &self,
+        subscription: &BlocklistSubscription,
+    ) -> Result<(), RepositoryError> {
+        let model = blocklist_subscriptions::ActiveModel {
+            id: Set(subscription.id),
+            pending: Set(subscription.pending as i32),
+            last_synced_at: Set(subscription.last_synced_at.map(|at| at.fixed_offset())),
+            last_error: Set(subscription.last_error.clone()),
+            ..Default::default()
+        };
+        blocklist_subscriptions::Entity::update(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| match e {
+                sea_orm::DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => db_error(e),
+            })?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let result = blocklist_subscriptions::Entity::delete_by_id(id)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+
+        match result.rows_affected {
+            0 => Err(RepositoryError::NotFound),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// helper function that convert the domain model into a blocklist_subscriptions row
+fn to_active_model(subscription: &BlocklistSubscription) -> blocklist_subscriptions::ActiveModel {
+    blocklist_subscriptions::ActiveModel {
+        id: Set(subscription.id),
+        url: Set(subscription.url.clone()),
+        review: Set(subscription.review),
+        pending: Set(subscription.pending as i32),
+        last_synced_at: Set(subscription.last_synced_at.map(|at| at.fixed_offset())),
+        last_error: Set(subscription.last_error.clone()),
+        created_at: Set(subscription.created_at.fixed_offset()),
+    }
+}
+
+/// helper function that convert a blocklist_subscriptions row into the domain model
+fn to_domain(model: blocklist_subscriptions::Model) -> BlocklistSubscription {
+    BlocklistSubscription {
+        id: model.id,
+        url: model.url,
+        review: model.review,
+        pending: model.pending.max(0) as usize,
+        last_synced_at: model.last_synced_at.map(|at| at.naive_utc().and_utc()),
+        last_error: model.last_error,
+        created_at: model.created_at.naive_utc().and_utc(),
+    }
+}
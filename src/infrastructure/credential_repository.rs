@@ -1,71 +1,92 @@
 use async_trait::async_trait;
-use chrono::Utc;
 use entity::credentials;
-use sea_orm::{ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
-use uuid::Uuid;
+use sea_orm::{ActiveValue::Set, ColumnTrait, DbErr, EntityTrait, QueryFilter};
+use tracing::instrument;
 
-use crate::domain::{
-    error::RepositoryError,
-    models::{
-        credential::{Credential, HashedPassword},
-        user::ActivityId,
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            credential::{Credential, HashedPassword},
+            email::EmailAddress,
+            user::{ActivityId, UserId},
+        },
+        repositories::credential_repository::CredentialRepository,
     },
-    repositories::credential_repository::CredentialRepository,
+    infrastructure::database::{DatabasePool, db_error},
 };
 
 #[derive(Clone)]
 pub struct PostgresCredentialRepository {
-    db: DatabaseConnection,
+    db: DatabasePool,
 }
 
 impl PostgresCredentialRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+    pub fn new(db: DatabasePool) -> Self {
         Self { db }
     }
 }
 
 #[async_trait]
 impl CredentialRepository for PostgresCredentialRepository {
-    async fn get_credential(&self, user_id: ActivityId) -> Result<Credential, RepositoryError> {
+    #[instrument(skip(self), err)]
+    async fn get_credential(&self, activity_id: &ActivityId) -> Result<Credential, RepositoryError> {
         let credential = credentials::Entity::find()
-            .filter(credentials::Column::ActivityId.eq(user_id.as_str()))
-            .one(&self.db)
+            .filter(credentials::Column::ActivityId.eq(activity_id.as_str()))
+            .one(self.db.reader())
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .map_err(db_error)?
             .ok_or(RepositoryError::NotFound)?;
 
-        let password_hash = HashedPassword::new(credential.password_hash);
-
-        let credential = Credential::reconstruct(
-            credential.user_id,
-            user_id,
-            password_hash,
-            credential.created_at.naive_utc().and_utc(),
-            credential.updated_at.naive_utc().and_utc(),
-        );
-
-        Ok(credential)
+        to_domain(credential)
     }
-    async fn create_credential(
+
+    #[instrument(skip_all, err)]
+    async fn find_by_email(
         &self,
-        id: Uuid,
-        activity_id: ActivityId,
-        password_hash: HashedPassword,
-        email: String,
-    ) -> Result<(), RepositoryError> {
-        let now = Utc::now();
-        let credential = credentials::ActiveModel {
-            user_id: Set(id),
-            activity_id: Set(activity_id.as_str().to_string()),
-            password_hash: Set(password_hash.as_str().to_string()),
-            email: Set(email),
-            created_at: Set(now.fixed_offset()),
-            updated_at: Set(now.fixed_offset()),
+        email: &EmailAddress,
+    ) -> Result<Option<Credential>, RepositoryError> {
+        // stored normalized, the unique index makes this at most one row
+        credentials::Entity::find()
+            .filter(credentials::Column::Email.eq(email.as_str()))
+            .one(self.db.reader())
+            .await
+            .map_err(db_error)?
+            .map(to_domain)
+            .transpose()
+    }
+
+    #[instrument(skip_all, fields(user_id = %credential.user_id()), err)]
+    async fn update_credential(&self, credential: &Credential) -> Result<(), RepositoryError> {
+        let model = credentials::ActiveModel {
+            user_id: Set(credential.user_id().as_uuid()),
+            password_hash: Set(credential.password_hash().as_str().to_string()),
+            updated_at: Set(credential.updated_at().fixed_offset()),
+            ..Default::default()
         };
-        credentials::Entity::insert(credential)
-            .exec(&self.db)
+        credentials::Entity::update(model)
+            .exec(self.db.writer())
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => db_error(e),
+            })?;
         Ok(())
     }
 }
+
+/// helper function that convert a credentials row into the domain model
+fn to_domain(model: credentials::Model) -> Result<Credential, RepositoryError> {
+    let activity_id = ActivityId::new(model.activity_id)
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+    let email = EmailAddress::new(model.email)
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+    Ok(Credential::reconstruct(
+        UserId::from(model.user_id),
+        activity_id,
+        HashedPassword::new(model.password_hash),
+        email,
+        model.updated_at.naive_utc().and_utc(),
+    ))
+}
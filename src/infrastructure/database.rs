@@ -0,0 +1,211 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use sea_orm::{
+    ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DatabaseTransaction, DbBackend,
+    DbErr, SqlErr, TransactionTrait, sqlx,
+};
+
+use crate::{
+    config::PoolConfig,
+    domain::{error::RepositoryError, services::load_service::LoadGauge},
+};
+
+/// Primary connection plus optional read replicas
+/// Repositories send writes to `writer()` and read-only queries to `reader()`
+#[derive(Clone)]
+pub struct DatabasePool {
+    primary: DatabaseConnection,
+    replicas: Arc<[DatabaseConnection]>,
+    next_replica: Arc<AtomicUsize>,
+    read_from_primary: bool,
+}
+
+impl DatabasePool {
+    pub fn new(primary: DatabaseConnection, replicas: Vec<DatabaseConnection>) -> Self {
+        Self {
+            primary,
+            replicas: replicas.into(),
+            next_replica: Arc::new(AtomicUsize::new(0)),
+            read_from_primary: false,
+        }
+    }
+
+    /// Connection for writes
+    pub fn writer(&self) -> &DatabaseConnection {
+        &self.primary
+    }
+
+    pub fn replicas(&self) -> &[DatabaseConnection] {
+        &self.replicas
+    }
+
+    /// Connection for read-only queries, replicas are used in round robin
+    pub fn reader(&self) -> &DatabaseConnection {
+        if self.read_from_primary || self.replicas.is_empty() {
+            return &self.primary;
+        }
+        let index = self.next_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+        &self.replicas[index]
+    }
+
+    /// Copy of this pool that reads from the primary as well
+    /// For usecases that must see their own (or very recent) writes
+    pub fn primary_only(&self) -> Self {
+        Self {
+            read_from_primary: true,
+            ..self.clone()
+        }
+    }
+
+    pub async fn close(self) -> Result<(), DbErr> {
+        for replica in self.replicas.iter() {
+            replica.clone().close().await?;
+        }
+        self.primary.close().await
+    }
+}
+
+impl From<DatabaseConnection> for DatabasePool {
+    fn from(primary: DatabaseConnection) -> Self {
+        Self::new(primary, Vec::new())
+    }
+}
+
+/// Where a repository runs its statements
+/// The pool for standalone repositories, a `TransactionScope` for those of a unit of work
+pub trait Connections: Clone + Send + Sync {
+    type Connection: ConnectionTrait + TransactionTrait + Send + Sync;
+
+    fn writer(&self) -> &Self::Connection;
+    fn reader(&self) -> &Self::Connection;
+}
+
+impl Connections for DatabasePool {
+    type Connection = DatabaseConnection;
+
+    fn writer(&self) -> &DatabaseConnection {
+        DatabasePool::writer(self)
+    }
+
+    fn reader(&self) -> &DatabaseConnection {
+        DatabasePool::reader(self)
+    }
+}
+
+/// Saturated when every connection to the primary is checked out and the pool cannot open another
+/// Replicas are not checked, a write needs the primary anyway
+impl LoadGauge for DatabasePool {
+    fn is_saturated(&self) -> bool {
+        match self.primary.get_database_backend() {
+            DbBackend::Postgres => pool_saturated(self.primary.get_postgres_connection_pool()),
+            DbBackend::MySql => pool_saturated(self.primary.get_mysql_connection_pool()),
+            DbBackend::Sqlite => pool_saturated(self.primary.get_sqlite_connection_pool()),
+        }
+    }
+}
+
+/// helper function that tell whether a request would have to wait for a connection
+fn pool_saturated<DB: sqlx::Database>(pool: &sqlx::Pool<DB>) -> bool {
+    pool.num_idle() == 0 && pool.size() >= pool.options().get_max_connections()
+}
+
+/// Transaction shared by the repositories of a unit of work
+/// Reads go to the transaction too, so they see its uncommitted writes
+/// Rolled back when the last clone is dropped without `commit`
+#[derive(Clone)]
+pub struct TransactionScope(Arc<DatabaseTransaction>);
+
+impl TransactionScope {
+    pub async fn begin(db: &DatabasePool) -> Result<Self, RepositoryError> {
+        let txn = db.writer().begin().await.map_err(db_error)?;
+        Ok(Self(Arc::new(txn)))
+    }
+
+    /// Fails when a repository still holds a clone of the scope
+    pub async fn commit(self) -> Result<(), RepositoryError> {
+        let txn = Arc::try_unwrap(self.0).map_err(|_| {
+            RepositoryError::DatabaseError("transaction committed while still in use".to_string())
+        })?;
+        txn.commit().await.map_err(db_error)
+    }
+}
+
+impl Connections for TransactionScope {
+    type Connection = DatabaseTransaction;
+
+    fn writer(&self) -> &DatabaseTransaction {
+        &self.0
+    }
+
+    fn reader(&self) -> &DatabaseTransaction {
+        &self.0
+    }
+}
+
+/// Connect to the primary and every replica
+pub async fn connect_pool(
+    primary_url: &str,
+    replica_urls: &[String],
+    settings: &PoolConfig,
+) -> Result<DatabasePool, DbErr> {
+    let primary = connect(primary_url, settings).await?;
+    let mut replicas = Vec::with_capacity(replica_urls.len());
+    for url in replica_urls {
+        replicas.push(connect(url, settings).await?);
+    }
+    Ok(DatabasePool::new(primary, replicas))
+}
+
+/// Open a connection pool, the backend is selected by the URL scheme
+/// `postgres://...` for regular deployments, `sqlite://path?mode=rwc` or `sqlite::memory:` for small ones
+pub async fn connect(url: &str, settings: &PoolConfig) -> Result<DatabaseConnection, DbErr> {
+    let mut opt = ConnectOptions::new(url);
+    opt.max_connections(settings.max_connections)
+        .min_connections(settings.min_connections)
+        .acquire_timeout(settings.acquire_timeout)
+        .idle_timeout(settings.idle_timeout)
+        .sqlx_logging(true)
+        .sqlx_logging_level(log::LevelFilter::Debug)
+        .sqlx_slow_statements_logging_settings(
+            log::LevelFilter::Warn,
+            settings.slow_query_threshold,
+        );
+
+    if let Some(timeout) = settings.statement_timeout {
+        let timeout = format!("{}ms", timeout.as_millis());
+        opt.map_sqlx_postgres_opts(move |pg| pg.options([("statement_timeout", timeout.as_str())]));
+    }
+
+    if is_in_memory_sqlite(url) {
+        // every connection to an in-memory database gets its own empty database
+        opt.max_connections(1);
+    }
+
+    Database::connect(opt).await
+}
+
+/// helper function that detect in-memory SQLite URLs
+fn is_in_memory_sqlite(url: &str) -> bool {
+    url.starts_with("sqlite:") && url.contains(":memory:")
+}
+
+/// Convert a SeaORM error into the domain error
+/// Pool exhaustion is reported as `Unavailable` so the API can answer 503 instead of a generic failure
+pub fn db_error(e: DbErr) -> RepositoryError {
+    match e {
+        DbErr::ConnectionAcquire(_) => RepositoryError::Unavailable,
+        e => RepositoryError::DatabaseError(e.to_string()),
+    }
+}
+
+/// Same as `db_error`, reporting a unique constraint violation as a conflict on `field`
+/// Meant for statements where only one unique value can collide
+pub fn unique_error(e: DbErr, field: &'static str) -> RepositoryError {
+    match e.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => RepositoryError::Conflict { field },
+        _ => db_error(e),
+    }
+}
@@ -0,0 +1,74 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use crate::domain::{
+    error::DomainError,
+    services::{
+        email_service::{EmailMessage, EmailSender},
+        load_service::LoadGauge,
+    },
+};
+
+/// EmailSender that hands messages to a background worker
+/// so requests never wait on the mail server
+#[derive(Clone)]
+pub struct EmailQueue {
+    tx: mpsc::UnboundedSender<EmailMessage>,
+    /// Messages sent to the queue and not delivered yet
+    backlog: Arc<AtomicUsize>,
+    max_backlog: usize,
+}
+
+impl EmailQueue {
+    /// Start the worker delivering through `sender`
+    /// The worker finishes once every queue handle is dropped and the backlog is sent
+    /// The queue reports itself saturated past `max_backlog` messages, it still accepts them
+    pub fn spawn<S: EmailSender + 'static>(
+        sender: S,
+        max_backlog: usize,
+    ) -> (Self, JoinHandle<()>) {
+        let (tx, mut rx) = mpsc::unbounded_channel::<EmailMessage>();
+        let backlog = Arc::new(AtomicUsize::new(0));
+
+        let worker_backlog = backlog.clone();
+        let worker = tokio::spawn(async move {
+            while let Some(message) = rx.recv().await {
+                let subject = message.subject.clone();
+                if let Err(e) = sender.send(message).await {
+                    tracing::warn!(error = %e, %subject, "failed to deliver email");
+                }
+                worker_backlog.fetch_sub(1, Ordering::Relaxed);
+            }
+        });
+
+        let queue = Self {
+            tx,
+            backlog,
+            max_backlog,
+        };
+        (queue, worker)
+    }
+}
+
+impl LoadGauge for EmailQueue {
+    fn is_saturated(&self) -> bool {
+        self.backlog.load(Ordering::Relaxed) >= self.max_backlog
+    }
+}
+
+#[async_trait]
+impl EmailSender for EmailQueue {
+    async fn send(&self, message: EmailMessage) -> Result<(), DomainError> {
+        // counted before the worker can see the message, so it never decrements first
+        self.backlog.fetch_add(1, Ordering::Relaxed);
+        self.tx.send(message).map_err(|_| {
+            self.backlog.fetch_sub(1, Ordering::Relaxed);
+            DomainError::EmailDelivery("email queue is closed".to_string())
+        })
+    }
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use entity::email_verifications;
+use sea_orm::{ActiveValue::Set, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{email_verification::EmailVerification, user::UserId},
+        repositories::email_verification_repository::EmailVerificationRepository,
+    },
+    infrastructure::database::{Connections, DatabasePool, db_error},
+};
+
+/// Runs on the pool by default, or on a `TransactionScope` inside a unit of work
+#[derive(Clone)]
+pub struct PostgresEmailVerificationRepository<C: Connections = DatabasePool> {
+    db: C,
+}
+
+impl<C: Connections> PostgresEmailVerificationRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl<C: Connections> EmailVerificationRepository for PostgresEmailVerificationRepository<C> {
+    #[instrument(skip_all, fields(user_id = %verification.user_id), err)]
+    async fn create(&self, verification: &EmailVerification) -> Result<(), RepositoryError> {
+        let model = email_verifications::ActiveModel {
+            user_id: Set(verification.user_id.as_uuid()),
+            token_hash: Set(verification.token_hash.clone()),
+            created_at: Set(verification.created_at.fixed_offset()),
+        };
+        email_verifications::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn is_pending(&self, user_id: UserId) -> Result<bool, RepositoryError> {
+        // read right after the link is opened, a replica may not have the removal yet
+        let count = email_verifications::Entity::find_by_id(user_id.as_uuid())
+            .count(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(count > 0)
+    }
+
+    #[instrument(skip_all, err)]
+    async fn confirm(&self, token_hash: &str) -> Result<UserId, RepositoryError> {
+        let model = email_verifications::Entity::find()
+            .filter(email_verifications::Column::TokenHash.eq(token_hash))
+            .one(self.db.writer())
+            .await
+            .map_err(db_error)?
+            .ok_or(RepositoryError::NotFound)?;
+        email_verifications::Entity::delete_by_id(model.user_id)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(UserId::from(model.user_id))
+    }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use entity::federation_domains;
+use sea_orm::{ActiveValue::Set, EntityTrait, QueryOrder};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::federation::{DomainPolicy, FederationDomain},
+        repositories::federation_domain_repository::FederationDomainRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error, unique_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresFederationDomainRepository {
+    db: DatabasePool,
+}
+
+impl PostgresFederationDomainRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl FederationDomainRepository for PostgresFederationDomainRepository {
+    #[instrument(skip(self), err)]
+    async fn list(&self) -> Result<Vec<FederationDomain>, RepositoryError> {
+        let domains = federation_domains::Entity::find()
+            .order_by_desc(federation_domains::Column::CreatedAt)
+            .all(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        domains.into_iter().map(to_domain).collect()
+    }
+
+    #[instrument(skip_all, fields(domain = %domain.domain), err)]
+    async fn create(&self, domain: &FederationDomain) -> Result<(), RepositoryError> {
+        let model = federation_domains::ActiveModel {
+            id: Set(domain.id),
+            domain: Set(domain.domain.clone()),
+            policy: Set(domain.policy.as_str().to_string()),
+            comment: Set(domain.comment.clone()),
+            created_at: Set(domain.created_at.fixed_offset()),
+        };
+        federation_domains::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| unique_error(e, "domain"))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let result = federation_domains::Entity::delete_by_id(id)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+
+        match result.rows_affected {
+            0 => Err(RepositoryError::NotFound),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// helper function that convert a federation_domains row into the domain model
+fn to_domain(model: federation_domains::Model) -> Result<FederationDomain, RepositoryError> {
+    Ok(FederationDomain {
+        id: model.id,
+        policy: DomainPolicy::parse(&model.policy).map_err(|_| {
+            RepositoryError::DatabaseError(format!("invalid federation domain {}", model.id))
+        })?,
+        domain: model.domain,
+        comment: model.comment,
+        created_at: model.created_at.naive_utc().and_utc(),
+    })
+}
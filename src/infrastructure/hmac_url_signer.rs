@@ -0,0 +1,101 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::domain::{error::DomainError, services::media_proxy_service::UrlSigner};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs URLs as `{base64url(url)}.{base64url(hmac_sha256(url))}`
+#[derive(Clone)]
+pub struct HmacUrlSigner {
+    secret: String,
+}
+
+impl HmacUrlSigner {
+    pub fn new(secret: String) -> Self {
+        Self { secret }
+    }
+
+    fn mac(&self, url: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(url.as_bytes());
+        mac
+    }
+}
+
+impl UrlSigner for HmacUrlSigner {
+    fn verify(&self, signed: &str) -> Result<String, DomainError> {
+        let (encoded_url, encoded_signature) =
+            signed.split_once('.').ok_or(DomainError::InvalidSignature)?;
+
+        let url = URL_SAFE_NO_PAD
+            .decode(encoded_url)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or(DomainError::InvalidSignature)?;
+        let signature = URL_SAFE_NO_PAD
+            .decode(encoded_signature)
+            .map_err(|_| DomainError::InvalidSignature)?;
+
+        self.mac(&url)
+            .verify_slice(&signature)
+            .map_err(|_| DomainError::InvalidSignature)?;
+
+        Ok(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(signer: &HmacUrlSigner, url: &str) -> String {
+        let signature = signer.mac(url).finalize().into_bytes();
+        format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(url),
+            URL_SAFE_NO_PAD.encode(signature)
+        )
+    }
+
+    #[test]
+    fn test_sign_and_verify_positive() {
+        let signer = HmacUrlSigner::new("secret".to_string());
+        let url = "https://remote.example/media/cat.png?size=large";
+
+        let signed = sign(&signer, url);
+
+        assert_eq!(url, signer.verify(&signed).unwrap());
+    }
+
+    #[test]
+    fn test_verify_tampered_url_negative() {
+        let signer = HmacUrlSigner::new("secret".to_string());
+        let signed = sign(&signer, "https://remote.example/media/cat.png");
+        let (_, signature) = signed.split_once('.').unwrap();
+        let forged = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode("http://169.254.169.254/latest/meta-data"),
+            signature
+        );
+
+        assert!(matches!(
+            signer.verify(&forged),
+            Err(DomainError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn test_verify_other_secret_negative() {
+        let signed = sign(
+            &HmacUrlSigner::new("secret".to_string()),
+            "https://remote.example/a.png",
+        );
+
+        let result = HmacUrlSigner::new("other".to_string()).verify(&signed);
+
+        assert!(matches!(result, Err(DomainError::InvalidSignature)));
+    }
+}
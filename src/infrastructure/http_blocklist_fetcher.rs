@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{error::DomainError, services::blocklist_service::BlocklistFetcher},
+    infrastructure::http_client::{self, ResilientHttpClient},
+};
+
+/// Largest blocklist accepted, shared lists hold a few thousand rows
+const MAX_BLOCKLIST_BYTES: usize = 5 * 1024 * 1024;
+
+/// Download blocklists through the outbound client, private addresses are refused (SSRF)
+#[derive(Clone)]
+pub struct HttpBlocklistFetcher {
+    client: ResilientHttpClient,
+}
+
+impl HttpBlocklistFetcher {
+    pub fn new(client: ResilientHttpClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl BlocklistFetcher for HttpBlocklistFetcher {
+    async fn fetch(&self, url: &str) -> Result<String, DomainError> {
+        let response = self
+            .client
+            .get(url)
+            .await
+            .map_err(|e| DomainError::RemoteFetch(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DomainError::RemoteFetch(format!(
+                "remote answered {}",
+                response.status()
+            )));
+        }
+
+        let body = http_client::read_body(response, MAX_BLOCKLIST_BYTES)
+            .await
+            .map_err(|e| DomainError::RemoteFetch(e.to_string()))?;
+        String::from_utf8(body)
+            .map_err(|_| DomainError::RemoteFetch("blocklist is not UTF-8".to_string()))
+    }
+}
@@ -0,0 +1,332 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use reqwest::{Client, Request, Response, StatusCode, redirect};
+use thiserror::Error;
+
+use crate::{
+    config::SOFTWARE,
+    infrastructure::outbound_guard::{self, PublicOnlyResolver},
+};
+
+#[derive(Debug, Error)]
+pub enum HttpClientError {
+    #[error("Circuit open for host {0}")]
+    CircuitOpen(String),
+
+    #[error("Deadline exceeded")]
+    DeadlineExceeded,
+
+    #[error("Request has no host")]
+    MissingHost,
+
+    #[error("Blocked address: {0}")]
+    BlockedAddress(String),
+
+    #[error("Blocked scheme: {0}")]
+    BlockedScheme(String),
+
+    #[error("Response larger than {0} bytes")]
+    ResponseTooLarge(usize),
+
+    #[error("Request error: {0}")]
+    Request(#[from] reqwest::Error),
+}
+
+/// Retry, circuit breaker and deadline settings for outbound requests
+#[derive(Debug, Clone)]
+pub struct HttpClientSettings {
+    /// Timeout of a single attempt
+    pub request_timeout: Duration,
+    /// Upper bound for the whole call, retries included
+    pub deadline: Duration,
+    pub max_retries: u32,
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Consecutive failures after which a host is cut off
+    pub breaker_threshold: u32,
+    /// How long a host stays cut off before a trial request is let through
+    pub breaker_cooldown: Duration,
+    /// Refuse loopback, private and link-local destinations (SSRF protection)
+    pub block_private_addresses: bool,
+    pub max_redirects: usize,
+    pub user_agent: String,
+}
+
+impl Default for HttpClientSettings {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(10),
+            deadline: Duration::from_secs(30),
+            max_retries: 3,
+            base_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            breaker_threshold: 5,
+            breaker_cooldown: Duration::from_secs(60),
+            block_private_addresses: true,
+            max_redirects: 5,
+            user_agent: SOFTWARE.to_string(),
+        }
+    }
+}
+
+/// Failure bookkeeping of a single remote host
+#[derive(Debug, Default)]
+struct CircuitBreaker {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Closed, or open with an elapsed cooldown (half-open trial)
+    fn allows(&self, now: Instant) -> bool {
+        self.open_until.is_none_or(|until| now >= until)
+    }
+
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.open_until = None;
+    }
+
+    fn record_failure(&mut self, now: Instant, threshold: u32, cooldown: Duration) {
+        self.consecutive_failures += 1;
+        if self.consecutive_failures >= threshold {
+            self.open_until = Some(now + cooldown);
+        }
+    }
+}
+
+/// HTTP client for every outbound call (federation, webhooks, ...)
+/// Retries transient failures with jittered backoff, stops calling hosts that keep failing
+/// and bounds every call with an overall deadline
+/// Destinations (redirects included) are restricted to public addresses unless disabled
+#[derive(Clone)]
+pub struct ResilientHttpClient {
+    client: Client,
+    settings: HttpClientSettings,
+    breakers: Arc<Mutex<HashMap<String, CircuitBreaker>>>,
+}
+
+impl ResilientHttpClient {
+    pub fn new(settings: HttpClientSettings) -> Result<Self, HttpClientError> {
+        let mut builder = Client::builder()
+            .timeout(settings.request_timeout)
+            .user_agent(settings.user_agent.as_str())
+            .redirect(redirect_policy(
+                settings.max_redirects,
+                settings.block_private_addresses,
+            ));
+        if settings.block_private_addresses {
+            builder = builder.dns_resolver(Arc::new(PublicOnlyResolver));
+        }
+        let client = builder.build()?;
+
+        Ok(Self {
+            client,
+            settings,
+            breakers: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    pub async fn get(&self, url: &str) -> Result<Response, HttpClientError> {
+        let request = self.client.get(url).build()?;
+        self.execute(request).await
+    }
+
+    /// Send a request, retrying transient failures until the deadline
+    pub async fn execute(&self, request: Request) -> Result<Response, HttpClientError> {
+        if self.settings.block_private_addresses {
+            outbound_guard::validate_url(request.url())?;
+        }
+        let host = request
+            .url()
+            .host_str()
+            .ok_or(HttpClientError::MissingHost)?
+            .to_string();
+
+        tokio::time::timeout(self.settings.deadline, self.execute_with_retry(&host, request))
+            .await
+            .map_err(|_| HttpClientError::DeadlineExceeded)?
+    }
+
+    async fn execute_with_retry(
+        &self,
+        host: &str,
+        request: Request,
+    ) -> Result<Response, HttpClientError> {
+        let mut attempt = 0;
+        let mut next = Some(request);
+        loop {
+            if !self.breaker_allows(host) {
+                return Err(HttpClientError::CircuitOpen(host.to_string()));
+            }
+
+            let current = next.take().expect("request for this attempt");
+            // keep a copy for the next attempt, bodies that cannot be cloned (streams) get a single attempt
+            next = current.try_clone();
+
+            let result = self.client.execute(current).await;
+            let retryable = match &result {
+                Ok(response) => is_retryable_status(response.status()),
+                Err(e) => (e.is_timeout() || e.is_connect()) && !is_blocked(e),
+            };
+
+            if !retryable {
+                if result.is_ok() {
+                    self.record_success(host);
+                }
+                return result.map_err(HttpClientError::from);
+            }
+            self.record_failure(host);
+
+            attempt += 1;
+            if next.is_none() || attempt > self.settings.max_retries {
+                return result.map_err(HttpClientError::from);
+            }
+
+            tracing::debug!(%host, attempt, "retrying outbound request");
+            tokio::time::sleep(self.backoff(attempt)).await;
+        }
+    }
+
+    /// Full jitter: a random delay between zero and the exponential cap
+    fn backoff(&self, attempt: u32) -> Duration {
+        let cap = backoff_cap(&self.settings, attempt);
+        Duration::from_millis(rand::random_range(0..=cap.as_millis() as u64))
+    }
+
+    fn breaker_allows(&self, host: &str) -> bool {
+        let breakers = self.breakers.lock().unwrap();
+        breakers
+            .get(host)
+            .is_none_or(|breaker| breaker.allows(Instant::now()))
+    }
+
+    fn record_success(&self, host: &str) {
+        if let Some(breaker) = self.breakers.lock().unwrap().get_mut(host) {
+            breaker.record_success();
+        }
+    }
+
+    fn record_failure(&self, host: &str) {
+        let mut breakers = self.breakers.lock().unwrap();
+        let breaker = breakers.entry(host.to_string()).or_default();
+        let was_open = breaker.open_until.is_some();
+        breaker.record_failure(
+            Instant::now(),
+            self.settings.breaker_threshold,
+            self.settings.breaker_cooldown,
+        );
+        if !was_open && breaker.open_until.is_some() {
+            tracing::warn!(%host, "circuit opened for remote host");
+        }
+    }
+}
+
+/// Read a response body, failing as soon as it grows past `max_bytes`
+/// The declared length can lie or be missing, so the cap is enforced while reading
+pub async fn read_body(mut response: Response, max_bytes: usize) -> Result<Vec<u8>, HttpClientError> {
+    if response
+        .content_length()
+        .is_some_and(|length| length > max_bytes as u64)
+    {
+        return Err(HttpClientError::ResponseTooLarge(max_bytes));
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > max_bytes {
+            return Err(HttpClientError::ResponseTooLarge(max_bytes));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
+/// helper function that build the redirect policy: bounded hops, guarded destinations
+fn redirect_policy(max_redirects: usize, block_private_addresses: bool) -> redirect::Policy {
+    redirect::Policy::custom(move |attempt| {
+        if attempt.previous().len() >= max_redirects {
+            return attempt.error("too many redirects");
+        }
+        let guarded = match block_private_addresses {
+            true => outbound_guard::validate_url(attempt.url()),
+            false => Ok(()),
+        };
+        match guarded {
+            Ok(()) => attempt.follow(),
+            Err(e) => attempt.error(e),
+        }
+    })
+}
+
+/// helper function that tell whether a request failed because of the address guard
+fn is_blocked(error: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(error);
+    while let Some(e) = source {
+        if e.is::<HttpClientError>() {
+            return true;
+        }
+        source = e.source();
+    }
+    false
+}
+
+/// helper function that decide whether a response is worth retrying
+fn is_retryable_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// helper function that compute the exponential backoff cap of an attempt
+fn backoff_cap(settings: &HttpClientSettings, attempt: u32) -> Duration {
+    settings
+        .base_backoff
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(settings.max_backoff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_opens_after_threshold() {
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+
+        breaker.record_failure(now, 3, Duration::from_secs(60));
+        breaker.record_failure(now, 3, Duration::from_secs(60));
+        assert!(breaker.allows(now));
+
+        breaker.record_failure(now, 3, Duration::from_secs(60));
+        assert!(!breaker.allows(now));
+    }
+
+    #[test]
+    fn test_circuit_half_opens_after_cooldown() {
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+        for _ in 0..3 {
+            breaker.record_failure(now, 3, Duration::from_secs(60));
+        }
+
+        assert!(breaker.allows(now + Duration::from_secs(60)));
+
+        breaker.record_success();
+        assert!(breaker.allows(now));
+        assert_eq!(0, breaker.consecutive_failures);
+    }
+
+    #[test]
+    fn test_backoff_cap_is_exponential_and_bounded() {
+        let settings = HttpClientSettings::default();
+
+        assert_eq!(Duration::from_millis(200), backoff_cap(&settings, 1));
+        assert_eq!(Duration::from_millis(400), backoff_cap(&settings, 2));
+        assert_eq!(Duration::from_millis(800), backoff_cap(&settings, 3));
+        assert_eq!(settings.max_backoff, backoff_cap(&settings, 20));
+    }
+}
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        services::media_proxy_service::{MediaFetcher, RemoteMedia},
+    },
+    infrastructure::http_client::{self, HttpClientError, ResilientHttpClient},
+};
+
+/// Content types the proxy is willing to serve
+const ALLOWED_TYPE_PREFIXES: [&str; 3] = ["image/", "video/", "audio/"];
+
+#[derive(Clone)]
+pub struct HttpMediaFetcher {
+    client: ResilientHttpClient,
+    max_bytes: usize,
+}
+
+impl HttpMediaFetcher {
+    pub fn new(client: ResilientHttpClient, max_bytes: usize) -> Self {
+        Self { client, max_bytes }
+    }
+}
+
+#[async_trait]
+impl MediaFetcher for HttpMediaFetcher {
+    async fn fetch(&self, url: &str) -> Result<RemoteMedia, DomainError> {
+        let response = self
+            .client
+            .get(url)
+            .await
+            .map_err(|e| DomainError::RemoteFetch(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DomainError::RemoteFetch(format!(
+                "remote answered {}",
+                response.status()
+            )));
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        if !ALLOWED_TYPE_PREFIXES
+            .iter()
+            .any(|prefix| content_type.starts_with(prefix))
+        {
+            return Err(DomainError::UnsupportedMediaType);
+        }
+
+        let body = http_client::read_body(response, self.max_bytes)
+            .await
+            .map_err(|e| match e {
+                HttpClientError::ResponseTooLarge(_) => DomainError::MediaTooLarge,
+                e => DomainError::RemoteFetch(e.to_string()),
+            })?;
+
+        Ok(RemoteMedia { content_type, body })
+    }
+}
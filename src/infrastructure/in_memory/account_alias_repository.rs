@@ -0,0 +1,59 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{
+        alias::AccountAlias,
+        user::{ActivityId, UserId},
+    },
+    repositories::account_alias_repository::AccountAliasRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryAccountAliasRepository {
+    aliases: Arc<RwLock<Vec<AccountAlias>>>,
+}
+
+impl InMemoryAccountAliasRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AccountAliasRepository for InMemoryAccountAliasRepository {
+    async fn list(&self, user_id: UserId) -> Result<Vec<AccountAlias>, RepositoryError> {
+        Ok(self
+            .aliases
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|alias| alias.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn add(&self, alias: &AccountAlias) -> Result<(), RepositoryError> {
+        let mut aliases = self.aliases.write().unwrap();
+        if aliases
+            .iter()
+            .any(|stored| stored.user_id == alias.user_id && stored.uri == alias.uri)
+        {
+            return Err(RepositoryError::Conflict { field: "alias" });
+        }
+        aliases.push(alias.clone());
+        Ok(())
+    }
+
+    async fn remove(&self, user_id: UserId, uri: &ActivityId) -> Result<(), RepositoryError> {
+        let mut aliases = self.aliases.write().unwrap();
+        let count = aliases.len();
+        aliases.retain(|alias| alias.user_id != user_id || &alias.uri != uri);
+        if aliases.len() == count {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
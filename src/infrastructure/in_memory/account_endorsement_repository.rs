@@ -0,0 +1,59 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{endorsement::Endorsement, user::UserId},
+    repositories::account_endorsement_repository::AccountEndorsementRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryAccountEndorsementRepository {
+    endorsements: Arc<RwLock<Vec<Endorsement>>>,
+}
+
+impl InMemoryAccountEndorsementRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AccountEndorsementRepository for InMemoryAccountEndorsementRepository {
+    async fn list(&self, user_id: UserId) -> Result<Vec<Endorsement>, RepositoryError> {
+        Ok(self
+            .endorsements
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|endorsement| endorsement.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn add(&self, endorsement: &Endorsement) -> Result<(), RepositoryError> {
+        let mut endorsements = self.endorsements.write().unwrap();
+        if endorsements.iter().any(|stored| {
+            stored.user_id == endorsement.user_id && stored.target_id == endorsement.target_id
+        }) {
+            return Err(RepositoryError::Conflict {
+                field: "endorsement",
+            });
+        }
+        endorsements.push(endorsement.clone());
+        Ok(())
+    }
+
+    async fn remove(&self, user_id: UserId, target_id: UserId) -> Result<(), RepositoryError> {
+        let mut endorsements = self.endorsements.write().unwrap();
+        let count = endorsements.len();
+        endorsements.retain(|endorsement| {
+            endorsement.user_id != user_id || endorsement.target_id != target_id
+        });
+        if endorsements.len() == count {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
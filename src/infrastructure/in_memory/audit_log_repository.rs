@@ -0,0 +1,59 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::audit_log::{AuditLogEntry, AuditLogFilter},
+    repositories::audit_log_repository::AuditLogRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryAuditLogRepository {
+    entries: Arc<RwLock<Vec<AuditLogEntry>>>,
+}
+
+impl InMemoryAuditLogRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Actions recorded so far, oldest first
+    pub fn actions(&self) -> Vec<String> {
+        self.entries
+            .read()
+            .unwrap()
+            .iter()
+            .map(|entry| entry.action.clone())
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for InMemoryAuditLogRepository {
+    async fn append(&self, entry: &AuditLogEntry) -> Result<(), RepositoryError> {
+        self.entries.write().unwrap().push(entry.clone());
+        Ok(())
+    }
+
+    async fn list(&self, filter: &AuditLogFilter) -> Result<Vec<AuditLogEntry>, RepositoryError> {
+        Ok(self
+            .entries
+            .read()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|entry| filter.actor_id.is_none_or(|id| entry.actor_id == Some(id)))
+            .filter(|entry| {
+                filter
+                    .action
+                    .as_ref()
+                    .is_none_or(|action| &entry.action == action)
+            })
+            .filter(|entry| filter.target_id.is_none_or(|id| entry.target_id == id))
+            .filter(|entry| filter.before.is_none_or(|before| entry.created_at < before))
+            .take(filter.limit as usize)
+            .cloned()
+            .collect())
+    }
+}
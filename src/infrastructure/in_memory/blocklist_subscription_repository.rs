@@ -0,0 +1,70 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::RepositoryError, models::federation::BlocklistSubscription,
+    repositories::blocklist_subscription_repository::BlocklistSubscriptionRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryBlocklistSubscriptionRepository {
+    subscriptions: Arc<RwLock<Vec<BlocklistSubscription>>>,
+}
+
+impl InMemoryBlocklistSubscriptionRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BlocklistSubscriptionRepository for InMemoryBlocklistSubscriptionRepository {
+    async fn list(&self) -> Result<Vec<BlocklistSubscription>, RepositoryError> {
+        Ok(self.subscriptions.read().unwrap().clone())
+    }
+
+    async fn find(&self, id: Uuid) -> Result<Option<BlocklistSubscription>, RepositoryError> {
+        let subscriptions = self.subscriptions.read().unwrap();
+        Ok(subscriptions
+            .iter()
+            .find(|subscription| subscription.id == id)
+            .cloned())
+    }
+
+    async fn create(&self, subscription: &BlocklistSubscription) -> Result<(), RepositoryError> {
+        let mut subscriptions = self.subscriptions.write().unwrap();
+        if subscriptions
+            .iter()
+            .any(|stored| stored.url == subscription.url)
+        {
+            return Err(RepositoryError::Conflict { field: "url" });
+        }
+        subscriptions.push(subscription.clone());
+        Ok(())
+    }
+
+    async fn update_sync(
+        &self,
+        subscription: &BlocklistSubscription,
+    ) -> Result<(), RepositoryError> {
+        let mut subscriptions = self.subscriptions.write().unwrap();
+        let stored = subscriptions
+            .iter_mut()
+            .find(|stored| stored.id == subscription.id)
+            .ok_or(RepositoryError::NotFound)?;
+        *stored = subscription.clone();
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let mut subscriptions = self.subscriptions.write().unwrap();
+        let before = subscriptions.len();
+        subscriptions.retain(|subscription| subscription.id != id);
+        match subscriptions.len() < before {
+            true => Ok(()),
+            false => Err(RepositoryError::NotFound),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use crate::domain::{
+    error::RepositoryError,
+    models::{
+        credential::Credential,
+        email::EmailAddress,
+        user::{ActivityId, UserId},
+    },
+    repositories::credential_repository::CredentialRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryCredentialRepository {
+    credentials: Arc<RwLock<HashMap<UserId, Credential>>>,
+}
+
+impl InMemoryCredentialRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a credential, failing when the activity id or email is already taken
+    pub fn insert(&self, credential: Credential) -> Result<(), RepositoryError> {
+        let mut credentials = self.credentials.write().unwrap();
+        if credentials.values().any(|existing| {
+            existing.activity_id() == credential.activity_id() || existing.email() == credential.email()
+        }) {
+            return Err(RepositoryError::DatabaseError(
+                "duplicate credential".to_string(),
+            ));
+        }
+        credentials.insert(credential.user_id(), credential);
+        Ok(())
+    }
+
+    pub fn contains_email(&self, email: &str) -> bool {
+        self.credentials
+            .read()
+            .unwrap()
+            .values()
+            .any(|credential| credential.email().as_str() == email)
+    }
+}
+
+#[async_trait]
+impl CredentialRepository for InMemoryCredentialRepository {
+    async fn get_credential(&self, activity_id: &ActivityId) -> Result<Credential, RepositoryError> {
+        self.credentials
+            .read()
+            .unwrap()
+            .values()
+            .find(|credential| credential.activity_id() == activity_id)
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn find_by_email(
+        &self,
+        email: &EmailAddress,
+    ) -> Result<Option<Credential>, RepositoryError> {
+        Ok(self
+            .credentials
+            .read()
+            .unwrap()
+            .values()
+            .find(|credential| credential.email() == email)
+            .cloned())
+    }
+
+    async fn update_credential(&self, credential: &Credential) -> Result<(), RepositoryError> {
+        let mut credentials = self.credentials.write().unwrap();
+        let stored = credentials
+            .get_mut(&credential.user_id())
+            .ok_or(RepositoryError::NotFound)?;
+        *stored = credential.clone();
+        Ok(())
+    }
+}
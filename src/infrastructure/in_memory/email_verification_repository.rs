@@ -0,0 +1,49 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{email_verification::EmailVerification, user::UserId},
+    repositories::email_verification_repository::EmailVerificationRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryEmailVerificationRepository {
+    verifications: Arc<RwLock<Vec<EmailVerification>>>,
+}
+
+impl InMemoryEmailVerificationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EmailVerificationRepository for InMemoryEmailVerificationRepository {
+    async fn create(&self, verification: &EmailVerification) -> Result<(), RepositoryError> {
+        self.verifications
+            .write()
+            .unwrap()
+            .push(verification.clone());
+        Ok(())
+    }
+
+    async fn is_pending(&self, user_id: UserId) -> Result<bool, RepositoryError> {
+        Ok(self
+            .verifications
+            .read()
+            .unwrap()
+            .iter()
+            .any(|verification| verification.user_id == user_id))
+    }
+
+    async fn confirm(&self, token_hash: &str) -> Result<UserId, RepositoryError> {
+        let mut verifications = self.verifications.write().unwrap();
+        let index = verifications
+            .iter()
+            .position(|verification| verification.token_hash == token_hash)
+            .ok_or(RepositoryError::NotFound)?;
+        Ok(verifications.remove(index).user_id)
+    }
+}
@@ -0,0 +1,54 @@
+use std::{
+    cmp::Reverse,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::RepositoryError, models::federation::FederationDomain,
+    repositories::federation_domain_repository::FederationDomainRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryFederationDomainRepository {
+    domains: Arc<RwLock<Vec<FederationDomain>>>,
+}
+
+impl InMemoryFederationDomainRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl FederationDomainRepository for InMemoryFederationDomainRepository {
+    async fn list(&self) -> Result<Vec<FederationDomain>, RepositoryError> {
+        let mut domains = self.domains.read().unwrap().clone();
+        domains.sort_by_key(|domain| Reverse(domain.created_at));
+        Ok(domains)
+    }
+
+    async fn create(&self, domain: &FederationDomain) -> Result<(), RepositoryError> {
+        let mut domains = self.domains.write().unwrap();
+        if domains
+            .iter()
+            .any(|stored| stored.domain == domain.domain && stored.policy == domain.policy)
+        {
+            return Err(RepositoryError::Conflict { field: "domain" });
+        }
+        domains.push(domain.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let mut domains = self.domains.write().unwrap();
+        let before = domains.len();
+        domains.retain(|domain| domain.id != id);
+        match domains.len() < before {
+            true => Ok(()),
+            false => Err(RepositoryError::NotFound),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    cmp::Reverse,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::RepositoryError, models::ip_block::IpBlock,
+    repositories::ip_block_repository::IpBlockRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryIpBlockRepository {
+    blocks: Arc<RwLock<Vec<IpBlock>>>,
+}
+
+impl InMemoryIpBlockRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl IpBlockRepository for InMemoryIpBlockRepository {
+    async fn list(&self) -> Result<Vec<IpBlock>, RepositoryError> {
+        let mut blocks = self.blocks.read().unwrap().clone();
+        blocks.sort_by_key(|block| Reverse(block.created_at));
+        Ok(blocks)
+    }
+
+    async fn create(&self, block: &IpBlock) -> Result<(), RepositoryError> {
+        self.blocks.write().unwrap().push(block.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let mut blocks = self.blocks.write().unwrap();
+        let before = blocks.len();
+        blocks.retain(|block| block.id != id);
+        match blocks.len() < before {
+            true => Ok(()),
+            false => Err(RepositoryError::NotFound),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{
+        legal::{LegalAcceptance, LegalDocument, LegalDocumentKind},
+        user::UserId,
+    },
+    repositories::legal_document_repository::LegalDocumentRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryLegalDocumentRepository {
+    documents: Arc<RwLock<Vec<LegalDocument>>>,
+    acceptances: Arc<RwLock<HashMap<(UserId, LegalDocumentKind), LegalAcceptance>>>,
+}
+
+impl InMemoryLegalDocumentRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LegalDocumentRepository for InMemoryLegalDocumentRepository {
+    async fn current(
+        &self,
+        kind: LegalDocumentKind,
+    ) -> Result<Option<LegalDocument>, RepositoryError> {
+        Ok(self
+            .documents
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|document| document.kind == kind)
+            .max_by_key(|document| document.version)
+            .cloned())
+    }
+
+    async fn publish(&self, document: &LegalDocument) -> Result<(), RepositoryError> {
+        let mut documents = self.documents.write().unwrap();
+        if documents
+            .iter()
+            .any(|stored| stored.kind == document.kind && stored.version == document.version)
+        {
+            return Err(RepositoryError::DatabaseError(
+                "duplicate document version".to_string(),
+            ));
+        }
+        documents.push(document.clone());
+        Ok(())
+    }
+
+    async fn acceptances(&self, user_id: UserId) -> Result<Vec<LegalAcceptance>, RepositoryError> {
+        Ok(self
+            .acceptances
+            .read()
+            .unwrap()
+            .values()
+            .filter(|acceptance| acceptance.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn accept(&self, acceptance: &LegalAcceptance) -> Result<(), RepositoryError> {
+        self.acceptances
+            .write()
+            .unwrap()
+            .insert((acceptance.user_id, acceptance.kind), acceptance.clone());
+        Ok(())
+    }
+}
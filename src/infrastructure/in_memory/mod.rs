@@ -0,0 +1,20 @@
+//! HashMap backed implementations of the repository traits
+//! Used to unit-test usecases without a database
+
+pub mod account_alias_repository;
+pub mod account_endorsement_repository;
+pub mod audit_log_repository;
+pub mod blocklist_subscription_repository;
+pub mod credential_repository;
+pub mod email_verification_repository;
+pub mod federation_domain_repository;
+pub mod ip_block_repository;
+pub mod legal_document_repository;
+pub mod notification_preferences_repository;
+pub mod posting_preferences_repository;
+pub mod refresh_token_repository;
+pub mod report_repository;
+pub mod rule_repository;
+pub mod unit_of_work;
+pub mod user_registration_repository;
+pub mod user_repository;
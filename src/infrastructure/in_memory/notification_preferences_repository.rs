@@ -0,0 +1,41 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{notification_preferences::NotificationPreferences, user::UserId},
+    repositories::notification_preferences_repository::NotificationPreferencesRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryNotificationPreferencesRepository {
+    preferences: Arc<RwLock<HashMap<UserId, NotificationPreferences>>>,
+}
+
+impl InMemoryNotificationPreferencesRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl NotificationPreferencesRepository for InMemoryNotificationPreferencesRepository {
+    async fn find(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<NotificationPreferences>, RepositoryError> {
+        Ok(self.preferences.read().unwrap().get(&user_id).cloned())
+    }
+
+    async fn save(&self, preferences: &NotificationPreferences) -> Result<(), RepositoryError> {
+        self.preferences
+            .write()
+            .unwrap()
+            .insert(preferences.user_id, preferences.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{posting_preferences::PostingPreferences, user::UserId},
+    repositories::posting_preferences_repository::PostingPreferencesRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryPostingPreferencesRepository {
+    preferences: Arc<RwLock<HashMap<UserId, PostingPreferences>>>,
+}
+
+impl InMemoryPostingPreferencesRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl PostingPreferencesRepository for InMemoryPostingPreferencesRepository {
+    async fn find(&self, user_id: UserId) -> Result<Option<PostingPreferences>, RepositoryError> {
+        Ok(self.preferences.read().unwrap().get(&user_id).cloned())
+    }
+
+    async fn save(&self, preferences: &PostingPreferences) -> Result<(), RepositoryError> {
+        self.preferences
+            .write()
+            .unwrap()
+            .insert(preferences.user_id, preferences.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::{refresh_token::RefreshToken, user::UserId},
+    repositories::refresh_token_repository::RefreshTokenRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryRefreshTokenRepository {
+    tokens: Arc<RwLock<Vec<RefreshToken>>>,
+}
+
+impl InMemoryRefreshTokenRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for InMemoryRefreshTokenRepository {
+    async fn create(&self, token: &RefreshToken) -> Result<(), RepositoryError> {
+        self.tokens.write().unwrap().push(token.clone());
+        Ok(())
+    }
+
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<RefreshToken>, RepositoryError> {
+        Ok(self
+            .tokens
+            .read()
+            .unwrap()
+            .iter()
+            .find(|token| token.token_hash == hash)
+            .cloned())
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let mut tokens = self.tokens.write().unwrap();
+        match tokens
+            .iter_mut()
+            .find(|token| token.id == id && token.revoked_at.is_none())
+        {
+            Some(token) => {
+                token.revoked_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), RepositoryError> {
+        for token in self.tokens.write().unwrap().iter_mut() {
+            if token.family_id == family_id && token.revoked_at.is_none() {
+                token.revoked_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+
+    async fn revoke_all_for_user(&self, user_id: UserId) -> Result<(), RepositoryError> {
+        for token in self.tokens.write().unwrap().iter_mut() {
+            if token.user_id == user_id && token.revoked_at.is_none() {
+                token.revoked_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::RepositoryError,
+    models::report::{Report, ReportNote},
+    repositories::report_repository::ReportRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryReportRepository {
+    reports: Arc<RwLock<HashMap<Uuid, Report>>>,
+    notes: Arc<RwLock<Vec<ReportNote>>>,
+}
+
+impl InMemoryReportRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ReportRepository for InMemoryReportRepository {
+    async fn create(&self, report: &Report) -> Result<(), RepositoryError> {
+        self.reports
+            .write()
+            .unwrap()
+            .insert(report.id, report.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Report>, RepositoryError> {
+        Ok(self.reports.read().unwrap().get(&id).cloned())
+    }
+
+    async fn list(&self, resolved: Option<bool>) -> Result<Vec<Report>, RepositoryError> {
+        let mut reports: Vec<Report> = self
+            .reports
+            .read()
+            .unwrap()
+            .values()
+            .filter(|report| resolved.is_none_or(|resolved| report.is_resolved() == resolved))
+            .cloned()
+            .collect();
+        reports.sort_by_key(|report| Reverse(report.created_at));
+        Ok(reports)
+    }
+
+    async fn update(&self, report: &Report) -> Result<(), RepositoryError> {
+        let mut reports = self.reports.write().unwrap();
+        let stored = reports.get_mut(&report.id).ok_or(RepositoryError::NotFound)?;
+        *stored = report.clone();
+        Ok(())
+    }
+
+    async fn add_note(&self, note: &ReportNote) -> Result<(), RepositoryError> {
+        self.notes.write().unwrap().push(note.clone());
+        Ok(())
+    }
+
+    async fn notes(&self, report_id: Uuid) -> Result<Vec<ReportNote>, RepositoryError> {
+        Ok(self
+            .notes
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|note| note.report_id == report_id)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::RepositoryError, models::rule::Rule, repositories::rule_repository::RuleRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryRuleRepository {
+    rules: Arc<RwLock<HashMap<Uuid, Rule>>>,
+}
+
+impl InMemoryRuleRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RuleRepository for InMemoryRuleRepository {
+    async fn list(&self) -> Result<Vec<Rule>, RepositoryError> {
+        let mut rules: Vec<Rule> = self.rules.read().unwrap().values().cloned().collect();
+        rules.sort_by_key(|rule| (rule.position, rule.created_at));
+        Ok(rules)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Rule>, RepositoryError> {
+        Ok(self.rules.read().unwrap().get(&id).cloned())
+    }
+
+    async fn create(&self, rule: &Rule) -> Result<(), RepositoryError> {
+        self.rules.write().unwrap().insert(rule.id, rule.clone());
+        Ok(())
+    }
+
+    async fn update(&self, rule: &Rule) -> Result<(), RepositoryError> {
+        let mut rules = self.rules.write().unwrap();
+        let stored = rules.get_mut(&rule.id).ok_or(RepositoryError::NotFound)?;
+        *stored = rule.clone();
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        self.rules
+            .write()
+            .unwrap()
+            .remove(&id)
+            .map(|_| ())
+            .ok_or(RepositoryError::NotFound)
+    }
+}
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        repositories::unit_of_work::{Transaction, UnitOfWork},
+    },
+    infrastructure::in_memory::{
+        audit_log_repository::InMemoryAuditLogRepository,
+        email_verification_repository::InMemoryEmailVerificationRepository,
+        legal_document_repository::InMemoryLegalDocumentRepository,
+        user_registration_repository::InMemoryUserRegistrationRepository,
+    },
+};
+
+/// Hands out the given repositories as the transaction
+/// Writes are applied immediately, a dropped transaction is not rolled back
+#[derive(Clone, Default)]
+pub struct InMemoryUnitOfWork {
+    pub registrations: InMemoryUserRegistrationRepository,
+    pub legal_documents: InMemoryLegalDocumentRepository,
+    pub audit_log: InMemoryAuditLogRepository,
+    pub email_verifications: InMemoryEmailVerificationRepository,
+}
+
+#[async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    type Transaction = InMemoryUnitOfWork;
+
+    async fn begin(&self) -> Result<InMemoryUnitOfWork, RepositoryError> {
+        Ok(self.clone())
+    }
+}
+
+#[async_trait]
+impl Transaction for InMemoryUnitOfWork {
+    type Registrations = InMemoryUserRegistrationRepository;
+    type LegalDocuments = InMemoryLegalDocumentRepository;
+    type AuditLog = InMemoryAuditLogRepository;
+    type EmailVerifications = InMemoryEmailVerificationRepository;
+
+    fn registrations(&self) -> &Self::Registrations {
+        &self.registrations
+    }
+
+    fn legal_documents(&self) -> &Self::LegalDocuments {
+        &self.legal_documents
+    }
+
+    fn audit_log(&self) -> &Self::AuditLog {
+        &self.audit_log
+    }
+
+    fn email_verifications(&self) -> &Self::EmailVerifications {
+        &self.email_verifications
+    }
+
+    async fn commit(self) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
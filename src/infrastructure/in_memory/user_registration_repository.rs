@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            credential::{Credential, HashedPassword},
+            email::EmailAddress,
+            moderation::Moderation,
+            user::{ActivityId, Role, User, UserId},
+        },
+        repositories::user_registration_repository::UserRegistrationRepository,
+    },
+    infrastructure::in_memory::{
+        credential_repository::InMemoryCredentialRepository,
+        user_repository::InMemoryUserRepository,
+    },
+};
+
+/// Writes through the given user and credential stores,
+/// so registered users are visible to the other in-memory repositories
+#[derive(Clone, Default)]
+pub struct InMemoryUserRegistrationRepository {
+    users: InMemoryUserRepository,
+    credentials: InMemoryCredentialRepository,
+}
+
+impl InMemoryUserRegistrationRepository {
+    pub fn new(users: InMemoryUserRepository, credentials: InMemoryCredentialRepository) -> Self {
+        Self { users, credentials }
+    }
+}
+
+#[async_trait]
+impl UserRegistrationRepository for InMemoryUserRegistrationRepository {
+    async fn register_user_with_credentials(
+        &self,
+        activity_id: &ActivityId,
+        display_name: &str,
+        role: Role,
+        disabled: bool,
+        password_hash: HashedPassword,
+        email: EmailAddress,
+    ) -> Result<User, RepositoryError> {
+        // check every constraint first so a failure leaves nothing behind
+        if self.users.contains_activity_id(activity_id)
+            || self.users.contains_username(activity_id.username())
+        {
+            return Err(RepositoryError::Conflict { field: "username" });
+        }
+        if self.credentials.contains_email(email.as_str()) {
+            return Err(RepositoryError::Conflict { field: "email" });
+        }
+
+        let user_id = UserId::new(Uuid::new_v4());
+        let user = User::new(user_id, activity_id.clone(), display_name.to_string(), None)
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .with_role(role)
+            .with_moderation(Moderation {
+                disabled,
+                ..Default::default()
+            });
+
+        self.users.insert(user.clone())?;
+        self.credentials.insert(Credential::new(
+            user_id,
+            activity_id.clone(),
+            password_hash,
+            email,
+        ))?;
+
+        Ok(user)
+    }
+}
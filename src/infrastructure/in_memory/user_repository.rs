@@ -0,0 +1,127 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    error::RepositoryError,
+    models::user::{ActivityId, User, UserId, normalize_username},
+    repositories::user_repository::UserRepository,
+};
+
+#[derive(Clone, Default)]
+pub struct InMemoryUserRepository {
+    users: Arc<RwLock<HashMap<UserId, User>>>,
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store a user, failing when the activity id or username is already taken
+    pub fn insert(&self, user: User) -> Result<(), RepositoryError> {
+        let mut users = self.users.write().unwrap();
+        if users
+            .values()
+            .any(|existing| existing.activity_id() == user.activity_id())
+        {
+            return Err(RepositoryError::DatabaseError(
+                "duplicate activity_id".to_string(),
+            ));
+        }
+        if users
+            .values()
+            .any(|existing| same_username(existing, user.activity_id().username()))
+        {
+            return Err(RepositoryError::DatabaseError(
+                "duplicate username".to_string(),
+            ));
+        }
+        users.insert(user.id(), user);
+        Ok(())
+    }
+
+    pub fn contains_activity_id(&self, activity_id: &ActivityId) -> bool {
+        self.users
+            .read()
+            .unwrap()
+            .values()
+            .any(|user| user.activity_id() == activity_id)
+    }
+
+    pub fn contains_username(&self, username: &str) -> bool {
+        self.users
+            .read()
+            .unwrap()
+            .values()
+            .any(|user| same_username(user, username))
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn find_by_username(&self, username: &str) -> Result<Option<User>, RepositoryError> {
+        Ok(self
+            .users
+            .read()
+            .unwrap()
+            .values()
+            .find(|user| same_username(user, username))
+            .cloned())
+    }
+
+    async fn find_by_id(&self, id: UserId) -> Result<Option<User>, RepositoryError> {
+        Ok(self.users.read().unwrap().get(&id).cloned())
+    }
+
+    async fn update_moderation(&self, user: &User) -> Result<(), RepositoryError> {
+        let mut users = self.users.write().unwrap();
+        let stored = users.get_mut(&user.id()).ok_or(RepositoryError::NotFound)?;
+        *stored = user.clone();
+        Ok(())
+    }
+
+    async fn update_verification(&self, user: &User) -> Result<(), RepositoryError> {
+        let mut users = self.users.write().unwrap();
+        let stored = users.get_mut(&user.id()).ok_or(RepositoryError::NotFound)?;
+        *stored = user.clone();
+        Ok(())
+    }
+
+    async fn update_profile(
+        &self,
+        user: &User,
+        expected_updated_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut users = self.users.write().unwrap();
+        let stored = users.get_mut(&user.id()).ok_or(RepositoryError::NotFound)?;
+        if stored.updated_at() != expected_updated_at {
+            return Err(RepositoryError::Outdated);
+        }
+        *stored = user.clone();
+        Ok(())
+    }
+
+    async fn mark_deleted(&self, user: &User) -> Result<(), RepositoryError> {
+        let mut users = self.users.write().unwrap();
+        let stored = users.get_mut(&user.id()).ok_or(RepositoryError::NotFound)?;
+        *stored = user.clone();
+        Ok(())
+    }
+
+    async fn purge_deleted(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let mut users = self.users.write().unwrap();
+        let count = users.len();
+        users.retain(|_, user| user.deleted_at().is_none_or(|at| at >= before));
+        Ok((count - users.len()) as u64)
+    }
+}
+
+/// helper function that compare usernames the way the unique index does
+fn same_username(user: &User, username: &str) -> bool {
+    normalize_username(user.activity_id().username()) == normalize_username(username)
+}
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use entity::ip_blocks;
+use sea_orm::{ActiveValue::Set, EntityTrait, QueryOrder};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::ip_block::{IpBlock, IpBlockSeverity, parse_network},
+        repositories::ip_block_repository::IpBlockRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresIpBlockRepository {
+    db: DatabasePool,
+}
+
+impl PostgresIpBlockRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl IpBlockRepository for PostgresIpBlockRepository {
+    #[instrument(skip(self), err)]
+    async fn list(&self) -> Result<Vec<IpBlock>, RepositoryError> {
+        let blocks = ip_blocks::Entity::find()
+            .order_by_desc(ip_blocks::Column::CreatedAt)
+            .all(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        blocks.into_iter().map(to_domain).collect()
+    }
+
+    #[instrument(skip_all, fields(ip_block_id = %block.id), err)]
+    async fn create(&self, block: &IpBlock) -> Result<(), RepositoryError> {
+        let model = ip_blocks::ActiveModel {
+            id: Set(block.id),
+            network: Set(block.network.to_string()),
+            severity: Set(block.severity.as_str().to_string()),
+            comment: Set(block.comment.clone()),
+            expires_at: Set(block.expires_at.map(|at| at.fixed_offset())),
+            created_at: Set(block.created_at.fixed_offset()),
+        };
+        ip_blocks::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let result = ip_blocks::Entity::delete_by_id(id)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+
+        match result.rows_affected {
+            0 => Err(RepositoryError::NotFound),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// helper function that convert an ip_blocks row into the domain model
+fn to_domain(model: ip_blocks::Model) -> Result<IpBlock, RepositoryError> {
+    let corrupted = |_| RepositoryError::DatabaseError(format!("invalid ip block {}", model.id));
+    Ok(IpBlock {
+        id: model.id,
+        network: parse_network(&model.network).map_err(corrupted)?,
+        severity: IpBlockSeverity::parse(&model.severity).map_err(corrupted)?,
+        comment: model.comment,
+        expires_at: model.expires_at.map(|at| at.naive_utc().and_utc()),
+        created_at: model.created_at.naive_utc().and_utc(),
+    })
+}
@@ -1,11 +1,12 @@
-use chrono::{Duration, Utc};
-use jsonwebtoken::{EncodingKey, Header, encode};
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use crate::domain::{
     error::DomainError,
-    models::user::User,
-    services::token_service::{Token, TokenGenerator},
+    models::user::{User, UserId},
+    services::token_service::{DEFAULT_SCOPES, Token, TokenGenerator, TokenType, TokenVerifier},
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +15,14 @@ struct Claims {
     activity_id: String, // Activity ID
     exp: i64,            // Expiration time
     iat: i64,            // Issued at
+    /// Space separated, tokens issued before scopes existed carry the default ones
+    #[serde(default = "default_scope")]
+    scope: String,
+}
+
+/// helper function that give the scope claim of tokens issued without one
+fn default_scope() -> String {
+    DEFAULT_SCOPES.join(" ")
 }
 
 #[derive(Clone)]
@@ -36,10 +45,9 @@ impl JwtTokenGenerator {
             expiration_hours,
         }
     }
-}
 
-impl TokenGenerator for JwtTokenGenerator {
-    fn generate(&self, user: &User) -> Result<Token, DomainError> {
+    /// Token granting `scopes` instead of the login ones, for service credentials
+    pub fn generate_with_scopes(&self, user: &User, scopes: &[&str]) -> Result<Token, DomainError> {
         let now = Utc::now();
         let exp = now + Duration::hours(self.expiration_hours);
 
@@ -48,9 +56,10 @@ impl TokenGenerator for JwtTokenGenerator {
             activity_id: user.activity_id().as_str().to_string(),
             exp: exp.timestamp(),
             iat: now.timestamp(),
+            scope: scopes.join(" "),
         };
 
-        encode(
+        let value = encode(
             &Header::default(),
             &claims,
             &EncodingKey::from_secret(self.secret.as_bytes()),
@@ -59,6 +68,124 @@ impl TokenGenerator for JwtTokenGenerator {
             DomainError::Repository(crate::domain::error::RepositoryError::DatabaseError(
                 format!("Failed to generate token: {}", e),
             ))
+        })?;
+
+        Ok(Token {
+            value,
+            token_type: TokenType::Bearer,
+            user_id: user.id(),
+            expires_at: exp,
+            scopes: scopes.iter().map(|scope| scope.to_string()).collect(),
+        })
+    }
+}
+
+impl TokenGenerator for JwtTokenGenerator {
+    fn generate(&self, user: &User) -> Result<Token, DomainError> {
+        self.generate_with_scopes(user, &DEFAULT_SCOPES)
+    }
+}
+
+impl TokenVerifier for JwtTokenGenerator {
+    fn verify(&self, token: &str) -> Result<Token, DomainError> {
+        let data = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(self.secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| DomainError::AuthenticationFailed)?;
+
+        Ok(Token {
+            value: token.to_string(),
+            token_type: TokenType::Bearer,
+            user_id: Uuid::parse_str(&data.claims.sub)
+                .map(UserId::from)
+                .map_err(|_| DomainError::AuthenticationFailed)?,
+            expires_at: DateTime::from_timestamp(data.claims.exp, 0)
+                .ok_or(DomainError::AuthenticationFailed)?,
+            scopes: data
+                .claims
+                .scope
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::fixtures::UserBuilder;
+
+    #[test]
+    fn test_generate_reports_expiry_positive() {
+        let generator = JwtTokenGenerator::with_expiration("secret".to_string(), 2);
+
+        let token = generator.generate(&UserBuilder::default().build()).unwrap();
+
+        assert_eq!(TokenType::Bearer, token.token_type);
+        assert!((7190..=7200).contains(&token.expires_in()));
+    }
+
+    #[test]
+    fn test_verify_token_without_scope_positive() {
+        let now = Utc::now().timestamp();
+        let user = UserBuilder::default().build();
+        let legacy = serde_json::json!({
+            "sub": user.id().to_string(),
+            "activity_id": user.activity_id().as_str(),
+            "exp": now + 60,
+            "iat": now,
+        });
+        let token = encode(
+            &Header::default(),
+            &legacy,
+            &EncodingKey::from_secret("secret".as_bytes()),
+        )
+        .unwrap();
+
+        let verified = JwtTokenGenerator::new("secret".to_string())
+            .verify(&token)
+            .unwrap();
+
+        assert!(verified.has_scope("read"));
+        assert!(verified.has_scope("write"));
+    }
+
+    #[test]
+    fn test_verify_positive() {
+        let generator = JwtTokenGenerator::new("secret".to_string());
+        let user = UserBuilder::default().build();
+
+        let token = generator.generate(&user).unwrap();
+        let verified = generator.verify(&token.value).unwrap();
+
+        assert_eq!(user.id(), verified.user_id);
+        assert_eq!(token.expires_at.timestamp(), verified.expires_at.timestamp());
+        assert!(verified.has_scope("write"));
+    }
+
+    #[test]
+    fn test_verify_other_secret_negative() {
+        let user = UserBuilder::default().build();
+        let token = JwtTokenGenerator::new("secret".to_string())
+            .generate(&user)
+            .unwrap();
+
+        let result = JwtTokenGenerator::new("other".to_string()).verify(&token.value);
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn test_verify_expired_negative() {
+        let generator = JwtTokenGenerator::with_expiration("secret".to_string(), -1);
+        let token = generator.generate(&UserBuilder::default().build()).unwrap();
+
+        assert!(matches!(
+            generator.verify(&token.value),
+            Err(DomainError::AuthenticationFailed)
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use entity::{legal_acceptances, legal_documents};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, sea_query::OnConflict,
+};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            legal::{LegalAcceptance, LegalDocument, LegalDocumentKind},
+            user::UserId,
+        },
+        repositories::legal_document_repository::LegalDocumentRepository,
+    },
+    infrastructure::database::{Connections, DatabasePool, db_error},
+};
+
+/// Runs on the pool by default, or on a `TransactionScope` inside a unit of work
+#[derive(Clone)]
+pub struct PostgresLegalDocumentRepository<C: Connections = DatabasePool> {
+    db: C,
+}
+
+impl<C: Connections> PostgresLegalDocumentRepository<C> {
+    pub fn new(db: C) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl<C: Connections> LegalDocumentRepository for PostgresLegalDocumentRepository<C> {
+    #[instrument(skip(self), err)]
+    async fn current(
+        &self,
+        kind: LegalDocumentKind,
+    ) -> Result<Option<LegalDocument>, RepositoryError> {
+        let model = legal_documents::Entity::find()
+            .filter(legal_documents::Column::Kind.eq(kind.as_str()))
+            .order_by_desc(legal_documents::Column::Version)
+            .one(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        Ok(model.map(|model| LegalDocument {
+            id: model.id,
+            kind,
+            version: model.version,
+            content: model.content,
+            published_at: model.published_at.naive_utc().and_utc(),
+        }))
+    }
+
+    #[instrument(skip_all, fields(kind = document.kind.as_str(), version = document.version), err)]
+    async fn publish(&self, document: &LegalDocument) -> Result<(), RepositoryError> {
+        let model = legal_documents::ActiveModel {
+            id: Set(document.id),
+            kind: Set(document.kind.as_str().to_string()),
+            version: Set(document.version),
+            content: Set(document.content.clone()),
+            published_at: Set(document.published_at.fixed_offset()),
+        };
+        legal_documents::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn acceptances(&self, user_id: UserId) -> Result<Vec<LegalAcceptance>, RepositoryError> {
+        let models = legal_acceptances::Entity::find()
+            .filter(legal_acceptances::Column::UserId.eq(user_id.as_uuid()))
+            .all(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        // rows of kinds this version does not know about are skipped
+        Ok(models
+            .into_iter()
+            .filter_map(|model| {
+                Some(LegalAcceptance {
+                    user_id: UserId::from(model.user_id),
+                    kind: LegalDocumentKind::parse(&model.kind).ok()?,
+                    version: model.version,
+                    accepted_at: model.accepted_at.naive_utc().and_utc(),
+                })
+            })
+            .collect())
+    }
+
+    #[instrument(skip_all, fields(user_id = %acceptance.user_id), err)]
+    async fn accept(&self, acceptance: &LegalAcceptance) -> Result<(), RepositoryError> {
+        let model = legal_acceptances::ActiveModel {
+            user_id: Set(acceptance.user_id.as_uuid()),
+            kind: Set(acceptance.kind.as_str().to_string()),
+            version: Set(acceptance.version),
+            accepted_at: Set(acceptance.accepted_at.fixed_offset()),
+        };
+        legal_acceptances::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([
+                    legal_acceptances::Column::UserId,
+                    legal_acceptances::Column::Kind,
+                ])
+                .update_columns([
+                    legal_acceptances::Column::Version,
+                    legal_acceptances::Column::AcceptedAt,
+                ])
+                .to_owned(),
+            )
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::DomainError,
+    services::email_service::{EmailMessage, EmailSender},
+};
+
+/// Development backend that writes emails to the log instead of sending them
+#[derive(Clone, Default)]
+pub struct LogEmailSender;
+
+#[async_trait]
+impl EmailSender for LogEmailSender {
+    async fn send(&self, message: EmailMessage) -> Result<(), DomainError> {
+        tracing::info!(
+            to = %message.to,
+            subject = %message.subject,
+            body = %message.body,
+            "email (not sent, log backend)"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+
+use crate::domain::services::email_service::MailDomainVerifier;
+
+/// Accepts every domain, used unless the `mx-check` feature is enabled
+#[derive(Clone, Default)]
+pub struct AnyMailDomain;
+
+#[async_trait]
+impl MailDomainVerifier for AnyMailDomain {
+    async fn accepts_mail(&self, _domain: &str) -> bool {
+        true
+    }
+}
+
+/// Accepts domains publishing a mail exchanger, or an address record (implicit MX, RFC 5321)
+/// Domains declaring a null MX (RFC 7505) are refused
+#[cfg(feature = "mx-check")]
+#[derive(Clone)]
+pub struct MxMailDomainVerifier {
+    resolver: hickory_resolver::TokioAsyncResolver,
+}
+
+#[cfg(feature = "mx-check")]
+impl MxMailDomainVerifier {
+    /// Resolver configured from the system (`/etc/resolv.conf`)
+    pub fn from_system_conf() -> Result<Self, hickory_resolver::error::ResolveError> {
+        Ok(Self {
+            resolver: hickory_resolver::TokioAsyncResolver::tokio_from_system_conf()?,
+        })
+    }
+}
+
+#[cfg(feature = "mx-check")]
+#[async_trait]
+impl MailDomainVerifier for MxMailDomainVerifier {
+    #[tracing::instrument(skip(self))]
+    async fn accepts_mail(&self, domain: &str) -> bool {
+        use hickory_resolver::error::ResolveErrorKind;
+
+        // trailing dot, so the name is never completed with the local search domains
+        let fqdn = format!("{}.", domain);
+        let lookup = match self.resolver.mx_lookup(fqdn.as_str()).await {
+            Ok(lookup) => Ok(lookup.iter().any(|mx| !mx.exchange().is_root())),
+            Err(e) if matches!(e.kind(), ResolveErrorKind::NoRecordsFound { .. }) => {
+                self.resolver.lookup_ip(fqdn.as_str()).await.map(|_| true)
+            }
+            Err(e) => Err(e),
+        };
+
+        match lookup {
+            Ok(accepts) => accepts,
+            Err(e) if matches!(e.kind(), ResolveErrorKind::NoRecordsFound { .. }) => false,
+            Err(e) => {
+                tracing::warn!(error = %e, "mail domain lookup failed, accepting");
+                true
+            }
+        }
+    }
+}
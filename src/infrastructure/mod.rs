@@ -1,5 +1,32 @@
+pub mod account_alias_repository;
+pub mod account_endorsement_repository;
 pub mod argon2_password_hasher;
+pub mod audit_log_repository;
+pub mod blocklist_subscription_repository;
 pub mod credential_repository;
+pub mod database;
+pub mod email_queue;
+pub mod email_verification_repository;
+pub mod federation_domain_repository;
+pub mod hmac_url_signer;
+pub mod http_blocklist_fetcher;
+pub mod http_client;
+pub mod http_media_fetcher;
+#[cfg(test)]
+pub mod in_memory;
+pub mod ip_block_repository;
 pub mod jwt_token_generator;
+pub mod legal_document_repository;
+pub mod log_email_sender;
+pub mod mail_domain_verifier;
+pub mod notification_preferences_repository;
+pub mod outbound_guard;
+pub mod posting_preferences_repository;
+pub mod refresh_token_repository;
+pub mod report_repository;
+pub mod rule_repository;
+pub mod smtp_email_sender;
+pub mod spam_checker;
+pub mod unit_of_work;
 pub mod user_registration_repository;
 pub mod user_repository;
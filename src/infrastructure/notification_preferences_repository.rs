@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use entity::notification_preferences;
+use sea_orm::{ActiveValue::Set, EntityTrait, sea_query::OnConflict};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            notification_preferences::{DigestFrequency, NotificationPreferences},
+            user::UserId,
+        },
+        repositories::notification_preferences_repository::NotificationPreferencesRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresNotificationPreferencesRepository {
+    db: DatabasePool,
+}
+
+impl PostgresNotificationPreferencesRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl NotificationPreferencesRepository for PostgresNotificationPreferencesRepository {
+    #[instrument(skip(self), err)]
+    async fn find(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<NotificationPreferences>, RepositoryError> {
+        let model = notification_preferences::Entity::find_by_id(user_id.as_uuid())
+            .one(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        model.map(to_domain).transpose()
+    }
+
+    #[instrument(skip_all, fields(user_id = %preferences.user_id), err)]
+    async fn save(&self, preferences: &NotificationPreferences) -> Result<(), RepositoryError> {
+        let model = notification_preferences::ActiveModel {
+            user_id: Set(preferences.user_id.as_uuid()),
+            email_on_follow: Set(preferences.email_on_follow),
+            email_on_mention: Set(preferences.email_on_mention),
+            email_on_report_resolved: Set(preferences.email_on_report_resolved),
+            digest: Set(preferences.digest.as_str().to_string()),
+            updated_at: Set(preferences.updated_at.fixed_offset()),
+        };
+        notification_preferences::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(notification_preferences::Column::UserId)
+                    .update_columns([
+                        notification_preferences::Column::EmailOnFollow,
+                        notification_preferences::Column::EmailOnMention,
+                        notification_preferences::Column::EmailOnReportResolved,
+                        notification_preferences::Column::Digest,
+                        notification_preferences::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+/// helper function that convert a notification_preferences row into the domain model
+fn to_domain(
+    model: notification_preferences::Model,
+) -> Result<NotificationPreferences, RepositoryError> {
+    Ok(NotificationPreferences {
+        user_id: UserId::from(model.user_id),
+        email_on_follow: model.email_on_follow,
+        email_on_mention: model.email_on_mention,
+        email_on_report_resolved: model.email_on_report_resolved,
+        digest: DigestFrequency::parse(&model.digest).map_err(|_| {
+            RepositoryError::DatabaseError(format!("invalid digest {}", model.digest))
+        })?,
+        updated_at: model.updated_at.naive_utc().and_utc(),
+    })
+}
@@ -0,0 +1,146 @@
+use std::{
+    error::Error,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+use reqwest::{
+    Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+};
+use url::Host;
+
+use crate::infrastructure::http_client::HttpClientError;
+
+/// Resolver that drops every address outside the public internet
+/// Checking after resolution (instead of on the host name) also covers DNS rebinding
+#[derive(Debug, Clone, Copy)]
+pub struct PublicOnlyResolver;
+
+impl Resolve for PublicOnlyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let public: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_ip(addr.ip()))
+                .collect();
+
+            if public.is_empty() {
+                return Err(Box::new(HttpClientError::BlockedAddress(host)) as Box<dyn Error + Send + Sync>);
+            }
+            Ok(Box::new(public.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Reject URLs the client must never call: non HTTP schemes and private IP literals
+/// (IP literals bypass the resolver, so they are checked here)
+pub fn validate_url(url: &Url) -> Result<(), HttpClientError> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(HttpClientError::BlockedScheme(url.scheme().to_string()));
+    }
+
+    match url.host() {
+        None => Err(HttpClientError::MissingHost),
+        Some(Host::Ipv4(ip)) if !is_public_ip(IpAddr::V4(ip)) => {
+            Err(HttpClientError::BlockedAddress(ip.to_string()))
+        }
+        Some(Host::Ipv6(ip)) if !is_public_ip(IpAddr::V6(ip)) => {
+            Err(HttpClientError::BlockedAddress(ip.to_string()))
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+/// Whether an address is routable on the public internet
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_ipv4(mapped),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // 0.0.0.0/8 "this network"
+        || a == 0
+        // 100.64.0.0/10 carrier grade NAT
+        || (a == 100 && (b & 0xc0) == 64)
+        // 192.0.0.0/24 protocol assignments
+        || (a == 192 && b == 0 && c == 0)
+        // 198.18.0.0/15 benchmarking
+        || (a == 198 && (b & 0xfe) == 18)
+        // 240.0.0.0/4 reserved
+        || a >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        // 2001:db8::/32 documentation
+        || (first == 0x2001 && ip.segments()[1] == 0x0db8)
+        // 64:ff9b:1::/48 local NAT64
+        || (first == 0x0064 && ip.segments()[1] == 0xff9b && ip.segments()[2] == 0x0001))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_public_ip_positive() {
+        for ip in ["93.184.216.34", "2606:2800:220:1:248:1893:25c8:1946", "::ffff:93.184.216.34"] {
+            assert!(is_public_ip(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_is_public_ip_negative() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fc00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_ip(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_validate_url_positive() {
+        assert!(validate_url(&"https://remote.example/users/alice".parse().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_url_negative() {
+        for url in [
+            "file:///etc/passwd",
+            "gopher://remote.example/",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]:8080/",
+        ] {
+            assert!(validate_url(&url.parse().unwrap()).is_err(), "{url}");
+        }
+    }
+}
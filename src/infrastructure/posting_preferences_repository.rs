@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use entity::posting_preferences;
+use sea_orm::{ActiveValue::Set, EntityTrait, sea_query::OnConflict};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            posting_preferences::{PostingPreferences, Visibility},
+            user::UserId,
+        },
+        repositories::posting_preferences_repository::PostingPreferencesRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresPostingPreferencesRepository {
+    db: DatabasePool,
+}
+
+impl PostgresPostingPreferencesRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PostingPreferencesRepository for PostgresPostingPreferencesRepository {
+    #[instrument(skip(self), err)]
+    async fn find(&self, user_id: UserId) -> Result<Option<PostingPreferences>, RepositoryError> {
+        let model = posting_preferences::Entity::find_by_id(user_id.as_uuid())
+            .one(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        model.map(to_domain).transpose()
+    }
+
+    #[instrument(skip_all, fields(user_id = %preferences.user_id), err)]
+    async fn save(&self, preferences: &PostingPreferences) -> Result<(), RepositoryError> {
+        let model = posting_preferences::ActiveModel {
+            user_id: Set(preferences.user_id.as_uuid()),
+            default_visibility: Set(preferences.default_visibility.as_str().to_string()),
+            default_language: Set(preferences.default_language.clone()),
+            default_sensitive: Set(preferences.default_sensitive),
+            mark_media_sensitive: Set(preferences.mark_media_sensitive),
+            updated_at: Set(preferences.updated_at.fixed_offset()),
+        };
+        posting_preferences::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(posting_preferences::Column::UserId)
+                    .update_columns([
+                        posting_preferences::Column::DefaultVisibility,
+                        posting_preferences::Column::DefaultLanguage,
+                        posting_preferences::Column::DefaultSensitive,
+                        posting_preferences::Column::MarkMediaSensitive,
+                        posting_preferences::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
+
+/// helper function that convert a posting_preferences row into the domain model
+fn to_domain(model: posting_preferences::Model) -> Result<PostingPreferences, RepositoryError> {
+    Ok(PostingPreferences {
+        user_id: UserId::from(model.user_id),
+        default_visibility: Visibility::parse(&model.default_visibility).map_err(|_| {
+            RepositoryError::DatabaseError(format!(
+                "invalid visibility {}",
+                model.default_visibility
+            ))
+        })?,
+        default_language: model.default_language,
+        default_sensitive: model.default_sensitive,
+        mark_media_sensitive: model.mark_media_sensitive,
+        updated_at: model.updated_at.naive_utc().and_utc(),
+    })
+}
@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::refresh_tokens;
+use sea_orm::{ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter, sea_query::Expr};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{refresh_token::RefreshToken, user::UserId},
+        repositories::refresh_token_repository::RefreshTokenRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresRefreshTokenRepository {
+    db: DatabasePool,
+}
+
+impl PostgresRefreshTokenRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for PostgresRefreshTokenRepository {
+    #[instrument(skip_all, fields(user_id = %token.user_id), err)]
+    async fn create(&self, token: &RefreshToken) -> Result<(), RepositoryError> {
+        let model = refresh_tokens::ActiveModel {
+            id: Set(token.id),
+            user_id: Set(token.user_id.as_uuid()),
+            token_hash: Set(token.token_hash.clone()),
+            family_id: Set(token.family_id),
+            expires_at: Set(token.expires_at.fixed_offset()),
+            revoked_at: Set(token.revoked_at.map(|at| at.fixed_offset())),
+            created_at: Set(token.created_at.fixed_offset()),
+        };
+        refresh_tokens::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, err)]
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<RefreshToken>, RepositoryError> {
+        // a replica may lag behind the rotation, and miss a reuse
+        let model = refresh_tokens::Entity::find()
+            .filter(refresh_tokens::Column::TokenHash.eq(hash))
+            .one(self.db.writer())
+            .await
+            .map_err(db_error)?;
+
+        Ok(model.map(|model| RefreshToken {
+            id: model.id,
+            user_id: UserId::from(model.user_id),
+            token_hash: model.token_hash,
+            family_id: model.family_id,
+            expires_at: model.expires_at.naive_utc().and_utc(),
+            revoked_at: model.revoked_at.map(|at| at.naive_utc().and_utc()),
+            created_at: model.created_at.naive_utc().and_utc(),
+        }))
+    }
+
+    #[instrument(skip(self), err)]
+    async fn revoke(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        // only the first of concurrent exchanges finds the token still active
+        let result = refresh_tokens::Entity::update_many()
+            .col_expr(
+                refresh_tokens::Column::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(refresh_tokens::Column::Id.eq(id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(result.rows_affected > 0)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), RepositoryError> {
+        refresh_tokens::Entity::update_many()
+            .col_expr(
+                refresh_tokens::Column::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(refresh_tokens::Column::FamilyId.eq(family_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(user_id = %user_id), err)]
+    async fn revoke_all_for_user(&self, user_id: UserId) -> Result<(), RepositoryError> {
+        refresh_tokens::Entity::update_many()
+            .col_expr(
+                refresh_tokens::Column::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(refresh_tokens::Column::UserId.eq(user_id.as_uuid()))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+}
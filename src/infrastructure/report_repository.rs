@@ -0,0 +1,207 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use entity::{report_notes, report_rules, reports};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    TransactionTrait,
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            report::{Report, ReportCategory, ReportNote},
+            user::UserId,
+        },
+        repositories::report_repository::ReportRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresReportRepository {
+    db: DatabasePool,
+}
+
+impl PostgresReportRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ReportRepository for PostgresReportRepository {
+    #[instrument(skip_all, fields(report_id = %report.id), err)]
+    async fn create(&self, report: &Report) -> Result<(), RepositoryError> {
+        let model = reports::ActiveModel {
+            id: Set(report.id),
+            reporter_id: Set(report.reporter_id.as_uuid()),
+            target_account_id: Set(report.target_account_id.as_uuid()),
+            category: Set(report.category.as_str().to_string()),
+            comment: Set(report.comment.clone()),
+            assigned_account_id: Set(report.assigned_account_id.map(|id| id.as_uuid())),
+            action_taken_at: Set(report.action_taken_at.map(|at| at.fixed_offset())),
+            action_taken_by_id: Set(report.action_taken_by_id.map(|id| id.as_uuid())),
+            created_at: Set(report.created_at.fixed_offset()),
+            updated_at: Set(report.updated_at.fixed_offset()),
+        };
+
+        let txn = self.db.writer().begin().await.map_err(db_error)?;
+        reports::Entity::insert(model)
+            .exec(&txn)
+            .await
+            .map_err(db_error)?;
+        if !report.rule_ids.is_empty() {
+            let links = report.rule_ids.iter().map(|rule_id| report_rules::ActiveModel {
+                report_id: Set(report.id),
+                rule_id: Set(*rule_id),
+            });
+            report_rules::Entity::insert_many(links)
+                .exec(&txn)
+                .await
+                .map_err(db_error)?;
+        }
+        txn.commit().await.map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Report>, RepositoryError> {
+        let db = self.db.reader();
+        let Some(report) = reports::Entity::find_by_id(id)
+            .one(db)
+            .await
+            .map_err(db_error)?
+        else {
+            return Ok(None);
+        };
+
+        let mut rule_ids = rule_ids_of(db, &[id]).await?;
+        to_domain(report, rule_ids.remove(&id).unwrap_or_default()).map(Some)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list(&self, resolved: Option<bool>) -> Result<Vec<Report>, RepositoryError> {
+        let mut query = reports::Entity::find().order_by_desc(reports::Column::CreatedAt);
+        query = match resolved {
+            Some(true) => query.filter(reports::Column::ActionTakenAt.is_not_null()),
+            Some(false) => query.filter(reports::Column::ActionTakenAt.is_null()),
+            None => query,
+        };
+
+        let db = self.db.reader();
+        let reports = query.all(db).await.map_err(db_error)?;
+
+        let ids: Vec<Uuid> = reports.iter().map(|report| report.id).collect();
+        let mut rule_ids = rule_ids_of(db, &ids).await?;
+        reports
+            .into_iter()
+            .map(|report| {
+                let rules = rule_ids.remove(&report.id).unwrap_or_default();
+                to_domain(report, rules)
+            })
+            .collect()
+    }
+
+    #[instrument(skip_all, fields(report_id = %report.id), err)]
+    async fn update(&self, report: &Report) -> Result<(), RepositoryError> {
+        let model = reports::ActiveModel {
+            id: Set(report.id),
+            assigned_account_id: Set(report.assigned_account_id.map(|id| id.as_uuid())),
+            action_taken_at: Set(report.action_taken_at.map(|at| at.fixed_offset())),
+            action_taken_by_id: Set(report.action_taken_by_id.map(|id| id.as_uuid())),
+            updated_at: Set(report.updated_at.fixed_offset()),
+            ..Default::default()
+        };
+        reports::Entity::update(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => db_error(e),
+            })?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(report_id = %note.report_id), err)]
+    async fn add_note(&self, note: &ReportNote) -> Result<(), RepositoryError> {
+        let model = report_notes::ActiveModel {
+            id: Set(note.id),
+            report_id: Set(note.report_id),
+            author_id: Set(note.author_id.as_uuid()),
+            content: Set(note.content.clone()),
+            created_at: Set(note.created_at.fixed_offset()),
+        };
+        report_notes::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn notes(&self, report_id: Uuid) -> Result<Vec<ReportNote>, RepositoryError> {
+        let notes = report_notes::Entity::find()
+            .filter(report_notes::Column::ReportId.eq(report_id))
+            .order_by_asc(report_notes::Column::CreatedAt)
+            .all(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        Ok(notes
+            .into_iter()
+            .map(|note| ReportNote {
+                id: note.id,
+                report_id: note.report_id,
+                author_id: UserId::from(note.author_id),
+                content: note.content,
+                created_at: note.created_at.naive_utc().and_utc(),
+            })
+            .collect())
+    }
+}
+
+/// helper function that load the rules cited by each report in one query
+async fn rule_ids_of(
+    db: &impl ConnectionTrait,
+    report_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<Uuid>>, RepositoryError> {
+    if report_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let links = report_rules::Entity::find()
+        .filter(report_rules::Column::ReportId.is_in(report_ids.iter().copied()))
+        .all(db)
+        .await
+        .map_err(db_error)?;
+
+    let mut rule_ids: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for link in links {
+        rule_ids.entry(link.report_id).or_default().push(link.rule_id);
+    }
+    Ok(rule_ids)
+}
+
+/// helper function that convert a reports row into the domain model
+fn to_domain(model: reports::Model, rule_ids: Vec<Uuid>) -> Result<Report, RepositoryError> {
+    let category = ReportCategory::parse(&model.category)
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+    Ok(Report {
+        id: model.id,
+        reporter_id: UserId::from(model.reporter_id),
+        target_account_id: UserId::from(model.target_account_id),
+        category,
+        comment: model.comment,
+        rule_ids,
+        assigned_account_id: model.assigned_account_id.map(UserId::from),
+        action_taken_at: model.action_taken_at.map(|at| at.naive_utc().and_utc()),
+        action_taken_by_id: model.action_taken_by_id.map(UserId::from),
+        created_at: model.created_at.naive_utc().and_utc(),
+        updated_at: model.updated_at.naive_utc().and_utc(),
+    })
+}
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use entity::rules;
+use sea_orm::{ActiveValue::Set, ColumnTrait, DbErr, EntityTrait, QueryFilter, QueryOrder};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::RepositoryError, models::rule::Rule, repositories::rule_repository::RuleRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error},
+};
+
+#[derive(Clone)]
+pub struct PostgresRuleRepository {
+    db: DatabasePool,
+}
+
+impl PostgresRuleRepository {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl RuleRepository for PostgresRuleRepository {
+    #[instrument(skip(self), err)]
+    async fn list(&self) -> Result<Vec<Rule>, RepositoryError> {
+        let rules = rules::Entity::find()
+            .filter(rules::Column::DeletedAt.is_null())
+            .order_by_asc(rules::Column::Position)
+            .order_by_asc(rules::Column::CreatedAt)
+            .all(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        Ok(rules.into_iter().map(to_domain).collect())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Rule>, RepositoryError> {
+        let rule = rules::Entity::find_by_id(id)
+            .filter(rules::Column::DeletedAt.is_null())
+            .one(self.db.reader())
+            .await
+            .map_err(db_error)?;
+
+        Ok(rule.map(to_domain))
+    }
+
+    #[instrument(skip_all, fields(rule_id = %rule.id), err)]
+    async fn create(&self, rule: &Rule) -> Result<(), RepositoryError> {
+        let model = rules::ActiveModel {
+            id: Set(rule.id),
+            text: Set(rule.text.clone()),
+            hint: Set(rule.hint.clone()),
+            position: Set(rule.position),
+            created_at: Set(rule.created_at.fixed_offset()),
+            updated_at: Set(rule.updated_at.fixed_offset()),
+            deleted_at: Set(None),
+        };
+        rules::Entity::insert(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(rule_id = %rule.id), err)]
+    async fn update(&self, rule: &Rule) -> Result<(), RepositoryError> {
+        let model = rules::ActiveModel {
+            id: Set(rule.id),
+            text: Set(rule.text.clone()),
+            hint: Set(rule.hint.clone()),
+            position: Set(rule.position),
+            updated_at: Set(rule.updated_at.fixed_offset()),
+            ..Default::default()
+        };
+        rules::Entity::update(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => db_error(e),
+            })?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn delete(&self, id: Uuid) -> Result<(), RepositoryError> {
+        let model = rules::ActiveModel {
+            id: Set(id),
+            deleted_at: Set(Some(Utc::now().fixed_offset())),
+            ..Default::default()
+        };
+        rules::Entity::update(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => db_error(e),
+            })?;
+        Ok(())
+    }
+}
+
+/// helper function that convert a rules row into the domain model
+fn to_domain(model: rules::Model) -> Rule {
+    Rule {
+        id: model.id,
+        text: model.text,
+        hint: model.hint,
+        position: model.position,
+        created_at: model.created_at.naive_utc().and_utc(),
+        updated_at: model.updated_at.naive_utc().and_utc(),
+    }
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+
+use crate::domain::{
+    error::DomainError,
+    services::email_service::{EmailMessage, EmailSender},
+};
+
+#[derive(Clone)]
+pub struct SmtpEmailSender {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmailSender {
+    /// Connect to the relay with STARTTLS, authenticating when credentials are given
+    pub fn new(
+        host: &str,
+        port: u16,
+        credentials: Option<(String, String)>,
+        from: &str,
+    ) -> Result<Self, DomainError> {
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+            .map_err(|e| DomainError::EmailDelivery(e.to_string()))?
+            .port(port);
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        let from = from
+            .parse::<Mailbox>()
+            .map_err(|e| DomainError::EmailDelivery(e.to_string()))?;
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl EmailSender for SmtpEmailSender {
+    async fn send(&self, message: EmailMessage) -> Result<(), DomainError> {
+        let to = message
+            .to
+            .parse::<Mailbox>()
+            .map_err(|e| DomainError::EmailDelivery(e.to_string()))?;
+
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(message.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.body)
+            .map_err(|e| DomainError::EmailDelivery(e.to_string()))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| DomainError::EmailDelivery(e.to_string()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use crate::domain::services::spam_service::{RegistrationSubmission, SpamChecker, SpamVerdict};
+
+/// Object safe view of a checker, so heterogeneous heuristics fit in one pipeline
+/// Named apart from `SpamChecker::check_registration`, both traits are implemented by every checker
+trait Heuristic: Send + Sync {
+    fn verdict(&self, submission: &RegistrationSubmission) -> SpamVerdict;
+}
+
+impl<C: SpamChecker> Heuristic for C {
+    fn verdict(&self, submission: &RegistrationSubmission) -> SpamVerdict {
+        SpamChecker::check_registration(self, submission)
+    }
+}
+
+/// Runs every configured heuristic and keeps the strictest verdict
+#[derive(Clone, Default)]
+pub struct SpamPipeline {
+    checkers: Vec<Arc<dyn Heuristic>>,
+}
+
+impl SpamPipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with<C: SpamChecker + 'static>(mut self, checker: C) -> Self {
+        self.checkers.push(Arc::new(checker));
+        self
+    }
+}
+
+impl SpamChecker for SpamPipeline {
+    fn check_registration(&self, submission: &RegistrationSubmission) -> SpamVerdict {
+        self.checkers
+            .iter()
+            .map(|checker| checker.verdict(submission))
+            .max()
+            .unwrap_or(SpamVerdict::Clean)
+    }
+}
+
+/// Flags display names carrying links, a common trait of spam sign-ups
+#[derive(Clone, Default)]
+pub struct DisplayNameLinkChecker;
+
+impl SpamChecker for DisplayNameLinkChecker {
+    fn check_registration(&self, submission: &RegistrationSubmission) -> SpamVerdict {
+        let name = submission.display_name.to_ascii_lowercase();
+        match ["http://", "https://", "www."]
+            .iter()
+            .any(|marker| name.contains(marker))
+        {
+            true => SpamVerdict::Flag("link in display name".to_string()),
+            false => SpamVerdict::Clean,
+        }
+    }
+}
+
+/// Holds sign-ups whose email belongs to one of the given domains (or their subdomains)
+#[derive(Clone)]
+pub struct EmailDomainChecker {
+    domains: Vec<String>,
+}
+
+impl EmailDomainChecker {
+    pub fn new(domains: Vec<String>) -> Self {
+        Self {
+            domains: domains
+                .into_iter()
+                .map(|domain| domain.to_ascii_lowercase())
+                .collect(),
+        }
+    }
+}
+
+impl SpamChecker for EmailDomainChecker {
+    fn check_registration(&self, submission: &RegistrationSubmission) -> SpamVerdict {
+        let Some((_, domain)) = submission.email.rsplit_once('@') else {
+            return SpamVerdict::Clean;
+        };
+        let domain = domain.to_ascii_lowercase();
+
+        match self
+            .domains
+            .iter()
+            .find(|held| domain == **held || domain.ends_with(&format!(".{held}")))
+        {
+            Some(held) => SpamVerdict::Hold(format!("email domain {held}")),
+            None => SpamVerdict::Clean,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission<'a>(display_name: &'a str, email: &'a str) -> RegistrationSubmission<'a> {
+        RegistrationSubmission {
+            display_name,
+            email,
+        }
+    }
+
+    #[test]
+    fn test_pipeline_keeps_strictest_verdict() {
+        let pipeline = SpamPipeline::new()
+            .with(DisplayNameLinkChecker)
+            .with(EmailDomainChecker::new(vec!["spam.example".to_string()]));
+
+        let verdict =
+            pipeline.check_registration(&submission("buy at https://x", "a@mx.spam.example"));
+
+        assert_eq!(
+            SpamVerdict::Hold("email domain spam.example".to_string()),
+            verdict
+        );
+    }
+
+    #[test]
+    fn test_display_name_link_is_flagged() {
+        let verdict = DisplayNameLinkChecker
+            .check_registration(&submission("Visit WWW.example.com", "a@example.com"));
+
+        assert!(matches!(verdict, SpamVerdict::Flag(_)));
+    }
+
+    #[test]
+    fn test_clean_registration_passes() {
+        let pipeline = SpamPipeline::new()
+            .with(DisplayNameLinkChecker)
+            .with(EmailDomainChecker::new(vec!["spam.example".to_string()]));
+
+        let verdict = pipeline.check_registration(&submission("テスト", "a@notspam.example"));
+
+        assert_eq!(SpamVerdict::Clean, verdict);
+    }
+}
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        error::RepositoryError,
+        repositories::unit_of_work::{Transaction, UnitOfWork},
+    },
+    infrastructure::{
+        audit_log_repository::PostgresAuditLogRepository,
+        database::{DatabasePool, TransactionScope},
+        email_verification_repository::PostgresEmailVerificationRepository,
+        legal_document_repository::PostgresLegalDocumentRepository,
+        user_registration_repository::PostgresUserRegistrationRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct PostgresUnitOfWork {
+    db: DatabasePool,
+}
+
+impl PostgresUnitOfWork {
+    pub fn new(db: DatabasePool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    type Transaction = PostgresTransaction;
+
+    async fn begin(&self) -> Result<PostgresTransaction, RepositoryError> {
+        let scope = TransactionScope::begin(&self.db).await?;
+        Ok(PostgresTransaction {
+            registrations: PostgresUserRegistrationRepository::new(scope.clone()),
+            legal_documents: PostgresLegalDocumentRepository::new(scope.clone()),
+            audit_log: PostgresAuditLogRepository::new(scope.clone()),
+            email_verifications: PostgresEmailVerificationRepository::new(scope.clone()),
+            scope,
+        })
+    }
+}
+
+/// Postgres repositories bound to the same `TransactionScope`
+pub struct PostgresTransaction {
+    scope: TransactionScope,
+    registrations: PostgresUserRegistrationRepository<TransactionScope>,
+    legal_documents: PostgresLegalDocumentRepository<TransactionScope>,
+    audit_log: PostgresAuditLogRepository<TransactionScope>,
+    email_verifications: PostgresEmailVerificationRepository<TransactionScope>,
+}
+
+#[async_trait]
+impl Transaction for PostgresTransaction {
+    type Registrations = PostgresUserRegistrationRepository<TransactionScope>;
+    type LegalDocuments = PostgresLegalDocumentRepository<TransactionScope>;
+    type AuditLog = PostgresAuditLogRepository<TransactionScope>;
+    type EmailVerifications = PostgresEmailVerificationRepository<TransactionScope>;
+
+    fn registrations(&self) -> &Self::Registrations {
+        &self.registrations
+    }
+
+    fn legal_documents(&self) -> &Self::LegalDocuments {
+        &self.legal_documents
+    }
+
+    fn audit_log(&self) -> &Self::AuditLog {
+        &self.audit_log
+    }
+
+    fn email_verifications(&self) -> &Self::EmailVerifications {
+        &self.email_verifications
+    }
+
+    async fn commit(self) -> Result<(), RepositoryError> {
+        let Self {
+            scope,
+            registrations,
+            legal_documents,
+            audit_log,
+            email_verifications,
+        } = self;
+        // the repositories hold clones of the scope, which must be the last one to commit
+        drop((
+            registrations,
+            legal_documents,
+            audit_log,
+            email_verifications,
+        ));
+        scope.commit().await
+    }
+}
@@ -1,84 +1,120 @@
 use async_trait::async_trait;
-use sea_orm::{ActiveValue::Set, DatabaseConnection, EntityTrait, TransactionTrait};
+use sea_orm::{ActiveValue::Set, EntityTrait, TransactionTrait};
+use tracing::instrument;
 use uuid::Uuid;
 
-use crate::domain::{
-    error::RepositoryError,
-    models::{
-        credential::HashedPassword,
-        user::{ActivityId, User},
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            credential::HashedPassword,
+            email::EmailAddress,
+            moderation::Moderation,
+            profile_details::BirthdayVisibility,
+            user::{ActivityId, Role, User, UserId, normalize_username},
+        },
+        repositories::user_registration_repository::UserRegistrationRepository,
     },
-    repositories::user_registration_repository::UserRegistrationRepository,
+    infrastructure::database::{Connections, DatabasePool, db_error, unique_error},
 };
 use entity::{credentials, users};
 
+/// Runs on the pool by default, or on a `TransactionScope` inside a unit of work
 #[derive(Clone)]
-pub struct PostgresUserRegistrationRepository {
-    db: DatabaseConnection,
+pub struct PostgresUserRegistrationRepository<C: Connections = DatabasePool> {
+    db: C,
 }
 
-impl PostgresUserRegistrationRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+impl<C: Connections> PostgresUserRegistrationRepository<C> {
+    pub fn new(db: C) -> Self {
         Self { db }
     }
 }
 
 #[async_trait]
-impl UserRegistrationRepository for PostgresUserRegistrationRepository {
+impl<C: Connections> UserRegistrationRepository for PostgresUserRegistrationRepository<C> {
+    #[instrument(skip(self, password_hash, email), err)]
     async fn register_user_with_credentials(
         &self,
         activity_id: &ActivityId,
         display_name: &str,
+        role: Role,
+        disabled: bool,
         password_hash: HashedPassword,
-        email: String,
+        email: EmailAddress,
     ) -> Result<User, RepositoryError> {
         // Begin transaction
         let txn = self
             .db
+            .writer()
             .begin()
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(db_error)?;
 
-        let user_id = Uuid::new_v4();
+        let user_id = UserId::new(Uuid::new_v4());
+        let now = chrono::Utc::now();
 
         // Insert user
         let user_model = users::ActiveModel {
-            id: Set(user_id),
+            id: Set(user_id.as_uuid()),
             activity_id: Set(activity_id.as_str().to_string()),
+            username: Set(Some(normalize_username(activity_id.username()))),
             name: Set(display_name.to_string()),
             summary: Set(String::new()),
             icon: Set(None),
+            header: Set(None),
+            role: Set(role.as_str().to_string()),
+            disabled: Set(disabled),
+            silenced_at: Set(None),
+            suspended_at: Set(None),
+            created_at: Set(now.fixed_offset()),
+            updated_at: Set(now.fixed_offset()),
+            deleted_at: Set(None),
+            self_deleted: Set(false),
+            verified_at: Set(None),
+            theme_color: Set(None),
+            birthday: Set(None),
+            birthday_visibility: Set(BirthdayVisibility::default().as_str().to_string()),
+            location: Set(None),
+            location_visible: Set(false),
         };
 
+        // activity_id and username both derive from the username
         users::Entity::insert(user_model)
             .exec(&txn)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(|e| unique_error(e, "username"))?;
 
         // Insert credential
-        let now = chrono::Utc::now().fixed_offset();
         let credential_model = credentials::ActiveModel {
-            user_id: Set(user_id),
+            user_id: Set(user_id.as_uuid()),
             activity_id: Set(activity_id.as_str().to_string()),
             password_hash: Set(password_hash.as_str().to_string()),
-            email: Set(email),
-            created_at: Set(now),
-            updated_at: Set(now),
+            email: Set(email.as_str().to_string()),
+            created_at: Set(now.fixed_offset()),
+            updated_at: Set(now.fixed_offset()),
         };
 
+        // the user insert succeeded, so only the email can collide
         credentials::Entity::insert(credential_model)
             .exec(&txn)
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(|e| unique_error(e, "email"))?;
 
         // Commit transaction
         txn.commit()
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            .map_err(db_error)?;
 
         // Construct domain model
         let user = User::new(user_id, activity_id.clone(), display_name.to_string(), None)
-            .expect("Failed to create User from validated data");
+            .expect("Failed to create User from validated data")
+            .with_role(role)
+            .with_moderation(Moderation {
+                disabled,
+                ..Default::default()
+            })
+            .with_timestamps(now, now);
 
         Ok(user)
     }
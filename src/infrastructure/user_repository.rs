@@ -1,99 +1,226 @@
 use async_trait::async_trait;
-use sea_orm::{ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
-use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DbErr, EntityTrait, QueryFilter, sea_query::Expr,
+};
+use tracing::instrument;
 
-use crate::domain::{
-    error::RepositoryError,
-    models::user::{ActivityId, User},
-    repositories::user_repository::UserRepository,
+use crate::{
+    domain::{
+        error::RepositoryError,
+        models::{
+            moderation::Moderation,
+            profile_details::{BirthdayVisibility, ProfileDetails},
+            user::{ActivityId, Role, ThemeColor, User, UserId, normalize_username},
+        },
+        repositories::user_repository::UserRepository,
+    },
+    infrastructure::database::{DatabasePool, db_error},
 };
 use entity::users;
 
 #[derive(Clone)]
 pub struct PostgresUserRepository {
-    db: DatabaseConnection,
+    db: DatabasePool,
 }
 
 impl PostgresUserRepository {
-    pub fn new(db: DatabaseConnection) -> Self {
+    pub fn new(db: DatabasePool) -> Self {
         Self { db }
     }
 }
 
 #[async_trait]
 impl UserRepository for PostgresUserRepository {
+    #[instrument(skip(self), err)]
     async fn find_by_username(&self, username: &str) -> Result<Option<User>, RepositoryError> {
         let user = users::Entity::find()
-            .filter(users::Column::Name.eq(username))
-            .one(&self.db)
+            .filter(users::Column::Username.eq(normalize_username(username)))
+            .one(self.db.reader())
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-        match user {
-            Some(model) => {
-                let activity_id = ActivityId::new(model.activity_id)
-                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-                let icon_url = model.icon.as_ref().and_then(|icon| {
-                    icon.as_object()
-                        .and_then(|obj| obj.get("url"))
-                        .and_then(|url| url.as_str())
-                        .map(|s| s.to_string())
-                });
-
-                let user = User::new(model.id, activity_id, model.name, icon_url)
-                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-                Ok(Some(user))
-            }
-            None => Ok(None),
-        }
+            .map_err(db_error)?;
+
+        user.map(to_domain).transpose()
     }
 
-    async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, RepositoryError> {
-        let user = users::Entity::find_by_id(id)
-            .one(&self.db)
+    #[instrument(skip(self), err)]
+    async fn find_by_id(&self, id: UserId) -> Result<Option<User>, RepositoryError> {
+        let user = users::Entity::find_by_id(id.as_uuid())
+            .one(self.db.reader())
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-        match user {
-            Some(model) => {
-                let activity_id = ActivityId::new(model.activity_id)
-                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-                let icon_url = model.icon.as_ref().and_then(|icon| {
-                    icon.as_object()
-                        .and_then(|obj| obj.get("url"))
-                        .and_then(|url| url.as_str())
-                        .map(|s| s.to_string())
-                });
-
-                let user = User::new(model.id, activity_id, model.name, icon_url)
-                    .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-
-                Ok(Some(user))
-            }
-            None => Ok(None),
-        }
+            .map_err(db_error)?;
+
+        user.map(to_domain).transpose()
+    }
+
+    #[instrument(skip_all, fields(user_id = %user.id()), err)]
+    async fn update_moderation(&self, user: &User) -> Result<(), RepositoryError> {
+        let moderation = user.moderation();
+        let model = users::ActiveModel {
+            id: Set(user.id().as_uuid()),
+            disabled: Set(moderation.disabled),
+            silenced_at: Set(moderation.silenced_at.map(|at| at.fixed_offset())),
+            suspended_at: Set(moderation.suspended_at.map(|at| at.fixed_offset())),
+            ..Default::default()
+        };
+        users::Entity::update(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => db_error(e),
+            })?;
+        Ok(())
     }
 
-    async fn register_user(
+    #[instrument(skip_all, fields(user_id = %user.id()), err)]
+    async fn update_verification(&self, user: &User) -> Result<(), RepositoryError> {
+        let model = users::ActiveModel {
+            id: Set(user.id().as_uuid()),
+            verified_at: Set(user.verified_at().map(|at| at.fixed_offset())),
+            ..Default::default()
+        };
+        users::Entity::update(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => db_error(e),
+            })?;
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(user_id = %user.id()), err)]
+    async fn update_profile(
         &self,
-        activity_id: &ActivityId,
-        display_name: &str,
-    ) -> Result<Uuid, RepositoryError> {
-        let id = Uuid::new_v4();
-        let user_model = users::ActiveModel {
-            id: Set(id),
-            activity_id: Set(activity_id.as_str().to_string()),
-            name: Set(display_name.to_string()),
-            summary: Set(String::new()),
-            icon: Set(None),
+        user: &User,
+        expected_updated_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let details = user.details();
+        // compare and set in one statement, a concurrent edit leaves no row to update
+        let result = users::Entity::update_many()
+            .col_expr(users::Column::Name, Expr::value(user.display_name()))
+            .col_expr(users::Column::Summary, Expr::value(user.summary()))
+            .col_expr(
+                users::Column::ThemeColor,
+                Expr::value(user.theme_color().map(|color| color.as_str().to_string())),
+            )
+            .col_expr(users::Column::Birthday, Expr::value(details.birthday))
+            .col_expr(
+                users::Column::BirthdayVisibility,
+                Expr::value(details.birthday_visibility.as_str()),
+            )
+            .col_expr(users::Column::Location, Expr::value(details.location.clone()))
+            .col_expr(
+                users::Column::LocationVisible,
+                Expr::value(details.location_visible),
+            )
+            .col_expr(users::Column::UpdatedAt, Expr::value(user.updated_at().fixed_offset()))
+            .filter(users::Column::Id.eq(user.id().as_uuid()))
+            .filter(users::Column::UpdatedAt.eq(expected_updated_at.fixed_offset()))
+            .exec(self.db.writer())
+            .await
+            .map_err(db_error)?;
+        if result.rows_affected > 0 {
+            return Ok(());
+        }
+
+        let exists = users::Entity::find_by_id(user.id().as_uuid())
+            .one(self.db.writer())
+            .await
+            .map_err(db_error)?
+            .is_some();
+        Err(if exists {
+            RepositoryError::Outdated
+        } else {
+            RepositoryError::NotFound
+        })
+    }
+
+    #[instrument(skip_all, fields(user_id = %user.id()), err)]
+    async fn mark_deleted(&self, user: &User) -> Result<(), RepositoryError> {
+        let model = users::ActiveModel {
+            id: Set(user.id().as_uuid()),
+            deleted_at: Set(user.deleted_at().map(|at| at.fixed_offset())),
+            self_deleted: Set(user.self_deleted()),
+            ..Default::default()
         };
-        let insert_result = users::Entity::insert(user_model)
-            .exec(&self.db)
+        users::Entity::update(model)
+            .exec(self.db.writer())
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => RepositoryError::NotFound,
+                e => db_error(e),
+            })?;
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn purge_deleted(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        // credentials, preferences and acceptances go with the row (on delete cascade)
+        let result = users::Entity::delete_many()
+            .filter(users::Column::DeletedAt.lt(before.fixed_offset()))
+            .exec(self.db.writer())
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
-        Ok(insert_result.last_insert_id)
+            .map_err(db_error)?;
+        Ok(result.rows_affected)
     }
 }
+
+/// helper function that convert a users row into the domain model
+pub(crate) fn to_domain(model: users::Model) -> Result<User, RepositoryError> {
+    let activity_id = ActivityId::new(model.activity_id)
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+    let icon_url = model.icon.as_ref().and_then(image_url);
+    let header_url = model.header.as_ref().and_then(image_url);
+
+    let theme_color = model
+        .theme_color
+        .as_deref()
+        .map(ThemeColor::new)
+        .transpose()
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+    let details = ProfileDetails {
+        birthday: model.birthday,
+        birthday_visibility: BirthdayVisibility::parse(&model.birthday_visibility)
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?,
+        location: model.location,
+        location_visible: model.location_visible,
+    };
+
+    let role = Role::parse(&model.role).map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+    let moderation = Moderation {
+        disabled: model.disabled,
+        silenced_at: model.silenced_at.map(|at| at.naive_utc().and_utc()),
+        suspended_at: model.suspended_at.map(|at| at.naive_utc().and_utc()),
+    };
+
+    let user = User::new(UserId::from(model.id), activity_id, model.name, icon_url)
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .with_profile(model.summary, header_url)
+        .with_theme_color(theme_color)
+        .with_details(details)
+        .with_role(role)
+        .with_moderation(moderation)
+        .with_timestamps(
+            model.created_at.naive_utc().and_utc(),
+            model.updated_at.naive_utc().and_utc(),
+        )
+        .with_deleted_at(model.deleted_at.map(|at| at.naive_utc().and_utc()))
+        .with_self_deleted(model.self_deleted)
+        .with_verified_at(model.verified_at.map(|at| at.naive_utc().and_utc()));
+
+    Ok(user)
+}
+
+/// helper function that read the url of an image column (`{"url": ...}`)
+fn image_url(image: &serde_json::Value) -> Option<String> {
+    image
+        .as_object()
+        .and_then(|obj| obj.get("url"))
+        .and_then(|url| url.as_str())
+        .map(|s| s.to_string())
+}
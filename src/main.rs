@@ -1,66 +1,383 @@
+mod cli;
+mod config;
+mod doctor;
 mod domain;
 mod infrastructure;
 mod presentation;
+mod telemetry;
 mod usecase;
 
-use axum::{Router, routing::get};
-use sea_orm::{ConnectOptions, Database};
-use std::net::SocketAddr;
-use tokio::net::TcpListener;
+use axum::{Router, middleware, routing::get};
+use clap::Parser;
+use migration::{Migrator, MigratorTrait};
+use std::{net::SocketAddr, sync::Arc};
+use tokio::{net::TcpListener, signal};
+use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer};
+use tracing::Level;
 
 use crate::{
+    cli::{Cli, Command},
+    config::{Config, MailConfig},
+    domain::models::user::DELETION_GRACE_DAYS,
     infrastructure::{
+        account_alias_repository::PostgresAccountAliasRepository,
+        account_endorsement_repository::PostgresAccountEndorsementRepository,
         argon2_password_hasher::Argon2PasswordHasher,
+        audit_log_repository::PostgresAuditLogRepository,
+        blocklist_subscription_repository::PostgresBlocklistSubscriptionRepository,
         credential_repository::PostgresCredentialRepository,
+        database::{self, DatabasePool},
+        email_queue::EmailQueue,
+        email_verification_repository::PostgresEmailVerificationRepository,
+        federation_domain_repository::PostgresFederationDomainRepository,
+        hmac_url_signer::HmacUrlSigner,
+        http_blocklist_fetcher::HttpBlocklistFetcher,
+        http_client::{HttpClientSettings, ResilientHttpClient},
+        http_media_fetcher::HttpMediaFetcher,
+        ip_block_repository::PostgresIpBlockRepository,
         jwt_token_generator::JwtTokenGenerator,
-        user_registration_repository::PostgresUserRegistrationRepository,
+        legal_document_repository::PostgresLegalDocumentRepository,
+        log_email_sender::LogEmailSender,
+        notification_preferences_repository::PostgresNotificationPreferencesRepository,
+        posting_preferences_repository::PostgresPostingPreferencesRepository,
+        refresh_token_repository::PostgresRefreshTokenRepository,
+        report_repository::PostgresReportRepository,
+        rule_repository::PostgresRuleRepository,
+        smtp_email_sender::SmtpEmailSender,
+        spam_checker::{DisplayNameLinkChecker, EmailDomainChecker, SpamPipeline},
+        unit_of_work::PostgresUnitOfWork,
         user_repository::PostgresUserRepository,
     },
-    presentation::handlers::user_handler::create_user_router,
-    usecase::{login_usecase::LoginUsecase, register_user_usecase::RegisterUserUsecase},
+    presentation::{
+        handlers::{
+            account_handler::create_account_router,
+            admin_account_handler::create_admin_account_router,
+            alias_handler::create_alias_router,
+            audit_log_handler::create_audit_log_router,
+            blocklist_subscription_handler::create_blocklist_subscription_router,
+            email_verification_handler::create_email_verification_router,
+            endorsement_handler::create_endorsement_router,
+            federation_handler::create_federation_router,
+            ip_block_handler::create_ip_block_router, legal_handler::create_legal_router,
+            media_handler::create_media_router,
+            notification_preferences_handler::create_notification_preferences_router,
+            oauth_handler::create_oauth_router, problem_handler::create_problem_router,
+            report_handler::create_report_router, rule_handler::create_rule_router,
+            user_handler::create_user_router,
+        },
+        ip_block::{IpBlockGuard, enforce_ip_blocks},
+        load_shed::{LoadShedGuard, shed_load},
+    },
+    usecase::{
+        account_deletion_usecase::AccountDeletionUsecase,
+        alias_usecase::AliasUsecase, audit_log_usecase::AuditLogUsecase, auth_usecase::AuthUsecase,
+        blocklist_subscription_usecase::BlocklistSubscriptionUsecase,
+        email_verification_usecase::EmailVerificationUsecase,
+        endorsement_usecase::EndorsementUsecase,
+        federation_usecase::FederationUsecase,
+        ip_block_usecase::IpBlockUsecase, legal_usecase::LegalUsecase,
+        login_usecase::LoginUsecase,
+        media_proxy_usecase::MediaProxyUsecase, moderation_usecase::ModerationUsecase,
+        notification_preferences_usecase::NotificationPreferencesUsecase,
+        profile_usecase::ProfileUsecase,
+        register_user_usecase::RegisterUserUsecase, report_usecase::ReportUsecase,
+        rule_usecase::RuleUsecase,
+    },
 };
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenvy::from_path("../.env")?;
-    let mut opt = ConnectOptions::new(dotenvy::var("DATABASE_URL")?);
-    opt.max_connections(10)
-        .min_connections(1)
-        .sqlx_logging(true);
+    let args = Cli::parse();
+    let config = Config::from_env()?;
+    let telemetry = telemetry::init(&config)?;
 
-    let db = Database::connect(opt)
+    let db = database::connect_pool(&config.database_url, &config.replica_urls, &config.pool)
         .await
         .expect("Connection to DB failed");
-    let user_repository = PostgresUserRepository::new(db.clone());
-    let credential_repository = PostgresCredentialRepository::new(db.clone());
-    let registration_repository = PostgresUserRegistrationRepository::new(db.clone());
+
+    let result = match args.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve(config, db).await,
+        Command::Migrate { action } => cli::migrate(db.writer(), action).await.map_err(Into::into),
+        Command::CreateUser(create_user_args) => {
+            cli::create_user(&db, &config, create_user_args).await
+        }
+        Command::ResetPassword(reset_password_args) => {
+            cli::reset_password(&db, &config, reset_password_args).await
+        }
+        Command::ImportAccounts(import_accounts_args) => {
+            cli::import_accounts(&db, &config, import_accounts_args).await
+        }
+        Command::PurgeDeleted(purge_deleted_args) => {
+            cli::purge_deleted(&db, purge_deleted_args).await
+        }
+        Command::Seed(seed_args) => cli::seed(&db, &config, seed_args).await,
+        Command::RotateJwtSecret => {
+            cli::rotate_jwt_secret();
+            Ok(())
+        }
+        Command::IssueServiceToken(issue_service_token_args) => {
+            cli::issue_service_token(&db, &config.jwt_secret, issue_service_token_args).await
+        }
+        Command::Doctor => {
+            let checks = doctor::run(&config, &db, true).await;
+            doctor::report(&checks).map_err(Into::into)
+        }
+    };
+
+    telemetry.shutdown();
+    result
+}
+
+async fn serve(config: Config, db: DatabasePool) -> Result<(), Box<dyn std::error::Error>> {
+    if config.run_migrations {
+        Migrator::up(db.writer(), None).await?;
+    }
+
+    // fail fast with actionable messages instead of erroring on the first request
+    let checks = doctor::run(&config, &db, false).await;
+    doctor::report(&checks)?;
+
+    // login commonly follows registration immediately, read what was just written
+    let user_repository = PostgresUserRepository::new(db.primary_only());
+    let credential_repository = PostgresCredentialRepository::new(db.primary_only());
     let password_hasher = Argon2PasswordHasher::new();
-    let token_generator = JwtTokenGenerator::new("testtoken".to_string());
+    let token_generator = JwtTokenGenerator::new(config.jwt_secret.clone());
+    let (email_queue, email_worker) = match &config.mail {
+        MailConfig::Log => EmailQueue::spawn(LogEmailSender, config.load_shed.max_email_backlog),
+        MailConfig::Smtp {
+            host,
+            port,
+            credentials,
+            from,
+        } => EmailQueue::spawn(
+            SmtpEmailSender::new(host, *port, credentials.clone(), from)?,
+            config.load_shed.max_email_backlog,
+        ),
+    };
     let login_service = LoginUsecase::new(
         credential_repository.clone(),
         user_repository.clone(),
         password_hasher.clone(),
         token_generator.clone(),
+        email_queue.clone(),
+        PostgresRefreshTokenRepository::new(db.primary_only()),
+        PostgresEmailVerificationRepository::new(db.primary_only()),
     );
+    let audit_log_repository = PostgresAuditLogRepository::new(db.clone());
+    let mut spam_checker = SpamPipeline::new()
+        .with(EmailDomainChecker::new(config.spam_held_email_domains.clone()));
+    if config.spam_flag_display_name_links {
+        spam_checker = spam_checker.with(DisplayNameLinkChecker);
+    }
+    let legal_document_repository = PostgresLegalDocumentRepository::new(db.clone());
+    #[cfg(feature = "mx-check")]
+    let mail_domain_verifier =
+        infrastructure::mail_domain_verifier::MxMailDomainVerifier::from_system_conf()?;
+    #[cfg(not(feature = "mx-check"))]
+    let mail_domain_verifier = infrastructure::mail_domain_verifier::AnyMailDomain;
     let register_user_usecase = RegisterUserUsecase::new(
-        registration_repository,
+        PostgresUnitOfWork::new(db.clone()),
         password_hasher.clone(),
-        token_generator.clone(),
+        email_queue.clone(),
+        spam_checker,
+        legal_document_repository.clone(),
+        mail_domain_verifier,
+        config.require_instance_host()?.to_string(),
+    );
+    let rule_repository = PostgresRuleRepository::new(db.clone());
+    let auth_usecase = AuthUsecase::new(user_repository.clone(), token_generator.clone());
+    let report_usecase = ReportUsecase::new(
+        PostgresReportRepository::new(db.clone()),
+        user_repository.clone(),
+        audit_log_repository.clone(),
+        rule_repository.clone(),
+    );
+    let rule_usecase = RuleUsecase::new(rule_repository, audit_log_repository.clone());
+    let moderation_usecase =
+        ModerationUsecase::new(user_repository.clone(), audit_log_repository.clone());
+    // accounts past the grace period are purged daily, `purge-deleted` does the same on demand
+    let purge_job = ModerationUsecase::new(user_repository.clone(), audit_log_repository.clone());
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(24 * 60 * 60));
+        loop {
+            interval.tick().await;
+            if let Err(e) = purge_job
+                .purge_deleted(chrono::Duration::days(DELETION_GRACE_DAYS))
+                .await
+            {
+                tracing::warn!(error = %e, "failed to purge deleted accounts");
+            }
+        }
+    });
+    let account_deletion_usecase = AccountDeletionUsecase::new(
+        credential_repository.clone(),
+        user_repository.clone(),
+        password_hasher.clone(),
+    );
+    let profile_usecase = ProfileUsecase::new(
+        user_repository.clone(),
+        PostgresPostingPreferencesRepository::new(db.clone()),
     );
+    let alias_usecase = AliasUsecase::new(PostgresAccountAliasRepository::new(db.clone()));
+    let endorsement_usecase = EndorsementUsecase::new(
+        PostgresAccountEndorsementRepository::new(db.clone()),
+        user_repository.clone(),
+    );
+    let ip_block_usecase = Arc::new(IpBlockUsecase::new(
+        PostgresIpBlockRepository::new(db.clone()),
+        audit_log_repository.clone(),
+    ));
+    let federation_usecase = Arc::new(FederationUsecase::new(
+        config.federation_mode,
+        PostgresFederationDomainRepository::new(db.clone()),
+        audit_log_repository.clone(),
+    ));
+    let legal_usecase = LegalUsecase::new(legal_document_repository, audit_log_repository.clone());
+    let audit_log_usecase = AuditLogUsecase::new(audit_log_repository);
+    let email_verification_usecase =
+        EmailVerificationUsecase::new(PostgresEmailVerificationRepository::new(db.clone()));
+    let notification_preferences_usecase = NotificationPreferencesUsecase::new(
+        PostgresNotificationPreferencesRepository::new(db.clone()),
+    );
+    tracing::info!(user_agent = %config.user_agent, "outbound requests identify as");
+    let http_client = ResilientHttpClient::new(HttpClientSettings {
+        request_timeout: config.outbound.request_timeout,
+        deadline: config.outbound.deadline,
+        max_retries: config.outbound.max_retries,
+        max_redirects: config.outbound.max_redirects,
+        block_private_addresses: !config.allow_private_outbound,
+        user_agent: config.user_agent.clone(),
+        ..Default::default()
+    })?;
+    let media_proxy_usecase = MediaProxyUsecase::new(
+        HmacUrlSigner::new(config.media_proxy_secret.clone()),
+        HttpMediaFetcher::new(http_client.clone(), config.media_proxy_max_bytes),
+        federation_usecase.clone(),
+    );
+    let blocklist_subscription_usecase = Arc::new(BlocklistSubscriptionUsecase::new(
+        PostgresBlocklistSubscriptionRepository::new(db.clone()),
+        HttpBlocklistFetcher::new(http_client),
+        federation_usecase.clone(),
+        PostgresAuditLogRepository::new(db.clone()),
+    ));
+    // subscribed blocklists are synced every 6 hours, the sync endpoint does the same on demand
+    let sync_job = blocklist_subscription_usecase.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(6 * 60 * 60));
+        loop {
+            interval.tick().await;
+            if let Err(e) = sync_job.sync_all().await {
+                tracing::warn!(error = %e, "failed to sync blocklist subscriptions");
+            }
+        }
+    });
 
     let app = Router::new()
         .route("/", get(|| async { "Hello, Axum!!!" }))
         .nest(
             "/api",
-            create_user_router(login_service, register_user_usecase),
+            create_user_router(login_service, register_user_usecase)
+                .merge(create_email_verification_router(email_verification_usecase))
+                .merge(create_report_router(auth_usecase.clone(), report_usecase))
+                .merge(create_account_router(
+                    auth_usecase.clone(),
+                    profile_usecase,
+                    account_deletion_usecase,
+                ))
+                .merge(create_alias_router(auth_usecase.clone(), alias_usecase))
+                .merge(create_endorsement_router(
+                    auth_usecase.clone(),
+                    endorsement_usecase,
+                ))
+                .merge(create_admin_account_router(auth_usecase.clone(), moderation_usecase))
+                .merge(create_rule_router(auth_usecase.clone(), rule_usecase))
+                .merge(create_ip_block_router(auth_usecase.clone(), ip_block_usecase.clone()))
+                .merge(create_notification_preferences_router(
+                    auth_usecase.clone(),
+                    notification_preferences_usecase,
+                ))
+                .merge(create_federation_router(
+                    auth_usecase.clone(),
+                    federation_usecase,
+                ))
+                .merge(create_blocklist_subscription_router(
+                    auth_usecase.clone(),
+                    blocklist_subscription_usecase,
+                ))
+                .merge(create_legal_router(auth_usecase.clone(), legal_usecase))
+                .merge(create_audit_log_router(auth_usecase.clone(), audit_log_usecase)),
+        )
+        .merge(create_oauth_router(auth_usecase))
+        .merge(create_media_router(media_proxy_usecase))
+        .merge(create_problem_router())
+        .layer(middleware::from_fn_with_state(
+            IpBlockGuard {
+                ip_block_service: ip_block_usecase,
+                trust_proxy_headers: config.trust_proxy_headers,
+            },
+            enforce_ip_blocks::<PostgresIpBlockRepository, PostgresAuditLogRepository>,
+        ))
+        // outside the ip block middleware, a refused request never reaches the database
+        .layer(middleware::from_fn_with_state(
+            LoadShedGuard::new(
+                db.clone(),
+                email_queue.clone(),
+                config.load_shed.max_in_flight,
+                config.load_shed.max_in_flight_db_saturated,
+            ),
+            shed_load::<DatabasePool, EmailQueue>,
+        ))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
+                .on_response(DefaultOnResponse::new().level(Level::INFO)),
         );
 
     let addr = SocketAddr::from(([0, 0, 0, 0], 8080));
     let listener = TcpListener::bind(addr).await?;
-    axum::serve(listener, app.into_make_service()).await?;
+    tracing::info!(%addr, "listening");
+    // the peer address is needed by the ip block middleware
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+    tracing::info!("shutting down");
+
+    // the router (and every queue handle it held) is gone, deliver what is left
+    drop(email_queue);
+    email_worker.await?;
+
+    // every in-flight request has completed, release the pool
+    db.close().await?;
 
     Ok(())
 }
+
+/// Resolve when SIGINT or SIGTERM is received
+/// axum stops accepting connections and drains the in-flight ones after this completes
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c()
+            .await
+            .expect("Failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
 #[cfg(test)]
 mod tests {
     use axum::{
@@ -70,150 +387,75 @@ mod tests {
         response::Response,
     };
     use http_body_util::BodyExt;
-    use sea_orm::{ActiveModelTrait, ConnectOptions, Database, Set};
-    use tower::ServiceExt;
-    use uuid::Uuid;
+    use test_support::{TEST_ID, TestApp};
 
     use crate::{
-        domain::services::password_service::PasswordHasher,
+        domain::{
+            repositories::user_repository::UserRepository,
+            services::token_service::INTROSPECT_SCOPE,
+        },
         infrastructure::{
             argon2_password_hasher::Argon2PasswordHasher,
             credential_repository::PostgresCredentialRepository,
+            database::DatabasePool,
+            email_verification_repository::PostgresEmailVerificationRepository,
             jwt_token_generator::JwtTokenGenerator,
-            user_registration_repository::PostgresUserRegistrationRepository,
+            legal_document_repository::PostgresLegalDocumentRepository,
+            log_email_sender::LogEmailSender,
+            mail_domain_verifier::AnyMailDomain,
+            refresh_token_repository::PostgresRefreshTokenRepository,
+            spam_checker::SpamPipeline,
+            unit_of_work::PostgresUnitOfWork,
             user_repository::PostgresUserRepository,
         },
-        presentation::handlers::user_handler::{
-            LoginRequest, LoginResponse, RegisterRequest, create_user_router,
+        presentation::{
+            error::PROBLEM_CONTENT_TYPE,
+            handlers::{
+                oauth_handler::{IntrospectionResponse, create_oauth_router},
+                user_handler::{
+                    LoginRequest, LoginResponse, RegisterRequest, UserInfo, create_user_router,
+                },
+            },
+        },
+        usecase::{
+            auth_usecase::AuthUsecase, login_usecase::LoginUsecase,
+            register_user_usecase::RegisterUserUsecase,
         },
-        usecase::{login_usecase::LoginUsecase, register_user_usecase::RegisterUserUsecase},
     };
-    use entity::{credentials, users};
-
-    const TEST_ID: &str = "00000000-0000-0000-0000-000000000001";
-
-    async fn setup_test_db() -> (Router, sea_orm::DatabaseConnection, String) {
-        dotenvy::from_path("../.env").unwrap();
-
-        // Create unique schema for this test
-        let schema_name = format!("test_{}", Uuid::new_v4().to_string().replace('-', "_"));
-
-        // Connect to create schema
-        let mut opt = ConnectOptions::new(dotenvy::var("TEST_DATABASE_URL").unwrap());
-        opt.max_connections(10)
-            .min_connections(1)
-            .sqlx_logging(true);
 
-        let db_init = Database::connect(opt)
-            .await
-            .expect("Connection to DB failed");
-
-        use sea_orm::ConnectionTrait;
-        db_init.execute_unprepared(&format!("CREATE SCHEMA {}", schema_name))
-            .await
-            .expect("Failed to create schema");
-
-        // Reconnect with schema in search_path
-        let base_url = dotenvy::var("TEST_DATABASE_URL").unwrap();
-        let url_with_schema = if base_url.contains('?') {
-            format!("{}&options=-c%20search_path%3D{},public", base_url, schema_name)
-        } else {
-            format!("{}?options=-c%20search_path%3D{},public", base_url, schema_name)
-        };
-
-        let mut opt_with_schema = ConnectOptions::new(url_with_schema);
-        opt_with_schema
-            .max_connections(10)
-            .min_connections(1)
-            .sqlx_logging(true);
-
-        let db = Database::connect(opt_with_schema)
-            .await
-            .expect("Connection to DB failed");
-
-        // Create tables in the new schema
-        db.execute_unprepared(&format!(r#"
-            CREATE TABLE {}.users (
-                id UUID PRIMARY KEY,
-                activity_id VARCHAR NOT NULL UNIQUE,
-                name VARCHAR NOT NULL,
-                summary VARCHAR NOT NULL,
-                icon VARCHAR
-            )
-        "#, schema_name))
-            .await
-            .expect("Failed to create users table");
-
-        db.execute_unprepared(&format!(r#"
-            CREATE TABLE {}.credentials (
-                user_id UUID PRIMARY KEY REFERENCES {}.users(id) ON DELETE CASCADE,
-                activity_id VARCHAR NOT NULL UNIQUE,
-                password_hash VARCHAR NOT NULL,
-                email VARCHAR NOT NULL UNIQUE,
-                created_at TIMESTAMPTZ NOT NULL,
-                updated_at TIMESTAMPTZ NOT NULL
-            )
-        "#, schema_name, schema_name))
-            .await
-            .expect("Failed to create credentials table");
-
-        // Setup test data
-        let test_id = Uuid::parse_str(TEST_ID).unwrap();
-        let instance_host = dotenvy::var("INSTANCE_HOST").unwrap();
+    /// Router of the tests over the database of a `TestApp`: sync settings of main.app
+    fn test_router(db: sea_orm::DatabaseConnection) -> Router {
         let password_hasher = Argon2PasswordHasher::new();
-
-        // Create test user
-        let user = users::ActiveModel {
-            id: Set(test_id),
-            activity_id: Set(format!("https://{}/users/test_user", instance_host)),
-            name: Set("テスト".to_string()),
-            summary: Set("".to_string()),
-            icon: Set(None),
-        };
-        let _ = user.insert(&db).await;
-
-        // Create test credential with hashed password
-        let password_hash = password_hasher.hash("test_password").unwrap();
-        let credential = credentials::ActiveModel {
-            user_id: Set(test_id),
-            activity_id: Set(format!("https://{}/users/test_user", instance_host)),
-            password_hash: Set(password_hash.as_str().to_string()),
-            email: Set("test@example.com".to_string()),
-            created_at: Set(chrono::Utc::now().into()),
-            updated_at: Set(chrono::Utc::now().into()),
-        };
-        let _ = credential.insert(&db).await;
-
-        let user_repository = PostgresUserRepository::new(db.clone());
-        let credential_repository = PostgresCredentialRepository::new(db.clone());
-        let registration_repository = PostgresUserRegistrationRepository::new(db.clone());
+        let user_repository = PostgresUserRepository::new(db.clone().into());
+        let credential_repository = PostgresCredentialRepository::new(db.clone().into());
         let token_generator = JwtTokenGenerator::new("testtoken".to_string());
         let login_usecase = LoginUsecase::new(
             credential_repository.clone(),
             user_repository.clone(),
             password_hasher.clone(),
             token_generator.clone(),
+            LogEmailSender,
+            PostgresRefreshTokenRepository::new(db.clone().into()),
+            PostgresEmailVerificationRepository::<DatabasePool>::new(db.clone().into()),
         );
+        let auth_usecase = AuthUsecase::new(user_repository, token_generator);
+        let pool = DatabasePool::from(db);
         let register_user_usecase = RegisterUserUsecase::new(
-            registration_repository,
+            PostgresUnitOfWork::new(pool.clone()),
             password_hasher.clone(),
-            token_generator.clone(),
+            LogEmailSender,
+            SpamPipeline::new(),
+            PostgresLegalDocumentRepository::new(pool),
+            AnyMailDomain,
+            dotenvy::var("INSTANCE_HOST").unwrap(),
         );
 
-        // setup router: sync settings of main.app
-        let router = Router::new().nest(
-            "/api",
-            create_user_router(login_usecase, register_user_usecase),
-        );
-
-        (router, db, schema_name)
-    }
-
-    async fn cleanup_test_db(db: &sea_orm::DatabaseConnection, schema_name: &str) {
-        use sea_orm::ConnectionTrait;
-        db.execute_unprepared(&format!("DROP SCHEMA {} CASCADE", schema_name))
-            .await
-            .expect("Failed to drop schema");
+        Router::new()
+            .nest(
+                "/api",
+                create_user_router(login_usecase, register_user_usecase),
+            )
+            .merge(create_oauth_router(auth_usecase))
     }
 
     // Login usecase
@@ -222,9 +464,8 @@ mod tests {
     ///
     /// This function is general login handler
     /// Call this function from test case for login
-
-    async fn login(app: Router, body: String) -> Response {
-        app.oneshot(
+    async fn login(app: &TestApp, body: String) -> Response {
+        app.request(
             Request::builder()
                 .method("POST")
                 .uri("/api/login")
@@ -233,12 +474,11 @@ mod tests {
                 .unwrap(),
         )
         .await
-        .unwrap()
     }
 
     #[tokio::test]
     async fn test_login_positive() {
-        let (app, db, schema_name) = setup_test_db().await;
+        let app = TestApp::spawn(test_router).await;
 
         // create request body
         let user_id = "test_user".to_string();
@@ -250,7 +490,7 @@ mod tests {
         let body = serde_json::to_string(&login_request).unwrap();
 
         // send request
-        let response = login(app, body).await;
+        let response = login(&app, body).await;
 
         // validation
         assert_eq!(response.status(), StatusCode::OK);
@@ -259,13 +499,14 @@ mod tests {
         let login_response: LoginResponse = serde_json::from_slice(&bytes).unwrap();
         assert_eq!(TEST_ID, login_response.user.id);
         assert_eq!(user_id, login_response.user.acct);
-
-        cleanup_test_db(&db, &schema_name).await;
+        assert_eq!("Bearer", login_response.token_type);
+        assert!(login_response.expires_in > 0);
+        assert!(!login_response.refresh_token.is_empty());
     }
 
     #[tokio::test]
     async fn test_login_invalid_user_negative() {
-        let (app, db, schema_name) = setup_test_db().await;
+        let app = TestApp::spawn(test_router).await;
 
         // create request body
         let user_id = "invalid_user".to_string();
@@ -277,17 +518,15 @@ mod tests {
         let body = serde_json::to_string(&login_request).unwrap();
 
         // send request
-        let response = login(app, body).await;
+        let response = login(&app, body).await;
 
         // validation
         assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
-
-        cleanup_test_db(&db, &schema_name).await;
     }
 
     #[tokio::test]
     async fn test_login_invalid_password_negative() {
-        let (app, db, schema_name) = setup_test_db().await;
+        let app = TestApp::spawn(test_router).await;
 
         // create request body
         let user_id = "test_user".to_string();
@@ -299,12 +538,10 @@ mod tests {
         let body = serde_json::to_string(&login_request).unwrap();
 
         // send request
-        let response = login(app, body).await;
+        let response = login(&app, body).await;
 
         // validation
         assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
-
-        cleanup_test_db(&db, &schema_name).await;
     }
 
     // Register usecase
@@ -313,8 +550,8 @@ mod tests {
     ///
     /// This function is general register handler
     /// Call this function from test case for register
-    async fn register(app: Router, body: String) -> Response {
-        app.oneshot(
+    async fn register(app: &TestApp, body: String) -> Response {
+        app.request(
             Request::builder()
                 .method("POST")
                 .uri("/api/register")
@@ -323,12 +560,11 @@ mod tests {
                 .unwrap(),
         )
         .await
-        .unwrap()
     }
 
     #[tokio::test]
     async fn test_register_positive() {
-        let (app, db, schema_name) = setup_test_db().await;
+        let app = TestApp::spawn(test_router).await;
 
         // create request body
         let new_user_id = "new_user";
@@ -340,36 +576,35 @@ mod tests {
             password: new_password.to_string(),
             mail_address: new_mail_adress.to_string(),
             display_name: new_display_name.to_string(),
+            agreement: true,
         };
         let body = serde_json::to_string(&register_request).unwrap();
 
         // send request
-        let response = register(app, body).await;
+        let response = register(&app, body).await;
 
         // validation
         let status = response.status();
-        if status != StatusCode::CREATED {
+        if status != StatusCode::ACCEPTED {
             let body = response.into_body();
             let bytes = body.collect().await.unwrap().to_bytes();
             let error_msg = String::from_utf8(bytes.to_vec()).unwrap();
             panic!(
-                "Expected CREATED but got {:?}. Error: {}",
+                "Expected ACCEPTED but got {:?}. Error: {}",
                 status, error_msg
             );
         }
-        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(status, StatusCode::ACCEPTED);
         let body = response.into_body();
         let bytes = body.collect().await.unwrap().to_bytes();
-        let login_response: LoginResponse = serde_json::from_slice(&bytes).unwrap();
-        assert_eq!(new_user_id, login_response.user.acct);
-        assert_eq!("テスト", login_response.user.display_name);
-
-        cleanup_test_db(&db, &schema_name).await;
+        let user_info: UserInfo = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(new_user_id, user_info.acct);
+        assert_eq!("テスト", user_info.display_name);
     }
 
     #[tokio::test]
     async fn test_register_duplicated_user_negative() {
-        let (app, db, schema_name) = setup_test_db().await;
+        let app = TestApp::spawn(test_router).await;
 
         // create request body
         let new_user_id = "test_user";
@@ -381,19 +616,27 @@ mod tests {
             password: new_password.to_string(),
             mail_address: new_mail_adress.to_string(),
             display_name: new_display_name.to_string(),
+            agreement: true,
         };
         let body = serde_json::to_string(&register_request).unwrap();
 
         // send request
-        let response = register(app, body).await;
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-
-        cleanup_test_db(&db, &schema_name).await;
+        let response = register(&app, body).await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            PROBLEM_CONTENT_TYPE,
+            response.headers()[header::CONTENT_TYPE]
+        );
+        let body = response.into_body();
+        let bytes = body.collect().await.unwrap().to_bytes();
+        let problem: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!("/problems/conflict", problem["type"]);
+        assert_eq!("username taken", problem["detail"]);
     }
 
     #[tokio::test]
     async fn test_register_duplicated_email_negative() {
-        let (app, db, schema_name) = setup_test_db().await;
+        let app = TestApp::spawn(test_router).await;
 
         // create request body
         let new_user_id = "new_user";
@@ -405,15 +648,93 @@ mod tests {
             password: new_password.to_string(),
             mail_address: new_mail_adress.to_string(),
             display_name: new_display_name.to_string(),
+            agreement: true,
         };
         let body = serde_json::to_string(&register_request).unwrap();
 
         // send request
-        let response = register(app, body).await;
+        let response = register(&app, body).await;
 
         // validation
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        assert_eq!(
+            PROBLEM_CONTENT_TYPE,
+            response.headers()[header::CONTENT_TYPE]
+        );
+        let body = response.into_body();
+        let bytes = body.collect().await.unwrap().to_bytes();
+        let problem: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!("/problems/conflict", problem["type"]);
+        assert_eq!("email taken", problem["detail"]);
+    }
+
+    // OAuth
+
+    /// # Description
+    ///
+    /// Introspect `token` with `bearer` as the credential of the caller
+    async fn introspect(app: &TestApp, bearer: &str, token: &str) -> Response {
+        app.request(
+            Request::builder()
+                .method("POST")
+                .uri("/oauth/introspect")
+                .header(header::AUTHORIZATION, format!("Bearer {}", bearer))
+                .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(Body::from(format!("token={}", token)))
+                .unwrap(),
+        )
+        .await
+    }
+
+    /// # Description
+    ///
+    /// Access token of the seeded account, as issued at login
+    async fn login_token(app: &TestApp) -> String {
+        let login_request = LoginRequest {
+            user_id: "test_user".to_string(),
+            password: "test_password".to_string(),
+        };
+        let response = login(app, serde_json::to_string(&login_request).unwrap()).await;
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let login_response: LoginResponse = serde_json::from_slice(&bytes).unwrap();
+        login_response.token
+    }
+
+    #[tokio::test]
+    async fn test_introspect_service_token_positive() {
+        let app = TestApp::spawn(test_router).await;
+        let token = login_token(&app).await;
+
+        // service credential of the seeded account
+        let user = PostgresUserRepository::new(app.db.clone().into())
+            .find_by_username("test_user")
+            .await
+            .unwrap()
+            .unwrap();
+        let service_token = JwtTokenGenerator::new("testtoken".to_string())
+            .generate_with_scopes(&user, &[INTROSPECT_SCOPE])
+            .unwrap();
+
+        let response = introspect(&app, &service_token.value, &token).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let introspection: IntrospectionResponse = serde_json::from_slice(&bytes).unwrap();
+        assert!(introspection.active);
+        assert_eq!(Some("test_user".to_string()), introspection.username);
+    }
+
+    #[tokio::test]
+    async fn test_introspect_user_token_negative() {
+        let app = TestApp::spawn(test_router).await;
+        let token = login_token(&app).await;
+
+        // an account may not probe tokens, even its own
+        let response = introspect(&app, &token, &token).await;
 
-        cleanup_test_db(&db, &schema_name).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let problem: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!("/problems/forbidden", problem["type"]);
     }
 }
@@ -0,0 +1,189 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{HeaderMap, header, request::Parts},
+    response::Response,
+};
+
+use crate::{
+    domain::{
+        error::DomainError, models::user::User, repositories::user_repository::UserRepository,
+        services::token_service::TokenVerifier,
+    },
+    presentation::error::error_response,
+    usecase::auth_usecase::AuthUsecase,
+};
+
+/// State of a router whose handlers take an `AuthenticatedUser`
+pub trait AuthState: Send + Sync {
+    type Users: UserRepository + Send + Sync;
+    type Verifier: TokenVerifier;
+
+    fn auth_service(&self) -> &AuthUsecase<Self::Users, Self::Verifier>;
+}
+
+/// Caller resolved from the `Authorization: Bearer` header
+/// A missing, expired or forged token is answered before the handler runs
+/// Service credentials lack the login scopes and are refused with 403
+pub struct AuthenticatedUser(pub User);
+
+impl<S: AuthState> FromRequestParts<S> for AuthenticatedUser {
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        authenticate(state.auth_service(), &parts.headers)
+            .await
+            .map(Self)
+            .map_err(error_response)
+    }
+}
+
+/// helper function that extract the token of an `Authorization: Bearer` header
+pub fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+/// helper function that resolve the caller from the bearer token
+pub async fn authenticate<U: UserRepository + Send + Sync, V: TokenVerifier>(
+    auth_service: &AuthUsecase<U, V>,
+    headers: &HeaderMap,
+) -> Result<User, DomainError> {
+    let token = bearer_token(headers).ok_or(DomainError::AuthenticationFailed)?;
+    auth_service.authenticate(token).await
+}
+
+/// helper function that resolve the caller, refusing a bearer token not granted `scope`
+pub async fn authorize<U: UserRepository + Send + Sync, V: TokenVerifier>(
+    auth_service: &AuthUsecase<U, V>,
+    headers: &HeaderMap,
+    scope: &str,
+) -> Result<User, DomainError> {
+    let token = bearer_token(headers).ok_or(DomainError::AuthenticationFailed)?;
+    auth_service.authorize(token, scope).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, StatusCode},
+        routing::get,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        domain::{
+            models::fixtures::UserBuilder,
+            services::token_service::{INTROSPECT_SCOPE, TokenGenerator},
+        },
+        infrastructure::{
+            in_memory::user_repository::InMemoryUserRepository,
+            jwt_token_generator::JwtTokenGenerator,
+        },
+    };
+
+    #[derive(Clone)]
+    struct TestState {
+        auth_service: AuthUsecase<InMemoryUserRepository, JwtTokenGenerator>,
+    }
+
+    impl AuthState for TestState {
+        type Users = InMemoryUserRepository;
+        type Verifier = JwtTokenGenerator;
+
+        fn auth_service(&self) -> &AuthUsecase<InMemoryUserRepository, JwtTokenGenerator> {
+            &self.auth_service
+        }
+    }
+
+    /// # Description
+    /// Router answering the id of the caller, over a repository holding `user`
+    fn setup(user: &User) -> Router {
+        let user_repository = InMemoryUserRepository::new();
+        user_repository.insert(user.clone()).unwrap();
+        let state = TestState {
+            auth_service: AuthUsecase::new(
+                user_repository,
+                JwtTokenGenerator::new("secret".to_string()),
+            ),
+        };
+        Router::new()
+            .route(
+                "/",
+                get(|AuthenticatedUser(caller): AuthenticatedUser| async move {
+                    caller.id().to_string()
+                }),
+            )
+            .with_state(state)
+    }
+
+    /// # Description
+    /// GET `/`, with `token` as the bearer token when given
+    async fn call(router: Router, token: Option<&str>) -> Response {
+        let mut request = Request::builder().uri("/");
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        router
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_user_positive() {
+        let user = UserBuilder::default().build();
+        let token = JwtTokenGenerator::new("secret".to_string())
+            .generate(&user)
+            .unwrap();
+
+        let response = call(setup(&user), Some(&token.value)).await;
+
+        assert_eq!(StatusCode::OK, response.status());
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(user.id().to_string().as_bytes(), &bytes[..]);
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_user_missing_token_negative() {
+        let user = UserBuilder::default().build();
+
+        let response = call(setup(&user), None).await;
+
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_user_service_token_negative() {
+        let user = UserBuilder::default().build();
+        // service credential, only allowed to introspect
+        let token = JwtTokenGenerator::new("secret".to_string())
+            .generate_with_scopes(&user, &[INTROSPECT_SCOPE])
+            .unwrap();
+
+        let response = call(setup(&user), Some(&token.value)).await;
+
+        assert_eq!(StatusCode::FORBIDDEN, response.status());
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_user_expired_token_negative() {
+        let user = UserBuilder::default().build();
+        // expired an hour ago, past the leeway of the verifier
+        let token = JwtTokenGenerator::with_expiration("secret".to_string(), -1)
+            .generate(&user)
+            .unwrap();
+
+        let response = call(setup(&user), Some(&token.value)).await;
+
+        assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+    }
+}
@@ -0,0 +1,310 @@
+use axum::{
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value, json};
+
+use crate::domain::error::{DomainError, RepositoryError};
+
+/// Media type of the error bodies (RFC 7807)
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Error categories, each one is documented at its `type` URI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemType {
+    AuthenticationFailed,
+    AccountDisabled,
+    EmailNotVerified,
+    PendingDeletion,
+    Forbidden,
+    DomainNotFederated,
+    NotFound,
+    Gone,
+    ValidationFailed,
+    Conflict,
+    EditConflict,
+    OutdatedAgreement,
+    MediaTooLarge,
+    UnsupportedMediaType,
+    RemoteFetchFailed,
+    ServiceUnavailable,
+    Overloaded,
+    InternalError,
+}
+
+impl ProblemType {
+    pub const ALL: [ProblemType; 18] = [
+        ProblemType::AuthenticationFailed,
+        ProblemType::AccountDisabled,
+        ProblemType::EmailNotVerified,
+        ProblemType::PendingDeletion,
+        ProblemType::Forbidden,
+        ProblemType::DomainNotFederated,
+        ProblemType::NotFound,
+        ProblemType::Gone,
+        ProblemType::ValidationFailed,
+        ProblemType::Conflict,
+        ProblemType::EditConflict,
+        ProblemType::OutdatedAgreement,
+        ProblemType::MediaTooLarge,
+        ProblemType::UnsupportedMediaType,
+        ProblemType::RemoteFetchFailed,
+        ProblemType::ServiceUnavailable,
+        ProblemType::Overloaded,
+        ProblemType::InternalError,
+    ];
+
+    pub fn slug(self) -> &'static str {
+        match self {
+            ProblemType::AuthenticationFailed => "authentication-failed",
+            ProblemType::AccountDisabled => "account-disabled",
+            ProblemType::EmailNotVerified => "email-not-verified",
+            ProblemType::PendingDeletion => "pending-deletion",
+            ProblemType::Forbidden => "forbidden",
+            ProblemType::DomainNotFederated => "domain-not-federated",
+            ProblemType::NotFound => "not-found",
+            ProblemType::Gone => "gone",
+            ProblemType::ValidationFailed => "validation-failed",
+            ProblemType::Conflict => "conflict",
+            ProblemType::EditConflict => "edit-conflict",
+            ProblemType::OutdatedAgreement => "outdated-agreement",
+            ProblemType::MediaTooLarge => "media-too-large",
+            ProblemType::UnsupportedMediaType => "unsupported-media-type",
+            ProblemType::RemoteFetchFailed => "remote-fetch-failed",
+            ProblemType::ServiceUnavailable => "service-unavailable",
+            ProblemType::Overloaded => "overloaded",
+            ProblemType::InternalError => "internal-error",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.slug() == slug)
+    }
+
+    /// `type` member of the problem, relative to the server root
+    pub fn uri(self) -> String {
+        format!("/problems/{}", self.slug())
+    }
+
+    pub fn status(self) -> StatusCode {
+        match self {
+            ProblemType::AuthenticationFailed => StatusCode::UNAUTHORIZED,
+            ProblemType::AccountDisabled
+            | ProblemType::EmailNotVerified
+            | ProblemType::PendingDeletion
+            | ProblemType::Forbidden
+            | ProblemType::DomainNotFederated => StatusCode::FORBIDDEN,
+            ProblemType::NotFound => StatusCode::NOT_FOUND,
+            ProblemType::Gone => StatusCode::GONE,
+            ProblemType::ValidationFailed => StatusCode::UNPROCESSABLE_ENTITY,
+            ProblemType::Conflict | ProblemType::EditConflict | ProblemType::OutdatedAgreement => {
+                StatusCode::CONFLICT
+            }
+            ProblemType::MediaTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            ProblemType::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ProblemType::RemoteFetchFailed => StatusCode::BAD_GATEWAY,
+            ProblemType::ServiceUnavailable | ProblemType::Overloaded => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+            ProblemType::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            ProblemType::AuthenticationFailed => "Authentication failed",
+            ProblemType::AccountDisabled => "Your login is currently disabled",
+            ProblemType::EmailNotVerified => "Your email address is not verified",
+            ProblemType::PendingDeletion => "Your account is pending deletion",
+            ProblemType::Forbidden => "Forbidden",
+            ProblemType::DomainNotFederated => "Domain not federated",
+            ProblemType::NotFound => "Not found",
+            ProblemType::Gone => "Gone",
+            ProblemType::ValidationFailed => "Validation failed",
+            ProblemType::Conflict => "Already taken",
+            ProblemType::EditConflict => "Edit conflict",
+            ProblemType::OutdatedAgreement => "Outdated agreement",
+            ProblemType::MediaTooLarge => "Media too large",
+            ProblemType::UnsupportedMediaType => "Unsupported media type",
+            ProblemType::RemoteFetchFailed => "Remote fetch failed",
+            ProblemType::ServiceUnavailable => "Service unavailable",
+            ProblemType::Overloaded => "Server overloaded",
+            ProblemType::InternalError => "Internal server error",
+        }
+    }
+
+    /// What the category means and what the client can do about it
+    pub fn description(self) -> &'static str {
+        match self {
+            ProblemType::AuthenticationFailed => {
+                "The credentials or the access token are missing, wrong or expired."
+            }
+            ProblemType::AccountDisabled => "A moderator disabled the login of this account.",
+            ProblemType::EmailNotVerified => {
+                "Open the link sent to the address given at sign-up to activate the account."
+            }
+            ProblemType::PendingDeletion => {
+                "The account was deleted by its owner and can be reactivated until `purge_at`, \
+                 by sending the credentials to `reactivate`."
+            }
+            ProblemType::Forbidden => "The account is not allowed to perform this action.",
+            ProblemType::DomainNotFederated => {
+                "The server does not federate with the remote domain."
+            }
+            ProblemType::NotFound => "The resource does not exist or is not visible.",
+            ProblemType::Gone => {
+                "The resource was deleted, `tombstone` describes what it used to be."
+            }
+            ProblemType::ValidationFailed => {
+                "The request is well-formed but a value is invalid, `detail` tells which one."
+            }
+            ProblemType::Conflict => "A unique value, named by `field`, is already in use.",
+            ProblemType::EditConflict => {
+                "The record was modified by another request, reload it and retry."
+            }
+            ProblemType::OutdatedAgreement => {
+                "A newer version of the document has been published, accept it instead."
+            }
+            ProblemType::MediaTooLarge => "The remote media exceeds the size limit.",
+            ProblemType::UnsupportedMediaType => "The remote media is of a type not served.",
+            ProblemType::RemoteFetchFailed => "The remote server could not be reached.",
+            ProblemType::ServiceUnavailable => "The database is unreachable, retry later.",
+            ProblemType::Overloaded => {
+                "The server is too busy to take the request, retry after `Retry-After` seconds."
+            }
+            ProblemType::InternalError => "An unexpected error, it has been logged.",
+        }
+    }
+}
+
+/// Error body in the `application/problem+json` format
+#[derive(Debug)]
+pub struct Problem {
+    kind: ProblemType,
+    detail: Option<String>,
+    extensions: Map<String, Value>,
+}
+
+impl Problem {
+    pub fn new(kind: ProblemType) -> Self {
+        Self {
+            kind,
+            detail: None,
+            extensions: Map::new(),
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Additional member, next to the standard ones
+    pub fn with_extension(mut self, key: &str, value: Value) -> Self {
+        self.extensions.insert(key.to_string(), value);
+        self
+    }
+}
+
+/// The single mapping of usecase errors to HTTP responses
+impl From<DomainError> for Problem {
+    fn from(error: DomainError) -> Self {
+        match error {
+            DomainError::AuthenticationFailed => Problem::new(ProblemType::AuthenticationFailed),
+            DomainError::AccountDisabled => Problem::new(ProblemType::AccountDisabled),
+            DomainError::EmailNotVerified => Problem::new(ProblemType::EmailNotVerified),
+            DomainError::PendingDeletion(purge_at) => pending_deletion_problem(purge_at),
+            DomainError::Forbidden => Problem::new(ProblemType::Forbidden),
+            DomainError::DomainNotFederated(_) => Problem::new(ProblemType::DomainNotFederated),
+            // an invalid media proxy signature is answered as if the URL did not exist
+            DomainError::InvalidSignature | DomainError::Repository(RepositoryError::NotFound) => {
+                Problem::new(ProblemType::NotFound)
+            }
+            DomainError::Gone(tombstone) => Problem::new(ProblemType::Gone).with_extension(
+                "tombstone",
+                json!({
+                    "type": "Tombstone",
+                    "id": tombstone.id,
+                    "formerType": tombstone.former_type,
+                    "deleted": tombstone.deleted_at,
+                }),
+            ),
+            e @ (DomainError::WeakPassword
+            | DomainError::EmptyDisplayName
+            | DomainError::InvalidThemeColor
+            | DomainError::InvalidProfileDetails
+            | DomainError::InvalidEmail
+            | DomainError::InvalidActivityId
+            | DomainError::InvalidUsername
+            | DomainError::InvalidRole
+            | DomainError::InvalidAlias
+            | DomainError::InvalidEndorsement
+            | DomainError::InvalidReport
+            | DomainError::InvalidRule
+            | DomainError::InvalidIpBlock
+            | DomainError::InvalidFederationDomain
+            | DomainError::InvalidBlocklistUrl
+            | DomainError::InvalidNotificationPreferences
+            | DomainError::InvalidPostingPreferences
+            | DomainError::InvalidLegalDocument
+            | DomainError::AgreementRequired) => {
+                Problem::new(ProblemType::ValidationFailed).with_detail(e.to_string())
+            }
+            DomainError::Repository(RepositoryError::Conflict { field }) => {
+                Problem::new(ProblemType::Conflict)
+                    .with_detail(format!("{field} taken"))
+                    .with_extension("field", json!(field))
+            }
+            DomainError::Repository(RepositoryError::Outdated) => {
+                Problem::new(ProblemType::EditConflict)
+            }
+            DomainError::OutdatedAgreement => Problem::new(ProblemType::OutdatedAgreement),
+            DomainError::MediaTooLarge => Problem::new(ProblemType::MediaTooLarge),
+            DomainError::UnsupportedMediaType => Problem::new(ProblemType::UnsupportedMediaType),
+            DomainError::RemoteFetch(e) => {
+                tracing::info!(error = %e, "remote fetch failed");
+                Problem::new(ProblemType::RemoteFetchFailed)
+            }
+            DomainError::Repository(RepositoryError::Unavailable) => {
+                Problem::new(ProblemType::ServiceUnavailable)
+            }
+            e => {
+                tracing::error!(error = %e, "request failed");
+                Problem::new(ProblemType::InternalError)
+            }
+        }
+    }
+}
+
+impl IntoResponse for Problem {
+    fn into_response(self) -> Response {
+        let mut body = Map::new();
+        body.insert("type".to_string(), json!(self.kind.uri()));
+        body.insert("title".to_string(), json!(self.kind.title()));
+        body.insert("status".to_string(), json!(self.kind.status().as_u16()));
+        if let Some(detail) = self.detail {
+            body.insert("detail".to_string(), json!(detail));
+        }
+        body.extend(self.extensions);
+
+        (
+            self.kind.status(),
+            [(header::CONTENT_TYPE, PROBLEM_CONTENT_TYPE)],
+            Value::Object(body).to_string(),
+        )
+            .into_response()
+    }
+}
+
+/// helper function that map usecase errors to HTTP responses
+pub fn error_response(error: DomainError) -> Response {
+    Problem::from(error).into_response()
+}
+
+/// helper function that answer a login to an account deleted by its owner, with how to undo it
+fn pending_deletion_problem(purge_at: DateTime<Utc>) -> Problem {
+    Problem::new(ProblemType::PendingDeletion)
+        .with_extension("purge_at", json!(purge_at))
+        .with_extension("reactivate", json!("POST /api/v1/accounts/reactivate"))
+}
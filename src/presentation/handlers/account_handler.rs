@@ -0,0 +1,408 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, patch, post},
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        models::{
+            posting_preferences::{PostingPreferences, PostingPreferencesUpdate, Visibility},
+            profile_details::{BirthdayVisibility, ProfileDetails},
+            user::{ProfileUpdate, ThemeColor, User},
+        },
+        repositories::{
+            credential_repository::CredentialRepository,
+            posting_preferences_repository::PostingPreferencesRepository,
+            user_repository::UserRepository,
+        },
+        services::{password_service::PasswordHasher, token_service::TokenVerifier},
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+        handlers::user_handler::UserInfo,
+    },
+    usecase::{
+        account_deletion_usecase::AccountDeletionUsecase, auth_usecase::AuthUsecase,
+        profile_usecase::ProfileUsecase,
+    },
+};
+
+// Request
+
+/// json for profile update request, omitted fields are left unchanged
+#[derive(Serialize, Deserialize)]
+pub struct UpdateCredentialsRequest {
+    pub display_name: Option<String>,
+    /// Bio of the account
+    pub note: Option<String>,
+    /// `#rrggbb`, an empty string clears it
+    pub theme_color: Option<String>,
+    /// `YYYY-MM-DD`, an empty string clears it
+    pub birthday: Option<String>,
+    /// hidden, month_day or full
+    pub birthday_visibility: Option<String>,
+    /// An empty string clears it
+    pub location: Option<String>,
+    pub location_visible: Option<bool>,
+    /// `updated_at` of the profile being edited, answered with 409 when it changed since
+    /// Required, a request without it is rejected with 422 rather than overwriting blindly
+    pub updated_at: DateTime<Utc>,
+    /// Defaults applied to new statuses
+    pub source: Option<SourceRequest>,
+}
+
+/// json for the posting defaults part of a profile update
+#[derive(Serialize, Deserialize)]
+pub struct SourceRequest {
+    /// public, unlisted, private or direct
+    pub privacy: Option<String>,
+    pub sensitive: Option<bool>,
+    /// ISO 639 code, an empty string clears it
+    pub language: Option<String>,
+    pub mark_media_sensitive: Option<bool>,
+}
+
+/// json for account deletion request, the password confirms the intent
+#[derive(Serialize, Deserialize)]
+pub struct DeleteAccountRequest {
+    pub password: String,
+}
+
+/// json for reactivation request, sent without a token since the account cannot log in
+#[derive(Serialize, Deserialize)]
+pub struct ReactivateAccountRequest {
+    pub user_id: String,
+    pub password: String,
+}
+
+impl SourceRequest {
+    /// helper function that validate the request into a domain update
+    fn into_update(self) -> Result<PostingPreferencesUpdate, DomainError> {
+        Ok(PostingPreferencesUpdate {
+            default_visibility: self.privacy.as_deref().map(Visibility::parse).transpose()?,
+            default_language: self.language,
+            default_sensitive: self.sensitive,
+            mark_media_sensitive: self.mark_media_sensitive,
+        })
+    }
+}
+
+impl UpdateCredentialsRequest {
+    /// helper function that merge the sent details into the current ones, `None` when none was sent
+    fn details(&self, current: &ProfileDetails) -> Result<Option<ProfileDetails>, DomainError> {
+        if self.birthday.is_none()
+            && self.birthday_visibility.is_none()
+            && self.location.is_none()
+            && self.location_visible.is_none()
+        {
+            return Ok(None);
+        }
+
+        let mut details = current.clone();
+        if let Some(birthday) = self.birthday.as_deref().map(str::trim) {
+            details.birthday = match birthday {
+                "" => None,
+                birthday => Some(
+                    NaiveDate::parse_from_str(birthday, "%Y-%m-%d")
+                        .map_err(|_| DomainError::InvalidProfileDetails)?,
+                ),
+            };
+        }
+        if let Some(visibility) = &self.birthday_visibility {
+            details.birthday_visibility = BirthdayVisibility::parse(visibility)?;
+        }
+        if let Some(location) = self.location.as_deref().map(str::trim) {
+            details.location = Some(location.to_string()).filter(|location| !location.is_empty());
+        }
+        if let Some(visible) = self.location_visible {
+            details.location_visible = visible;
+        }
+        Ok(Some(details))
+    }
+}
+
+// Response
+
+/// json for the caller's own account, with the version to send back on the next update
+#[derive(Serialize, Deserialize)]
+pub struct CredentialAccountResponse {
+    #[serde(flatten)]
+    pub account: UserInfo,
+    pub theme_color: Option<String>,
+    pub updated_at: DateTime<Utc>,
+    pub source: SourceResponse,
+}
+
+impl CredentialAccountResponse {
+    fn new(user: User, preferences: PostingPreferences) -> Self {
+        Self {
+            theme_color: user.theme_color().map(|color| color.as_str().to_string()),
+            updated_at: user.updated_at(),
+            source: SourceResponse {
+                note: user.summary().to_string(),
+                birthday: user.details().birthday,
+                birthday_visibility: user.details().birthday_visibility.as_str().to_string(),
+                location: user.details().location.clone(),
+                location_visible: user.details().location_visible,
+                privacy: preferences.default_visibility.as_str().to_string(),
+                sensitive: preferences.default_sensitive,
+                language: preferences.default_language,
+                mark_media_sensitive: preferences.mark_media_sensitive,
+            },
+            account: user.into(),
+        }
+    }
+}
+
+/// json for account deletion response
+#[derive(Serialize, Deserialize)]
+pub struct DeleteAccountResponse {
+    /// The account can be reactivated until then
+    pub purge_at: DateTime<Utc>,
+}
+
+/// json for the editable, unrendered settings of the caller's account
+#[derive(Serialize, Deserialize)]
+pub struct SourceResponse {
+    pub note: String,
+    pub birthday: Option<NaiveDate>,
+    pub birthday_visibility: String,
+    pub location: Option<String>,
+    pub location_visible: bool,
+    pub privacy: String,
+    pub sensitive: bool,
+    pub language: Option<String>,
+    pub mark_media_sensitive: bool,
+}
+
+/* Router Function and Handler Function */
+
+// Account Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_account_router<
+    C: CredentialRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    P: PostingPreferencesRepository + Send + Sync + 'static + Clone,
+    H: PasswordHasher + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    profile_service: ProfileUsecase<U, P>,
+    deletion_service: AccountDeletionUsecase<C, U, H>,
+) -> Router {
+    let state = AccountState {
+        auth_service: Arc::new(auth_service),
+        profile_service: Arc::new(profile_service),
+        deletion_service: Arc::new(deletion_service),
+    };
+
+    Router::new()
+        .route(
+            "/v1/accounts/verify_credentials",
+            get(verify_credentials::<C, U, P, H, V>),
+        )
+        .route(
+            "/v1/accounts/update_credentials",
+            patch(update_credentials::<C, U, P, H, V>),
+        )
+        .route("/v1/accounts/delete", post(delete_account::<C, U, P, H, V>))
+        .route(
+            "/v1/accounts/reactivate",
+            post(reactivate_account::<C, U, P, H, V>),
+        )
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct AccountState<
+    C: CredentialRepository,
+    U: UserRepository,
+    P: PostingPreferencesRepository,
+    H: PasswordHasher,
+    V: TokenVerifier,
+> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub profile_service: Arc<ProfileUsecase<U, P>>,
+    pub deletion_service: Arc<AccountDeletionUsecase<C, U, H>>,
+}
+
+impl<
+    C: CredentialRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    P: PostingPreferencesRepository + Send + Sync,
+    H: PasswordHasher + Send + Sync,
+    V: TokenVerifier,
+> AuthState for AccountState<C, U, P, H, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for reading the caller's own account
+#[instrument(skip_all)]
+async fn verify_credentials<
+    C: CredentialRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    P: PostingPreferencesRepository + Send + Sync,
+    H: PasswordHasher + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AccountState<C, U, P, H, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Response {
+    match state.profile_service.posting_preferences(&user).await {
+        Ok(preferences) => (
+            StatusCode::OK,
+            Json(CredentialAccountResponse::new(user, preferences)),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for editing the caller's profile and posting defaults
+#[instrument(skip_all)]
+async fn update_credentials<
+    C: CredentialRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    P: PostingPreferencesRepository + Send + Sync,
+    H: PasswordHasher + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AccountState<C, U, P, H, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Json(payload): Json<UpdateCredentialsRequest>,
+) -> Response {
+    // before `source` is moved out of the payload
+    let details = match payload.details(user.details()) {
+        Ok(details) => details,
+        Err(e) => return error_response(e),
+    };
+
+    let posting_update = match payload.source.map(SourceRequest::into_update).transpose() {
+        Ok(update) => update.unwrap_or_default(),
+        Err(e) => return error_response(e),
+    };
+
+    let theme_color = match payload.theme_color.as_deref().map(parse_theme_color) {
+        None => None,
+        Some(Ok(color)) => Some(color),
+        Some(Err(e)) => return error_response(e),
+    };
+
+    // the profile is only written (and its version checked) when a profile field is sent
+    let user = if payload.display_name.is_some()
+        || payload.note.is_some()
+        || theme_color.is_some()
+        || details.is_some()
+    {
+        let update = ProfileUpdate {
+            display_name: payload.display_name,
+            summary: payload.note,
+            theme_color,
+            details,
+        };
+        match state
+            .profile_service
+            .update(&user, update, payload.updated_at)
+            .await
+        {
+            Ok(user) => user,
+            Err(e) => return error_response(e),
+        }
+    } else {
+        user
+    };
+
+    let preferences = if posting_update.is_empty() {
+        state.profile_service.posting_preferences(&user).await
+    } else {
+        state
+            .profile_service
+            .update_posting_preferences(&user, posting_update)
+            .await
+    };
+    match preferences {
+        Ok(preferences) => (
+            StatusCode::OK,
+            Json(CredentialAccountResponse::new(user, preferences)),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for deleting the caller's account, reversible during the grace period
+#[instrument(skip_all)]
+async fn delete_account<
+    C: CredentialRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    P: PostingPreferencesRepository + Send + Sync,
+    H: PasswordHasher + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AccountState<C, U, P, H, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Json(payload): Json<DeleteAccountRequest>,
+) -> Response {
+    match state
+        .deletion_service
+        .request(&user, &payload.password)
+        .await
+    {
+        Ok(purge_at) => (
+            StatusCode::ACCEPTED,
+            Json(DeleteAccountResponse { purge_at }),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for undoing the deletion of an account, the client logs in afterwards
+#[instrument(skip_all)]
+async fn reactivate_account<
+    C: CredentialRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    P: PostingPreferencesRepository + Send + Sync,
+    H: PasswordHasher + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AccountState<C, U, P, H, V>>,
+    Json(payload): Json<ReactivateAccountRequest>,
+) -> Response {
+    match state
+        .deletion_service
+        .reactivate(&payload.user_id, &payload.password)
+        .await
+    {
+        Ok(user) => (StatusCode::OK, Json(UserInfo::from(user))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// helper function that parse a theme color, an empty string meaning no color
+fn parse_theme_color(value: &str) -> Result<Option<ThemeColor>, DomainError> {
+    match value.trim() {
+        "" => Ok(None),
+        value => ThemeColor::new(value).map(Some),
+    }
+}
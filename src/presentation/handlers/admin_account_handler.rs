@@ -0,0 +1,284 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, post},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        models::{
+            moderation::ModerationAction,
+            user::{User, UserId},
+        },
+        repositories::{audit_log_repository::AuditLogRepository, user_repository::UserRepository},
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::{Problem, ProblemType, error_response},
+    },
+    usecase::{auth_usecase::AuthUsecase, moderation_usecase::ModerationUsecase},
+};
+
+// Request
+
+/// json for moderation action request
+#[derive(Serialize, Deserialize)]
+pub struct AccountActionRequest {
+    /// disable, silence or suspend
+    #[serde(rename = "type")]
+    pub action: String,
+    /// Reason recorded in the audit log
+    pub text: Option<String>,
+}
+
+// Response
+
+/// json for admin account response
+#[derive(Serialize, Deserialize)]
+pub struct AdminAccountResponse {
+    pub id: UserId,
+    pub activity_id: String,
+    pub display_name: String,
+    pub role: String,
+    pub disabled: bool,
+    pub silenced: bool,
+    pub suspended: bool,
+    pub silenced_at: Option<DateTime<Utc>>,
+    pub suspended_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub verified_at: Option<DateTime<Utc>>,
+}
+
+impl From<User> for AdminAccountResponse {
+    fn from(user: User) -> Self {
+        let moderation = user.moderation();
+        Self {
+            id: user.id(),
+            activity_id: user.activity_id().as_str().to_string(),
+            display_name: user.display_name().to_string(),
+            role: user.role().as_str().to_string(),
+            disabled: moderation.disabled,
+            silenced: moderation.is_silenced(),
+            suspended: moderation.is_suspended(),
+            silenced_at: moderation.silenced_at,
+            suspended_at: moderation.suspended_at,
+            created_at: user.created_at(),
+            updated_at: user.updated_at(),
+            deleted_at: user.deleted_at(),
+            verified_at: user.verified_at(),
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Admin Account Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_admin_account_router<
+    U: UserRepository + Send + Sync + 'static + Clone,
+    A: AuditLogRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    moderation_service: ModerationUsecase<U, A>,
+) -> Router {
+    let state = AdminAccountState {
+        auth_service: Arc::new(auth_service),
+        moderation_service: Arc::new(moderation_service),
+    };
+
+    Router::new()
+        .route("/v1/admin/accounts/{id}", delete(delete_account::<U, A, V>))
+        .route("/v1/admin/accounts/{id}/action", post(action::<U, A, V>))
+        .route("/v1/admin/accounts/{id}/enable", post(enable::<U, A, V>))
+        .route("/v1/admin/accounts/{id}/unsilence", post(unsilence::<U, A, V>))
+        .route("/v1/admin/accounts/{id}/unsuspend", post(unsuspend::<U, A, V>))
+        .route("/v1/admin/accounts/{id}/verify", post(verify::<U, A, V>))
+        .route("/v1/admin/accounts/{id}/unverify", post(unverify::<U, A, V>))
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct AdminAccountState<U: UserRepository, A: AuditLogRepository, V: TokenVerifier> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub moderation_service: Arc<ModerationUsecase<U, A>>,
+}
+
+impl<U: UserRepository + Send + Sync, A: AuditLogRepository + Send + Sync, V: TokenVerifier>
+    AuthState for AdminAccountState<U, A, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for disable / silence / suspend
+#[instrument(skip_all, fields(account_id = %id))]
+async fn action<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AdminAccountState<U, A, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<UserId>,
+    Json(payload): Json<AccountActionRequest>,
+) -> Response {
+    let action = match payload.action.as_str() {
+        "disable" => ModerationAction::Disable,
+        "silence" => ModerationAction::Silence,
+        "suspend" => ModerationAction::Suspend,
+        _ => {
+            return Problem::new(ProblemType::ValidationFailed)
+                .with_detail("Unknown action")
+                .into_response();
+        }
+    };
+    apply(&state, &moderator, id, action, payload.text).await
+}
+
+/// handler function for re-enabling login
+#[instrument(skip_all, fields(account_id = %id))]
+async fn enable<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AdminAccountState<U, A, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<UserId>,
+) -> Response {
+    apply(&state, &moderator, id, ModerationAction::Enable, None).await
+}
+
+/// handler function for lifting a silence
+#[instrument(skip_all, fields(account_id = %id))]
+async fn unsilence<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AdminAccountState<U, A, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<UserId>,
+) -> Response {
+    apply(&state, &moderator, id, ModerationAction::Unsilence, None).await
+}
+
+/// handler function for lifting a suspension
+#[instrument(skip_all, fields(account_id = %id))]
+async fn unsuspend<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AdminAccountState<U, A, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<UserId>,
+) -> Response {
+    apply(&state, &moderator, id, ModerationAction::Unsuspend, None).await
+}
+
+/// handler function for granting the verified badge
+#[instrument(skip_all, fields(account_id = %id))]
+async fn verify<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AdminAccountState<U, A, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(id): Path<UserId>,
+) -> Response {
+    set_verified(&state, &admin, id, true).await
+}
+
+/// handler function for revoking the verified badge
+#[instrument(skip_all, fields(account_id = %id))]
+async fn unverify<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AdminAccountState<U, A, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(id): Path<UserId>,
+) -> Response {
+    set_verified(&state, &admin, id, false).await
+}
+
+/// handler function for account deletion, the account answers 410 Gone afterwards
+#[instrument(skip_all, fields(account_id = %id))]
+async fn delete_account<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AdminAccountState<U, A, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<UserId>,
+) -> Response {
+    match state.moderation_service.delete(&moderator, id, None).await {
+        Ok(user) => (StatusCode::OK, Json(AdminAccountResponse::from(user))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// helper function that apply a moderation action on behalf of `moderator`
+async fn apply<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    state: &AdminAccountState<U, A, V>,
+    moderator: &User,
+    id: UserId,
+    action: ModerationAction,
+    reason: Option<String>,
+) -> Response {
+    match state
+        .moderation_service
+        .apply(moderator, id, action, reason)
+        .await {
+        Ok(user) => (StatusCode::OK, Json(AdminAccountResponse::from(user))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// helper function that grant or revoke the verified badge on behalf of `admin`
+async fn set_verified<
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    state: &AdminAccountState<U, A, V>,
+    admin: &User,
+    id: UserId,
+    verified: bool,
+) -> Response {
+    match state
+        .moderation_service
+        .set_verified(admin, id, verified)
+        .await
+    {
+        Ok(user) => (StatusCode::OK, Json(AdminAccountResponse::from(user))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
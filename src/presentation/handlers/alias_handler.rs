@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        models::alias::AccountAlias,
+        repositories::{
+            account_alias_repository::AccountAliasRepository, user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+    },
+    usecase::{alias_usecase::AliasUsecase, auth_usecase::AuthUsecase},
+};
+
+// Request
+
+/// json for alias creation and removal request
+#[derive(Serialize, Deserialize)]
+pub struct AliasRequest {
+    /// ActivityPub id of the other account
+    pub uri: String,
+}
+
+// Response
+
+/// json for alias response
+#[derive(Serialize, Deserialize)]
+pub struct AliasResponse {
+    pub uri: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AccountAlias> for AliasResponse {
+    fn from(alias: AccountAlias) -> Self {
+        Self {
+            uri: alias.uri.as_str().to_string(),
+            created_at: alias.created_at,
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Alias Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_alias_router<
+    L: AccountAliasRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    alias_service: AliasUsecase<L>,
+) -> Router {
+    let state = AliasState {
+        auth_service: Arc::new(auth_service),
+        alias_service: Arc::new(alias_service),
+    };
+
+    Router::new()
+        .route(
+            "/v1/accounts/aliases",
+            get(list_aliases::<L, U, V>)
+                .post(add_alias::<L, U, V>)
+                .delete(remove_alias::<L, U, V>),
+        )
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct AliasState<L: AccountAliasRepository, U: UserRepository, V: TokenVerifier> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub alias_service: Arc<AliasUsecase<L>>,
+}
+
+impl<L: AccountAliasRepository + Send + Sync, U: UserRepository + Send + Sync, V: TokenVerifier>
+    AuthState for AliasState<L, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for listing the caller's aliases
+#[instrument(skip_all)]
+async fn list_aliases<
+    L: AccountAliasRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AliasState<L, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Response {
+    match state.alias_service.list(&user).await {
+        Ok(aliases) => (
+            StatusCode::OK,
+            Json(
+                aliases
+                    .into_iter()
+                    .map(AliasResponse::from)
+                    .collect::<Vec<_>>(),
+            ),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for adding an alias
+#[instrument(skip_all)]
+async fn add_alias<
+    L: AccountAliasRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AliasState<L, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Json(payload): Json<AliasRequest>,
+) -> Response {
+    match state.alias_service.add(&user, payload.uri).await {
+        Ok(alias) => (StatusCode::CREATED, Json(AliasResponse::from(alias))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for removing an alias
+#[instrument(skip_all)]
+async fn remove_alias<
+    L: AccountAliasRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AliasState<L, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Json(payload): Json<AliasRequest>,
+) -> Response {
+    match state.alias_service.remove(&user, payload.uri).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::{
+            audit_log::{AuditLogEntry, AuditLogFilter},
+            user::UserId,
+        },
+        repositories::{
+            audit_log_repository::AuditLogRepository, user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+    },
+    usecase::{audit_log_usecase::AuditLogUsecase, auth_usecase::AuthUsecase},
+};
+
+/// Page size when `limit` is omitted
+const DEFAULT_LIMIT: u64 = 40;
+
+// Request
+
+/// query of the audit log listing
+#[derive(Deserialize)]
+pub struct AuditLogQuery {
+    pub actor_id: Option<UserId>,
+    /// e.g. `account.suspend`, `report.resolve`
+    pub action: Option<String>,
+    pub target_id: Option<Uuid>,
+    /// Only entries created before this instant (RFC 3339)
+    pub before: Option<DateTime<Utc>>,
+    pub limit: Option<u64>,
+}
+
+// Response
+
+/// json for audit log entry response
+#[derive(Serialize, Deserialize)]
+pub struct AuditLogResponse {
+    pub id: Uuid,
+    pub actor_id: Option<UserId>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Uuid,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<AuditLogEntry> for AuditLogResponse {
+    fn from(entry: AuditLogEntry) -> Self {
+        Self {
+            id: entry.id,
+            actor_id: entry.actor_id,
+            action: entry.action,
+            target_type: entry.target_type,
+            target_id: entry.target_id,
+            reason: entry.reason,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Audit Log Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_audit_log_router<
+    A: AuditLogRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    audit_log_service: AuditLogUsecase<A>,
+) -> Router {
+    let state = AuditLogState {
+        auth_service: Arc::new(auth_service),
+        audit_log_service: Arc::new(audit_log_service),
+    };
+
+    Router::new()
+        .route("/v1/admin/audit_log", get(list_audit_log::<A, U, V>))
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct AuditLogState<A: AuditLogRepository, U: UserRepository, V: TokenVerifier> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub audit_log_service: Arc<AuditLogUsecase<A>>,
+}
+
+impl<A: AuditLogRepository + Send + Sync, U: UserRepository + Send + Sync, V: TokenVerifier>
+    AuthState for AuditLogState<A, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for audit log listing
+#[instrument(skip_all)]
+async fn list_audit_log<
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<AuditLogState<A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Query(query): Query<AuditLogQuery>,
+) -> Response {
+    let filter = AuditLogFilter {
+        actor_id: query.actor_id,
+        action: query.action,
+        target_id: query.target_id,
+        before: query.before,
+        limit: query.limit.unwrap_or(DEFAULT_LIMIT),
+    };
+
+    match state.audit_log_service.list(&admin, filter).await {
+        Ok(entries) => {
+            let response: Vec<AuditLogResponse> = entries.into_iter().map(Into::into).collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
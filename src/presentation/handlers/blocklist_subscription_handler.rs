@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::federation::BlocklistSubscription,
+        repositories::{
+            audit_log_repository::AuditLogRepository,
+            blocklist_subscription_repository::BlocklistSubscriptionRepository,
+            federation_domain_repository::FederationDomainRepository,
+            user_repository::UserRepository,
+        },
+        services::{blocklist_service::BlocklistFetcher, token_service::TokenVerifier},
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+        handlers::federation_handler::{BlocklistImportQuery, BlocklistImportResponse},
+    },
+    usecase::{
+        auth_usecase::AuthUsecase, blocklist_subscription_usecase::BlocklistSubscriptionUsecase,
+        federation_usecase::BlocklistImport,
+    },
+};
+
+// Request
+
+/// json for the import of the blocklist served at a URL
+#[derive(Serialize, Deserialize)]
+pub struct BlocklistUrlRequest {
+    pub url: String,
+}
+
+/// json for blocklist subscription request
+#[derive(Serialize, Deserialize)]
+pub struct BlocklistSubscriptionRequest {
+    pub url: String,
+    /// Hold new entries until an admin syncs the subscription
+    #[serde(default)]
+    pub review: bool,
+}
+
+// Response
+
+/// json for blocklist subscription response
+#[derive(Serialize, Deserialize)]
+pub struct BlocklistSubscriptionResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub review: bool,
+    /// Entries of the last sync waiting for review
+    pub pending: usize,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<BlocklistSubscription> for BlocklistSubscriptionResponse {
+    fn from(subscription: BlocklistSubscription) -> Self {
+        Self {
+            id: subscription.id,
+            url: subscription.url,
+            review: subscription.review,
+            pending: subscription.pending,
+            last_synced_at: subscription.last_synced_at,
+            last_error: subscription.last_error,
+            created_at: subscription.created_at,
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Blocklist Subscription Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+/// The usecase is shared with the periodic sync task
+pub fn create_blocklist_subscription_router<
+    S: BlocklistSubscriptionRepository + Send + Sync + 'static,
+    F: BlocklistFetcher + 'static,
+    D: FederationDomainRepository + Send + Sync + 'static,
+    A: AuditLogRepository + Send + Sync + 'static,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    blocklist_service: Arc<BlocklistSubscriptionUsecase<S, F, D, A>>,
+) -> Router {
+    let state = BlocklistSubscriptionState {
+        auth_service: Arc::new(auth_service),
+        blocklist_service,
+    };
+
+    Router::new()
+        .route(
+            "/v1/admin/federation/domains/import_url",
+            post(import_url::<S, F, D, A, U, V>),
+        )
+        .route(
+            "/v1/admin/federation/subscriptions",
+            get(list_subscriptions::<S, F, D, A, U, V>).post(subscribe::<S, F, D, A, U, V>),
+        )
+        .route(
+            "/v1/admin/federation/subscriptions/{id}",
+            delete(unsubscribe::<S, F, D, A, U, V>),
+        )
+        .route(
+            "/v1/admin/federation/subscriptions/{id}/sync",
+            post(sync_subscription::<S, F, D, A, U, V>),
+        )
+        .with_state(state)
+}
+
+pub struct BlocklistSubscriptionState<
+    S: BlocklistSubscriptionRepository,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository,
+    A: AuditLogRepository,
+    U: UserRepository,
+    V: TokenVerifier,
+> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub blocklist_service: Arc<BlocklistSubscriptionUsecase<S, F, D, A>>,
+}
+
+impl<
+    S: BlocklistSubscriptionRepository + Send + Sync,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+> AuthState for BlocklistSubscriptionState<S, F, D, A, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+impl<
+    S: BlocklistSubscriptionRepository,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository,
+    A: AuditLogRepository,
+    U: UserRepository,
+    V: TokenVerifier,
+> Clone for BlocklistSubscriptionState<S, F, D, A, U, V>
+{
+    fn clone(&self) -> Self {
+        Self {
+            auth_service: self.auth_service.clone(),
+            blocklist_service: self.blocklist_service.clone(),
+        }
+    }
+}
+
+// handler function
+
+/// handler function for the import of the blocklist served at a URL
+#[instrument(skip_all, fields(preview = query.preview))]
+async fn import_url<
+    S: BlocklistSubscriptionRepository + Send + Sync,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<BlocklistSubscriptionState<S, F, D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Query(query): Query<BlocklistImportQuery>,
+    Json(payload): Json<BlocklistUrlRequest>,
+) -> Response {
+    match state
+        .blocklist_service
+        .import_url(&admin, &payload.url, query.preview)
+        .await
+    {
+        Ok(import) => import_response(import, query.preview),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for the blocklist subscription list
+#[instrument(skip_all)]
+async fn list_subscriptions<
+    S: BlocklistSubscriptionRepository + Send + Sync,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<BlocklistSubscriptionState<S, F, D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+) -> Response {
+    match state.blocklist_service.list(&admin).await {
+        Ok(subscriptions) => {
+            let response: Vec<BlocklistSubscriptionResponse> =
+                subscriptions.into_iter().map(Into::into).collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for blocklist subscription
+#[instrument(skip_all)]
+async fn subscribe<
+    S: BlocklistSubscriptionRepository + Send + Sync,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<BlocklistSubscriptionState<S, F, D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Json(payload): Json<BlocklistSubscriptionRequest>,
+) -> Response {
+    match state
+        .blocklist_service
+        .subscribe(&admin, &payload.url, payload.review)
+        .await
+    {
+        Ok(subscription) => (
+            StatusCode::CREATED,
+            Json(BlocklistSubscriptionResponse::from(subscription)),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for blocklist unsubscription
+#[instrument(skip_all, fields(blocklist_subscription_id = %id))]
+async fn unsubscribe<
+    S: BlocklistSubscriptionRepository + Send + Sync,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<BlocklistSubscriptionState<S, F, D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    match state.blocklist_service.unsubscribe(&admin, id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for the sync of one subscription, applying the entries held for review
+#[instrument(skip_all, fields(blocklist_subscription_id = %id, preview = query.preview))]
+async fn sync_subscription<
+    S: BlocklistSubscriptionRepository + Send + Sync,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<BlocklistSubscriptionState<S, F, D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+    Query(query): Query<BlocklistImportQuery>,
+) -> Response {
+    match state
+        .blocklist_service
+        .sync(&admin, id, query.preview)
+        .await
+    {
+        Ok(import) => import_response(import, query.preview),
+        Err(e) => error_response(e),
+    }
+}
+
+/// helper function that answer an import like the CSV upload does
+fn import_response(import: BlocklistImport, preview: bool) -> Response {
+    let response = BlocklistImportResponse {
+        preview,
+        added: import.added.into_iter().map(Into::into).collect(),
+        skipped: import.skipped,
+    };
+    let status = if preview {
+        StatusCode::OK
+    } else {
+        StatusCode::CREATED
+    };
+    (status, Json(response)).into_response()
+}
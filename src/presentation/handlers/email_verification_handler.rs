@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde::Deserialize;
+use tracing::instrument;
+
+use crate::{
+    domain::repositories::email_verification_repository::EmailVerificationRepository,
+    presentation::error::error_response,
+    usecase::email_verification_usecase::EmailVerificationUsecase,
+};
+
+// Request
+
+/// query of the link sent by email at sign-up
+#[derive(Deserialize)]
+pub struct VerifyEmailQuery {
+    pub token: String,
+}
+
+/* Router Function and Handler Function */
+
+// Email Verification Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_email_verification_router<V: EmailVerificationRepository + Send + Sync + 'static>(
+    email_verification_service: EmailVerificationUsecase<V>,
+) -> Router {
+    let state = EmailVerificationState {
+        email_verification_service: Arc::new(email_verification_service),
+    };
+
+    Router::new()
+        .route("/verify_email", get(verify_email::<V>))
+        .with_state(state)
+}
+
+pub struct EmailVerificationState<V: EmailVerificationRepository> {
+    pub email_verification_service: Arc<EmailVerificationUsecase<V>>,
+}
+
+impl<V: EmailVerificationRepository> Clone for EmailVerificationState<V> {
+    fn clone(&self) -> Self {
+        Self {
+            email_verification_service: self.email_verification_service.clone(),
+        }
+    }
+}
+
+// handler function
+
+/// handler function for the verification link
+#[instrument(skip_all)]
+async fn verify_email<V: EmailVerificationRepository + Send + Sync>(
+    State(state): State<EmailVerificationState<V>>,
+    Query(query): Query<VerifyEmailQuery>,
+) -> Response {
+    match state.email_verification_service.verify(&query.token).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        models::user::{User, UserId},
+        repositories::{
+            account_endorsement_repository::AccountEndorsementRepository,
+            user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+        handlers::user_handler::UserInfo,
+    },
+    usecase::{auth_usecase::AuthUsecase, endorsement_usecase::EndorsementUsecase},
+};
+
+// Response
+
+/// json for the caller's relationship with an account after (un)featuring it
+#[derive(Serialize, Deserialize)]
+pub struct EndorsementRelationshipResponse {
+    pub id: UserId,
+    pub endorsed: bool,
+}
+
+/* Router Function and Handler Function */
+
+// Endorsement Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_endorsement_router<
+    E: AccountEndorsementRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    endorsement_service: EndorsementUsecase<E, U>,
+) -> Router {
+    let state = EndorsementState {
+        auth_service: Arc::new(auth_service),
+        endorsement_service: Arc::new(endorsement_service),
+    };
+
+    Router::new()
+        .route("/v1/endorsements", get(list_own::<E, U, V>))
+        .route(
+            "/v1/accounts/{id}/endorsements",
+            get(list_of_account::<E, U, V>),
+        )
+        .route("/v1/accounts/{id}/pin", post(endorse::<E, U, V>))
+        .route("/v1/accounts/{id}/unpin", post(unendorse::<E, U, V>))
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct EndorsementState<E: AccountEndorsementRepository, U: UserRepository, V: TokenVerifier> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub endorsement_service: Arc<EndorsementUsecase<E, U>>,
+}
+
+impl<
+    E: AccountEndorsementRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+> AuthState for EndorsementState<E, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for listing the accounts the caller features
+#[instrument(skip_all)]
+async fn list_own<
+    E: AccountEndorsementRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<EndorsementState<E, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Response {
+    featured_response(state.endorsement_service.list(user.id()).await)
+}
+
+/// handler function for listing the accounts featured on a profile, public
+#[instrument(skip_all, fields(account_id = %id))]
+async fn list_of_account<
+    E: AccountEndorsementRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<EndorsementState<E, U, V>>,
+    Path(id): Path<UserId>,
+) -> Response {
+    featured_response(state.endorsement_service.list(id).await)
+}
+
+/// handler function for featuring an account on the caller's profile
+#[instrument(skip_all, fields(account_id = %id))]
+async fn endorse<
+    E: AccountEndorsementRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<EndorsementState<E, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<UserId>,
+) -> Response {
+    match state.endorsement_service.endorse(&user, id).await {
+        Ok(()) => relationship_response(id, true),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for removing an account from the caller's profile
+#[instrument(skip_all, fields(account_id = %id))]
+async fn unendorse<
+    E: AccountEndorsementRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<EndorsementState<E, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(id): Path<UserId>,
+) -> Response {
+    match state.endorsement_service.unendorse(&user, id).await {
+        Ok(()) => relationship_response(id, false),
+        Err(e) => error_response(e),
+    }
+}
+
+/// helper function that answer a list of featured accounts
+fn featured_response(result: Result<Vec<User>, DomainError>) -> Response {
+    match result {
+        Ok(users) => (
+            StatusCode::OK,
+            Json(users.into_iter().map(UserInfo::from).collect::<Vec<_>>()),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// helper function that answer the relationship with the (un)featured account
+fn relationship_response(id: UserId, endorsed: bool) -> Response {
+    (
+        StatusCode::OK,
+        Json(EndorsementRelationshipResponse { id, endorsed }),
+    )
+        .into_response()
+}
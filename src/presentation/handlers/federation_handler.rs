@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::federation::{DomainPolicy, FederationDomain, parse_blocklist},
+        repositories::{
+            audit_log_repository::AuditLogRepository,
+            federation_domain_repository::FederationDomainRepository,
+            user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+    },
+    usecase::{auth_usecase::AuthUsecase, federation_usecase::FederationUsecase},
+};
+
+// Request
+
+/// json for federation domain creation request
+#[derive(Serialize, Deserialize)]
+pub struct FederationDomainRequest {
+    /// Host name, its subdomains are covered too
+    pub domain: String,
+    /// `block` or `allow`
+    pub policy: String,
+    #[serde(default)]
+    pub comment: String,
+}
+
+/// query of the blocklist import
+#[derive(Deserialize)]
+pub struct BlocklistImportQuery {
+    /// Only report what would be added
+    #[serde(default)]
+    pub preview: bool,
+}
+
+// Response
+
+/// json for federation domain response
+#[derive(Serialize, Deserialize)]
+pub struct FederationDomainResponse {
+    pub id: Uuid,
+    pub domain: String,
+    pub policy: String,
+    pub comment: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<FederationDomain> for FederationDomainResponse {
+    fn from(domain: FederationDomain) -> Self {
+        Self {
+            id: domain.id,
+            domain: domain.domain,
+            policy: domain.policy.as_str().to_string(),
+            comment: domain.comment,
+            created_at: domain.created_at,
+        }
+    }
+}
+
+/// json for the domain list, along with the mode it is read in
+#[derive(Serialize, Deserialize)]
+pub struct FederationDomainListResponse {
+    /// `blocklist` or `allowlist`
+    pub mode: String,
+    pub domains: Vec<FederationDomainResponse>,
+}
+
+/// json for blocklist import response
+#[derive(Serialize, Deserialize)]
+pub struct BlocklistImportResponse {
+    pub preview: bool,
+    pub added: Vec<FederationDomainResponse>,
+    pub skipped: Vec<String>,
+}
+
+/* Router Function and Handler Function */
+
+// Federation Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+/// The usecase is shared with the media proxy, so changes reach it immediately
+pub fn create_federation_router<
+    D: FederationDomainRepository + Send + Sync + 'static,
+    A: AuditLogRepository + Send + Sync + 'static,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    federation_service: Arc<FederationUsecase<D, A>>,
+) -> Router {
+    let state = FederationState {
+        auth_service: Arc::new(auth_service),
+        federation_service,
+    };
+
+    Router::new()
+        .route(
+            "/v1/admin/federation/domains",
+            get(list_domains::<D, A, U, V>).post(create_domain::<D, A, U, V>),
+        )
+        .route(
+            "/v1/admin/federation/domains/import",
+            post(import_blocklist::<D, A, U, V>),
+        )
+        .route(
+            "/v1/admin/federation/domains/{id}",
+            delete(delete_domain::<D, A, U, V>),
+        )
+        .with_state(state)
+}
+
+pub struct FederationState<
+    D: FederationDomainRepository,
+    A: AuditLogRepository,
+    U: UserRepository,
+    V: TokenVerifier,
+> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub federation_service: Arc<FederationUsecase<D, A>>,
+}
+
+impl<
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+> AuthState for FederationState<D, A, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+impl<D: FederationDomainRepository, A: AuditLogRepository, U: UserRepository, V: TokenVerifier>
+    Clone for FederationState<D, A, U, V>
+{
+    fn clone(&self) -> Self {
+        Self {
+            auth_service: self.auth_service.clone(),
+            federation_service: self.federation_service.clone(),
+        }
+    }
+}
+
+// handler function
+
+/// handler function for the federation domain list
+#[instrument(skip_all)]
+async fn list_domains<
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<FederationState<D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+) -> Response {
+    match state.federation_service.list(&admin).await {
+        Ok(domains) => {
+            let response = FederationDomainListResponse {
+                mode: state.federation_service.mode().as_str().to_string(),
+                domains: domains.into_iter().map(Into::into).collect(),
+            };
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for federation domain creation
+#[instrument(skip_all)]
+async fn create_domain<
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<FederationState<D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Json(payload): Json<FederationDomainRequest>,
+) -> Response {
+    let policy = match DomainPolicy::parse(&payload.policy) {
+        Ok(policy) => policy,
+        Err(e) => return error_response(e),
+    };
+
+    match state
+        .federation_service
+        .create(&admin, &payload.domain, policy, payload.comment)
+        .await
+    {
+        Ok(domain) => (
+            StatusCode::CREATED,
+            Json(FederationDomainResponse::from(domain)),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for the import of a shared blocklist, sent as CSV
+#[instrument(skip_all, fields(preview = query.preview))]
+async fn import_blocklist<
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<FederationState<D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Query(query): Query<BlocklistImportQuery>,
+    body: String,
+) -> Response {
+    let entries = match parse_blocklist(&body) {
+        Ok(entries) => entries,
+        Err(e) => return error_response(e),
+    };
+
+    match state
+        .federation_service
+        .import_blocks(&admin, entries, query.preview)
+        .await
+    {
+        Ok(import) => {
+            let response = BlocklistImportResponse {
+                preview: query.preview,
+                added: import.added.into_iter().map(Into::into).collect(),
+                skipped: import.skipped,
+            };
+            let status = if query.preview {
+                StatusCode::OK
+            } else {
+                StatusCode::CREATED
+            };
+            (status, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for federation domain deletion
+#[instrument(skip_all, fields(federation_domain_id = %id))]
+async fn delete_domain<
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<FederationState<D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    match state.federation_service.delete(&admin, id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
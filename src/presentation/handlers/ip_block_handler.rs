@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        models::ip_block::{IpBlock, IpBlockSeverity},
+        repositories::{
+            audit_log_repository::AuditLogRepository, ip_block_repository::IpBlockRepository,
+            user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+    },
+    usecase::{auth_usecase::AuthUsecase, ip_block_usecase::IpBlockUsecase},
+};
+
+// Request
+
+/// json for ip block creation request
+#[derive(Serialize, Deserialize)]
+pub struct IpBlockRequest {
+    /// Single address or CIDR range
+    pub ip: String,
+    /// `sign_up_requires_approval`, `sign_up_block` or `no_access`
+    pub severity: String,
+    #[serde(default)]
+    pub comment: String,
+    /// Seconds until the block lapses, permanent when omitted
+    pub expires_in: Option<i64>,
+}
+
+// Response
+
+/// json for ip block response
+#[derive(Serialize, Deserialize)]
+pub struct IpBlockResponse {
+    pub id: Uuid,
+    pub ip: String,
+    pub severity: String,
+    pub comment: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl From<IpBlock> for IpBlockResponse {
+    fn from(block: IpBlock) -> Self {
+        Self {
+            id: block.id,
+            ip: block.network.to_string(),
+            severity: block.severity.as_str().to_string(),
+            comment: block.comment,
+            created_at: block.created_at,
+            expires_at: block.expires_at,
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// IP Block Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+/// The usecase is shared with the IP block middleware, so changes reach it immediately
+pub fn create_ip_block_router<
+    B: IpBlockRepository + Send + Sync + 'static + Clone,
+    A: AuditLogRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    ip_block_service: Arc<IpBlockUsecase<B, A>>,
+) -> Router {
+    let state = IpBlockState {
+        auth_service: Arc::new(auth_service),
+        ip_block_service,
+    };
+
+    Router::new()
+        .route(
+            "/v1/admin/ip_blocks",
+            get(list_ip_blocks::<B, A, U, V>).post(create_ip_block::<B, A, U, V>),
+        )
+        .route(
+            "/v1/admin/ip_blocks/{id}",
+            delete(delete_ip_block::<B, A, U, V>),
+        )
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct IpBlockState<
+    B: IpBlockRepository,
+    A: AuditLogRepository,
+    U: UserRepository,
+    V: TokenVerifier,
+> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub ip_block_service: Arc<IpBlockUsecase<B, A>>,
+}
+
+impl<
+    B: IpBlockRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+> AuthState for IpBlockState<B, A, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for the ip block list
+#[instrument(skip_all)]
+async fn list_ip_blocks<
+    B: IpBlockRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<IpBlockState<B, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+) -> Response {
+    match state.ip_block_service.list(&admin).await {
+        Ok(blocks) => {
+            let response: Vec<IpBlockResponse> = blocks.into_iter().map(Into::into).collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for ip block creation
+#[instrument(skip_all)]
+async fn create_ip_block<
+    B: IpBlockRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<IpBlockState<B, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Json(payload): Json<IpBlockRequest>,
+) -> Response {
+    let severity = match IpBlockSeverity::parse(&payload.severity) {
+        Ok(severity) => severity,
+        Err(e) => return error_response(e),
+    };
+    let expires_at = match payload.expires_in.map(Duration::try_seconds) {
+        None => None,
+        Some(Some(expires_in)) => Some(Utc::now() + expires_in),
+        Some(None) => return error_response(DomainError::InvalidIpBlock),
+    };
+
+    match state
+        .ip_block_service
+        .create(&admin, &payload.ip, severity, payload.comment, expires_at)
+        .await
+    {
+        Ok(block) => (StatusCode::CREATED, Json(IpBlockResponse::from(block))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for ip block deletion
+#[instrument(skip_all, fields(ip_block_id = %id))]
+async fn delete_ip_block<
+    B: IpBlockRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<IpBlockState<B, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    match state.ip_block_service.delete(&admin, id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
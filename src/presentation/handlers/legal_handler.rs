@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        models::legal::{LegalDocument, LegalDocumentKind},
+        repositories::{
+            audit_log_repository::AuditLogRepository,
+            legal_document_repository::LegalDocumentRepository, user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+    },
+    usecase::{auth_usecase::AuthUsecase, legal_usecase::LegalUsecase},
+};
+
+// Request
+
+/// json for legal document publication request
+#[derive(Serialize, Deserialize)]
+pub struct PublishLegalDocumentRequest {
+    pub content: String,
+}
+
+/// json for legal document acceptance request
+#[derive(Serialize, Deserialize)]
+pub struct AcceptLegalDocumentRequest {
+    /// Version the user was shown, rejected when a newer one exists
+    pub version: i32,
+}
+
+// Response
+
+/// json for legal document response
+#[derive(Serialize, Deserialize)]
+pub struct LegalDocumentResponse {
+    pub kind: String,
+    pub version: i32,
+    pub content: String,
+    pub published_at: DateTime<Utc>,
+}
+
+impl From<LegalDocument> for LegalDocumentResponse {
+    fn from(document: LegalDocument) -> Self {
+        Self {
+            kind: document.kind.as_str().to_string(),
+            version: document.version,
+            content: document.content,
+            published_at: document.published_at,
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Legal Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_legal_router<
+    D: LegalDocumentRepository + Send + Sync + 'static + Clone,
+    A: AuditLogRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    legal_service: LegalUsecase<D, A>,
+) -> Router {
+    let state = LegalState {
+        auth_service: Arc::new(auth_service),
+        legal_service: Arc::new(legal_service),
+    };
+
+    Router::new()
+        .route(
+            "/v1/instance/legal/{kind}",
+            get(current_document::<D, A, U, V>),
+        )
+        .route(
+            "/v1/admin/legal/{kind}",
+            put(publish_document::<D, A, U, V>),
+        )
+        .route("/v1/legal/pending", get(pending_documents::<D, A, U, V>))
+        .route(
+            "/v1/legal/{kind}/accept",
+            post(accept_document::<D, A, U, V>),
+        )
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct LegalState<
+    D: LegalDocumentRepository,
+    A: AuditLogRepository,
+    U: UserRepository,
+    V: TokenVerifier,
+> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub legal_service: Arc<LegalUsecase<D, A>>,
+}
+
+impl<
+    D: LegalDocumentRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+> AuthState for LegalState<D, A, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for the current version of a document
+#[instrument(skip_all, fields(kind = %kind))]
+async fn current_document<
+    D: LegalDocumentRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<LegalState<D, A, U, V>>,
+    Path(kind): Path<String>,
+) -> Response {
+    let kind = match LegalDocumentKind::parse(&kind) {
+        Ok(kind) => kind,
+        Err(e) => return error_response(e),
+    };
+
+    match state.legal_service.current(kind).await {
+        Ok(document) => {
+            (StatusCode::OK, Json(LegalDocumentResponse::from(document))).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for publishing a new version of a document
+#[instrument(skip_all, fields(kind = %kind))]
+async fn publish_document<
+    D: LegalDocumentRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<LegalState<D, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(kind): Path<String>,
+    Json(payload): Json<PublishLegalDocumentRequest>,
+) -> Response {
+    let kind = match LegalDocumentKind::parse(&kind) {
+        Ok(kind) => kind,
+        Err(e) => return error_response(e),
+    };
+
+    match state
+        .legal_service
+        .publish(&admin, kind, payload.content)
+        .await
+    {
+        Ok(document) => (
+            StatusCode::CREATED,
+            Json(LegalDocumentResponse::from(document)),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for the documents the caller still has to accept
+#[instrument(skip_all)]
+async fn pending_documents<
+    D: LegalDocumentRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<LegalState<D, A, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Response {
+    match state.legal_service.pending(&user).await {
+        Ok(documents) => {
+            let response: Vec<LegalDocumentResponse> =
+                documents.into_iter().map(Into::into).collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for accepting the current version of a document
+#[instrument(skip_all, fields(kind = %kind))]
+async fn accept_document<
+    D: LegalDocumentRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<LegalState<D, A, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Path(kind): Path<String>,
+    Json(payload): Json<AcceptLegalDocumentRequest>,
+) -> Response {
+    let kind = match LegalDocumentKind::parse(&kind) {
+        Ok(kind) => kind,
+        Err(e) => return error_response(e),
+    };
+
+    match state
+        .legal_service
+        .accept(&user, kind, payload.version)
+        .await
+    {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
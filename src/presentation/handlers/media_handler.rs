@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+    routing::get,
+};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        repositories::{
+            audit_log_repository::AuditLogRepository,
+            federation_domain_repository::FederationDomainRepository,
+        },
+        services::media_proxy_service::{MediaFetcher, UrlSigner},
+    },
+    presentation::error::error_response,
+    usecase::media_proxy_usecase::MediaProxyUsecase,
+};
+
+/// Remote files never change behind a given URL, let browsers and CDNs keep them
+const CACHE_CONTROL: &str = "public, max-age=604800, immutable";
+
+/* Router Function and Handler Function */
+
+// Media Router
+
+/// function return Router object
+/// Suppose to be merged into main router (served outside of /api)
+pub fn create_media_router<
+    S: UrlSigner + Send + Sync + 'static,
+    F: MediaFetcher + Send + Sync + 'static,
+    D: FederationDomainRepository + Send + Sync + 'static,
+    A: AuditLogRepository + Send + Sync + 'static,
+>(
+    media_proxy_service: MediaProxyUsecase<S, F, D, A>,
+) -> Router {
+    let state = MediaState {
+        media_proxy_service: Arc::new(media_proxy_service),
+    };
+
+    Router::new()
+        .route("/media/proxy/{signed_path}", get(proxy::<S, F, D, A>))
+        .with_state(state)
+}
+
+pub struct MediaState<
+    S: UrlSigner,
+    F: MediaFetcher,
+    D: FederationDomainRepository,
+    A: AuditLogRepository,
+> {
+    pub media_proxy_service: Arc<MediaProxyUsecase<S, F, D, A>>,
+}
+
+impl<S: UrlSigner, F: MediaFetcher, D: FederationDomainRepository, A: AuditLogRepository> Clone
+    for MediaState<S, F, D, A>
+{
+    fn clone(&self) -> Self {
+        Self {
+            media_proxy_service: self.media_proxy_service.clone(),
+        }
+    }
+}
+
+// handler function
+
+/// handler function for media proxy
+#[instrument(skip_all)]
+async fn proxy<
+    S: UrlSigner + Send + Sync,
+    F: MediaFetcher + Send + Sync,
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+>(
+    State(state): State<MediaState<S, F, D, A>>,
+    Path(signed_path): Path<String>,
+) -> impl IntoResponse {
+    match state.media_proxy_service.fetch(&signed_path).await {
+        Ok(media) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, media.content_type),
+                (header::CACHE_CONTROL, CACHE_CONTROL.to_string()),
+                (
+                    header::CONTENT_SECURITY_POLICY,
+                    "default-src 'none'; sandbox".to_string(),
+                ),
+            ],
+            media.body,
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
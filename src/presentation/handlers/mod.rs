@@ -1 +1,17 @@
+pub mod account_handler;
+pub mod admin_account_handler;
+pub mod alias_handler;
+pub mod audit_log_handler;
+pub mod blocklist_subscription_handler;
+pub mod email_verification_handler;
+pub mod endorsement_handler;
+pub mod federation_handler;
+pub mod ip_block_handler;
+pub mod legal_handler;
+pub mod media_handler;
+pub mod notification_preferences_handler;
+pub mod oauth_handler;
+pub mod problem_handler;
+pub mod report_handler;
+pub mod rule_handler;
 pub mod user_handler;
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        models::notification_preferences::{
+            DigestFrequency, NotificationPreferences, NotificationPreferencesUpdate,
+        },
+        repositories::{
+            notification_preferences_repository::NotificationPreferencesRepository,
+            user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+    },
+    usecase::{
+        auth_usecase::AuthUsecase, notification_preferences_usecase::NotificationPreferencesUsecase,
+    },
+};
+
+// Request
+
+/// json for notification preferences update request, omitted fields are left unchanged
+#[derive(Serialize, Deserialize)]
+pub struct NotificationPreferencesRequest {
+    pub email_on_follow: Option<bool>,
+    pub email_on_mention: Option<bool>,
+    pub email_on_report_resolved: Option<bool>,
+    /// `never`, `daily` or `weekly`
+    pub digest: Option<String>,
+}
+
+// Response
+
+/// json for notification preferences response
+#[derive(Serialize, Deserialize)]
+pub struct NotificationPreferencesResponse {
+    pub email_on_follow: bool,
+    pub email_on_mention: bool,
+    pub email_on_report_resolved: bool,
+    pub digest: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<NotificationPreferences> for NotificationPreferencesResponse {
+    fn from(preferences: NotificationPreferences) -> Self {
+        Self {
+            email_on_follow: preferences.email_on_follow,
+            email_on_mention: preferences.email_on_mention,
+            email_on_report_resolved: preferences.email_on_report_resolved,
+            digest: preferences.digest.as_str().to_string(),
+            updated_at: preferences.updated_at,
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Notification Preferences Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_notification_preferences_router<
+    N: NotificationPreferencesRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    preferences_service: NotificationPreferencesUsecase<N>,
+) -> Router {
+    let state = NotificationPreferencesState {
+        auth_service: Arc::new(auth_service),
+        preferences_service: Arc::new(preferences_service),
+    };
+
+    Router::new()
+        .route(
+            "/v1/notification_preferences",
+            get(get_preferences::<N, U, V>).patch(update_preferences::<N, U, V>),
+        )
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct NotificationPreferencesState<
+    N: NotificationPreferencesRepository,
+    U: UserRepository,
+    V: TokenVerifier,
+> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub preferences_service: Arc<NotificationPreferencesUsecase<N>>,
+}
+
+impl<
+    N: NotificationPreferencesRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+> AuthState for NotificationPreferencesState<N, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for reading the caller's preferences
+#[instrument(skip_all)]
+async fn get_preferences<
+    N: NotificationPreferencesRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<NotificationPreferencesState<N, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+) -> Response {
+    match state.preferences_service.get(&user).await {
+        Ok(preferences) => (
+            StatusCode::OK,
+            Json(NotificationPreferencesResponse::from(preferences)),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for updating the caller's preferences
+#[instrument(skip_all)]
+async fn update_preferences<
+    N: NotificationPreferencesRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<NotificationPreferencesState<N, U, V>>,
+    AuthenticatedUser(user): AuthenticatedUser,
+    Json(payload): Json<NotificationPreferencesRequest>,
+) -> Response {
+    let digest = match payload.digest.as_deref().map(DigestFrequency::parse) {
+        None => None,
+        Some(Ok(digest)) => Some(digest),
+        Some(Err(e)) => return error_response(e),
+    };
+    let update = NotificationPreferencesUpdate {
+        email_on_follow: payload.email_on_follow,
+        email_on_mention: payload.email_on_mention,
+        email_on_report_resolved: payload.email_on_report_resolved,
+        digest,
+    };
+
+    match state.preferences_service.update(&user, update).await {
+        Ok(preferences) => (
+            StatusCode::OK,
+            Json(NotificationPreferencesResponse::from(preferences)),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use axum::{
+    Form, Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        models::user::UserId,
+        repositories::user_repository::UserRepository,
+        services::token_service::{INTROSPECT_SCOPE, TokenVerifier},
+    },
+    presentation::{auth::authorize, error::error_response},
+    usecase::auth_usecase::{ActiveToken, AuthUsecase},
+};
+
+// Request
+
+/// form for token introspection request (RFC 7662)
+#[derive(Serialize, Deserialize)]
+pub struct IntrospectionRequest {
+    pub token: String,
+    /// Accepted for compatibility, only access tokens exist
+    pub token_type_hint: Option<String>,
+}
+
+// Response
+
+/// json for token introspection response, only `active` is sent for inactive tokens
+#[derive(Serialize, Deserialize)]
+pub struct IntrospectionResponse {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Account the token acts as
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+    /// Expiry as seconds since the epoch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+}
+
+impl From<Option<ActiveToken>> for IntrospectionResponse {
+    fn from(active: Option<ActiveToken>) -> Self {
+        match active {
+            Some(ActiveToken { token, user }) => Self {
+                active: true,
+                scope: Some(token.scopes.join(" ")),
+                sub: Some(user.id()),
+                username: Some(user.activity_id().username().to_string()),
+                token_type: Some(token.token_type.as_str().to_string()),
+                exp: Some(token.expires_at.timestamp()),
+            },
+            None => Self {
+                active: false,
+                scope: None,
+                sub: None,
+                username: None,
+                token_type: None,
+                exp: None,
+            },
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// OAuth Router
+
+/// function return Router object
+/// Suppose to be merged into main router (served outside of /api)
+pub fn create_oauth_router<
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+) -> Router {
+    let state = OAuthState {
+        auth_service: Arc::new(auth_service),
+    };
+
+    Router::new()
+        .route("/oauth/introspect", post(introspect::<U, V>))
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct OAuthState<U: UserRepository, V: TokenVerifier> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+}
+
+// handler function
+
+/// handler function for token introspection
+/// Only service credentials granted the `introspect` scope may call it, a login token is
+/// answered with 403 so one account cannot probe the tokens of another
+#[instrument(skip_all)]
+async fn introspect<U: UserRepository + Send + Sync, V: TokenVerifier>(
+    State(state): State<OAuthState<U, V>>,
+    headers: HeaderMap,
+    Form(payload): Form<IntrospectionRequest>,
+) -> Response {
+    if let Err(e) = authorize(&state.auth_service, &headers, INTROSPECT_SCOPE).await {
+        return error_response(e);
+    }
+
+    match state.auth_service.introspect(&payload.token).await {
+        Ok(active) => (StatusCode::OK, Json(IntrospectionResponse::from(active))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
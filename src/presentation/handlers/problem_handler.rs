@@ -0,0 +1,64 @@
+use axum::{
+    Json, Router,
+    extract::Path,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::presentation::error::{Problem, ProblemType};
+
+// Response
+
+/// json documenting an error category, served at its `type` URI
+#[derive(Serialize, Deserialize)]
+pub struct ProblemTypeResponse {
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    pub title: String,
+    pub status: u16,
+    pub description: String,
+}
+
+impl From<ProblemType> for ProblemTypeResponse {
+    fn from(kind: ProblemType) -> Self {
+        Self {
+            type_uri: kind.uri(),
+            title: kind.title().to_string(),
+            status: kind.status().as_u16(),
+            description: kind.description().to_string(),
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Problem Router
+
+/// function return Router object
+/// Mounted at the root, the `type` URIs of error responses are relative to it
+pub fn create_problem_router() -> Router {
+    Router::new()
+        .route("/problems", get(list_problem_types))
+        .route("/problems/{slug}", get(get_problem_type))
+}
+
+// handler function
+
+/// handler function for the list of error categories
+#[instrument(skip_all)]
+async fn list_problem_types() -> Response {
+    let response: Vec<ProblemTypeResponse> = ProblemType::ALL.into_iter().map(Into::into).collect();
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// handler function for the documentation of one error category
+#[instrument(skip_all, fields(slug = %slug))]
+async fn get_problem_type(Path(slug): Path<String>) -> Response {
+    match ProblemType::from_slug(&slug) {
+        Some(kind) => (StatusCode::OK, Json(ProblemTypeResponse::from(kind))).into_response(),
+        None => Problem::new(ProblemType::NotFound).into_response(),
+    }
+}
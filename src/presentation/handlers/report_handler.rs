@@ -0,0 +1,377 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        models::{
+            report::{Report, ReportCategory, ReportNote},
+            user::UserId,
+        },
+        repositories::{
+            audit_log_repository::AuditLogRepository, report_repository::ReportRepository,
+            rule_repository::RuleRepository, user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+    },
+    usecase::{
+        auth_usecase::AuthUsecase,
+        report_usecase::{Assignment, ReportUsecase},
+    },
+};
+
+// Request
+
+/// json for report creation request
+#[derive(Serialize, Deserialize)]
+pub struct CreateReportRequest {
+    pub account_id: UserId,
+    #[serde(default)]
+    pub comment: String,
+    /// spam, legal, violation or other (default)
+    pub category: Option<String>,
+    /// Rules allegedly violated, requires the violation category
+    #[serde(default)]
+    pub rule_ids: Vec<Uuid>,
+}
+
+/// json for report note request
+#[derive(Serialize, Deserialize)]
+pub struct ReportNoteRequest {
+    pub content: String,
+}
+
+/// query of the admin report list
+#[derive(Deserialize)]
+pub struct ReportListQuery {
+    pub resolved: Option<bool>,
+}
+
+// Response
+
+/// json for report response
+#[derive(Serialize, Deserialize)]
+pub struct ReportResponse {
+    pub id: Uuid,
+    pub category: String,
+    pub comment: String,
+    pub rule_ids: Vec<Uuid>,
+    pub account_id: UserId,
+    pub target_account_id: UserId,
+    pub assigned_account_id: Option<UserId>,
+    pub action_taken: bool,
+    pub action_taken_at: Option<DateTime<Utc>>,
+    pub action_taken_by_account_id: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Only present on the admin detail endpoint
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<ReportNoteResponse>>,
+}
+
+impl From<Report> for ReportResponse {
+    fn from(report: Report) -> Self {
+        Self {
+            id: report.id,
+            category: report.category.as_str().to_string(),
+            action_taken: report.is_resolved(),
+            comment: report.comment,
+            rule_ids: report.rule_ids,
+            account_id: report.reporter_id,
+            target_account_id: report.target_account_id,
+            assigned_account_id: report.assigned_account_id,
+            action_taken_at: report.action_taken_at,
+            action_taken_by_account_id: report.action_taken_by_id,
+            created_at: report.created_at,
+            updated_at: report.updated_at,
+            notes: None,
+        }
+    }
+}
+
+/// json for report note response
+#[derive(Serialize, Deserialize)]
+pub struct ReportNoteResponse {
+    pub id: Uuid,
+    pub account_id: UserId,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportNote> for ReportNoteResponse {
+    fn from(note: ReportNote) -> Self {
+        Self {
+            id: note.id,
+            account_id: note.author_id,
+            content: note.content,
+            created_at: note.created_at,
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Report Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_report_router<
+    R: ReportRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    A: AuditLogRepository + Send + Sync + 'static + Clone,
+    L: RuleRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    report_service: ReportUsecase<R, U, A, L>,
+) -> Router {
+    let state = ReportState {
+        auth_service: Arc::new(auth_service),
+        report_service: Arc::new(report_service),
+    };
+
+    Router::new()
+        .route("/v1/reports", post(create_report::<R, U, A, L, V>))
+        .route("/v1/admin/reports", get(list_reports::<R, U, A, L, V>))
+        .route("/v1/admin/reports/{id}", get(get_report::<R, U, A, L, V>))
+        .route(
+            "/v1/admin/reports/{id}/assign_to_self",
+            post(assign_to_self::<R, U, A, L, V>),
+        )
+        .route("/v1/admin/reports/{id}/unassign", post(unassign::<R, U, A, L, V>))
+        .route("/v1/admin/reports/{id}/resolve", post(resolve::<R, U, A, L, V>))
+        .route("/v1/admin/reports/{id}/reopen", post(reopen::<R, U, A, L, V>))
+        .route("/v1/admin/reports/{id}/notes", post(add_note::<R, U, A, L, V>))
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct ReportState<
+    R: ReportRepository,
+    U: UserRepository,
+    A: AuditLogRepository,
+    L: RuleRepository,
+    V: TokenVerifier,
+> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub report_service: Arc<ReportUsecase<R, U, A, L>>,
+}
+
+impl<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+> AuthState for ReportState<R, U, A, L, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for report creation
+#[instrument(skip_all)]
+async fn create_report<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<ReportState<R, U, A, L, V>>,
+    AuthenticatedUser(reporter): AuthenticatedUser,
+    Json(payload): Json<CreateReportRequest>,
+) -> Response {
+    let category = match payload.category.as_deref().map(ReportCategory::parse) {
+        None => ReportCategory::Other,
+        Some(Ok(category)) => category,
+        Some(Err(e)) => return error_response(e),
+    };
+
+    match state
+        .report_service
+        .create(
+            &reporter,
+            payload.account_id,
+            category,
+            payload.comment,
+            payload.rule_ids,
+        )
+        .await
+    {
+        Ok(report) => (StatusCode::OK, Json(ReportResponse::from(report))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for admin report list
+#[instrument(skip_all)]
+async fn list_reports<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<ReportState<R, U, A, L, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Query(query): Query<ReportListQuery>,
+) -> Response {
+    match state.report_service.list(&moderator, query.resolved).await {
+        Ok(reports) => {
+            let response: Vec<ReportResponse> = reports.into_iter().map(Into::into).collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for admin report detail
+#[instrument(skip_all, fields(report_id = %id))]
+async fn get_report<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<ReportState<R, U, A, L, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    match state.report_service.get(&moderator, id).await {
+        Ok((report, notes)) => {
+            let mut response = ReportResponse::from(report);
+            response.notes = Some(notes.into_iter().map(Into::into).collect());
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for assigning a report to the caller
+#[instrument(skip_all, fields(report_id = %id))]
+async fn assign_to_self<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<ReportState<R, U, A, L, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    report_response(
+        state
+            .report_service
+            .assign(&moderator, id, Assignment::ToSelf)
+            .await,
+    )
+}
+
+/// handler function for clearing the assignee of a report
+#[instrument(skip_all, fields(report_id = %id))]
+async fn unassign<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<ReportState<R, U, A, L, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    report_response(
+        state
+            .report_service
+            .assign(&moderator, id, Assignment::Unassigned)
+            .await,
+    )
+}
+
+/// handler function for resolving a report
+#[instrument(skip_all, fields(report_id = %id))]
+async fn resolve<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<ReportState<R, U, A, L, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    report_response(state.report_service.resolve(&moderator, id).await)
+}
+
+/// handler function for reopening a resolved report
+#[instrument(skip_all, fields(report_id = %id))]
+async fn reopen<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<ReportState<R, U, A, L, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    report_response(state.report_service.reopen(&moderator, id).await)
+}
+
+/// handler function for adding a moderator note to a report
+#[instrument(skip_all, fields(report_id = %id))]
+async fn add_note<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<ReportState<R, U, A, L, V>>,
+    AuthenticatedUser(moderator): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ReportNoteRequest>,
+) -> Response {
+    match state
+        .report_service
+        .add_note(&moderator, id, payload.content)
+        .await
+    {
+        Ok(note) => (StatusCode::CREATED, Json(ReportNoteResponse::from(note))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// helper function that render an updated report
+fn report_response(result: Result<Report, DomainError>) -> Response {
+    match result {
+        Ok(report) => (StatusCode::OK, Json(ReportResponse::from(report))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
+};
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        models::rule::Rule,
+        repositories::{
+            audit_log_repository::AuditLogRepository, rule_repository::RuleRepository,
+            user_repository::UserRepository,
+        },
+        services::token_service::TokenVerifier,
+    },
+    presentation::{
+        auth::{AuthState, AuthenticatedUser},
+        error::error_response,
+    },
+    usecase::{auth_usecase::AuthUsecase, rule_usecase::RuleUsecase},
+};
+
+// Request
+
+/// json for rule creation / update request
+#[derive(Serialize, Deserialize)]
+pub struct RuleRequest {
+    pub text: String,
+    #[serde(default)]
+    pub hint: String,
+    #[serde(default)]
+    pub position: i32,
+}
+
+// Response
+
+/// json for rule response
+#[derive(Serialize, Deserialize)]
+pub struct RuleResponse {
+    pub id: Uuid,
+    pub text: String,
+    pub hint: String,
+    pub position: i32,
+}
+
+impl From<Rule> for RuleResponse {
+    fn from(rule: Rule) -> Self {
+        Self {
+            id: rule.id,
+            text: rule.text,
+            hint: rule.hint,
+            position: rule.position,
+        }
+    }
+}
+
+/* Router Function and Handler Function */
+
+// Rule Router
+
+/// function return Router object
+/// Suppose to be nested by main router
+pub fn create_rule_router<
+    L: RuleRepository + Send + Sync + 'static + Clone,
+    A: AuditLogRepository + Send + Sync + 'static + Clone,
+    U: UserRepository + Send + Sync + 'static + Clone,
+    V: TokenVerifier + 'static + Clone,
+>(
+    auth_service: AuthUsecase<U, V>,
+    rule_service: RuleUsecase<L, A>,
+) -> Router {
+    let state = RuleState {
+        auth_service: Arc::new(auth_service),
+        rule_service: Arc::new(rule_service),
+    };
+
+    Router::new()
+        .route("/v1/instance/rules", get(list_rules::<L, A, U, V>))
+        .route("/v1/admin/rules", post(create_rule::<L, A, U, V>))
+        .route(
+            "/v1/admin/rules/{id}",
+            put(update_rule::<L, A, U, V>).delete(delete_rule::<L, A, U, V>),
+        )
+        .with_state(state)
+}
+
+#[derive(Clone)]
+pub struct RuleState<
+    L: RuleRepository,
+    A: AuditLogRepository,
+    U: UserRepository,
+    V: TokenVerifier,
+> {
+    pub auth_service: Arc<AuthUsecase<U, V>>,
+    pub rule_service: Arc<RuleUsecase<L, A>>,
+}
+
+impl<
+    L: RuleRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+> AuthState for RuleState<L, A, U, V>
+{
+    type Users = U;
+    type Verifier = V;
+
+    fn auth_service(&self) -> &AuthUsecase<U, V> {
+        &self.auth_service
+    }
+}
+
+// handler function
+
+/// handler function for the public rule list
+#[instrument(skip_all)]
+async fn list_rules<
+    L: RuleRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<RuleState<L, A, U, V>>,
+) -> Response {
+    match state.rule_service.list().await {
+        Ok(rules) => {
+            let response: Vec<RuleResponse> = rules.into_iter().map(Into::into).collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for rule creation
+#[instrument(skip_all)]
+async fn create_rule<
+    L: RuleRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<RuleState<L, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Json(payload): Json<RuleRequest>,
+) -> Response {
+    match state
+        .rule_service
+        .create(&admin, payload.text, payload.hint, payload.position)
+        .await
+    {
+        Ok(rule) => (StatusCode::CREATED, Json(RuleResponse::from(rule))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for rule update
+#[instrument(skip_all, fields(rule_id = %id))]
+async fn update_rule<
+    L: RuleRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<RuleState<L, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<RuleRequest>,
+) -> Response {
+    match state
+        .rule_service
+        .update(&admin, id, payload.text, payload.hint, payload.position)
+        .await
+    {
+        Ok(rule) => (StatusCode::OK, Json(RuleResponse::from(rule))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// handler function for rule deletion
+#[instrument(skip_all, fields(rule_id = %id))]
+async fn delete_rule<
+    L: RuleRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    V: TokenVerifier,
+>(
+    State(state): State<RuleState<L, A, U, V>>,
+    AuthenticatedUser(admin): AuthenticatedUser,
+    Path(id): Path<Uuid>,
+) -> Response {
+    match state.rule_service.delete(&admin, id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
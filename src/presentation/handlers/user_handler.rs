@@ -2,23 +2,40 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
+        error::{DomainError, RepositoryError},
         repositories::{
             credential_repository::CredentialRepository,
-            user_registration_repository::UserRegistrationRepository,
+            email_verification_repository::EmailVerificationRepository,
+            legal_document_repository::LegalDocumentRepository,
+            refresh_token_repository::RefreshTokenRepository, unit_of_work::UnitOfWork,
             user_repository::UserRepository,
         },
-        services::{password_service::PasswordHasher, token_service::TokenGenerator},
+        services::{
+            email_service::{EmailSender, MailDomainVerifier},
+            password_service::PasswordHasher,
+            spam_service::SpamChecker, token_service::TokenGenerator,
+        },
+    },
+    presentation::{error::error_response, ip_block::ApprovalRequired},
+    usecase::{
+        login_usecase::{LoginResult, LoginUsecase},
+        register_user_usecase::{RegisterUserUsecase, RegistrationOutcome},
     },
-    usecase::{login_usecase::LoginUsecase, register_user_usecase::RegisterUserUsecase},
 };
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{
+    Extension, Json, Router, extract::State, http::StatusCode, response::IntoResponse,
+    routing::post,
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use tracing::instrument;
 
 // Request
 
 /// json for login request
 #[derive(Serialize, Deserialize)]
 pub struct LoginRequest {
+    /// Username or registered email address
     pub user_id: String,
     pub password: String,
 }
@@ -30,6 +47,15 @@ pub struct RegisterRequest {
     pub password: String,
     pub mail_address: String,
     pub display_name: String,
+    /// Acceptance of the current terms of service and privacy policy
+    #[serde(default)]
+    pub agreement: bool,
+}
+
+/// json for access token refresh request
+#[derive(Serialize, Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
 }
 
 // Response
@@ -38,14 +64,44 @@ pub struct RegisterRequest {
 #[derive(Serialize, Deserialize)]
 pub struct LoginResponse {
     pub token: String,
+    pub token_type: String,
+    /// Seconds until the token expires
+    pub expires_in: i64,
+    pub scope: String,
+    /// Single use, exchanged at `/api/token/refresh` for the next access token
+    pub refresh_token: String,
     pub user: UserInfo,
 }
 
+impl From<LoginResult> for LoginResponse {
+    fn from(result: LoginResult) -> Self {
+        Self {
+            token_type: result.token.token_type.as_str().to_string(),
+            expires_in: result.token.expires_in(),
+            scope: result.token.scopes.join(" "),
+            token: result.token.value,
+            refresh_token: result.refresh_token,
+            user: result.user.into(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct UserInfo {
     pub id: String,
     pub acct: String,
     pub display_name: String,
+    /// Bio of the account
+    pub note: String,
+    pub avatar: Option<String>,
+    pub header: Option<String>,
+    /// `YYYY-MM-DD`, or `--MM-DD` without the year, only when the owner shows it
+    pub birthday: Option<String>,
+    /// Only when the owner shows it
+    pub location: Option<String>,
+    /// Vouched for by an admin
+    pub verified: bool,
+    pub created_at: DateTime<Utc>,
 }
 
 impl From<crate::domain::models::user::User> for UserInfo {
@@ -80,6 +136,13 @@ impl From<crate::domain::models::user::User> for UserInfo {
             id: user.id().to_string(),
             acct,
             display_name: user.display_name().to_string(),
+            note: user.summary().to_string(),
+            avatar: user.icon_url().map(str::to_string),
+            header: user.header_url().map(str::to_string),
+            birthday: user.details().public_birthday(),
+            location: user.details().public_location().map(str::to_string),
+            verified: user.verified_at().is_some(),
+            created_at: user.created_at(),
         }
     }
 }
@@ -98,16 +161,21 @@ fn extract_host(url: &str) -> Option<String> {
 
 /// function return Router object
 /// Suppose to be nested by main router
-
 pub fn create_user_router<
     C: CredentialRepository + Send + Sync + 'static + Clone,
     U: UserRepository + Send + Sync + 'static + Clone,
-    R: UserRegistrationRepository + Send + Sync + 'static + Clone,
+    W: UnitOfWork + Send + Sync + 'static + Clone,
     P: PasswordHasher + Send + Sync + 'static + Clone,
     T: TokenGenerator + Send + Sync + 'static + Clone,
+    E: EmailSender + Send + Sync + 'static + Clone,
+    S: SpamChecker + 'static,
+    D: LegalDocumentRepository + Send + Sync + 'static + Clone,
+    M: MailDomainVerifier + 'static,
+    R: RefreshTokenRepository + Send + Sync + 'static + Clone,
+    V: EmailVerificationRepository + Send + Sync + 'static + Clone,
 >(
-    login_service: LoginUsecase<C, U, P, T>,
-    register_service: RegisterUserUsecase<R, P, T>,
+    login_service: LoginUsecase<C, U, P, T, E, R, V>,
+    register_service: RegisterUserUsecase<W, P, E, S, D, M>,
 ) -> Router {
     let state = AppState {
         login_service: Arc::new(login_service),
@@ -115,8 +183,9 @@ pub fn create_user_router<
     };
 
     Router::new()
-        .route("/login", post(login::<C, U, P, T>))
-        .route("/register", post(register::<R, P, T>))
+        .route("/login", post(login::<C, U, P, T, E, R, V>))
+        .route("/token/refresh", post(refresh::<C, U, P, T, E, R, V>))
+        .route("/register", post(register::<W, P, E, S, D, M>))
         .with_state(state)
 }
 
@@ -124,24 +193,49 @@ pub fn create_user_router<
 pub struct AppState<
     C: CredentialRepository,
     U: UserRepository,
-    R: UserRegistrationRepository,
+    W: UnitOfWork,
     P: PasswordHasher,
     T: TokenGenerator,
+    E: EmailSender,
+    S: SpamChecker,
+    D: LegalDocumentRepository,
+    M: MailDomainVerifier,
+    R: RefreshTokenRepository,
+    V: EmailVerificationRepository,
 > {
-    pub login_service: Arc<LoginUsecase<C, U, P, T>>,
-    pub register_service: Arc<RegisterUserUsecase<R, P, T>>,
+    pub login_service: Arc<LoginUsecase<C, U, P, T, E, R, V>>,
+    pub register_service: Arc<RegisterUserUsecase<W, P, E, S, D, M>>,
 }
 
 // handler function
 
 /// handler function for login
+#[instrument(skip_all)]
+#[allow(clippy::type_complexity)]
 async fn login<
     C: CredentialRepository + Send + Sync,
     U: UserRepository + Send + Sync,
     P: PasswordHasher + Send + Sync,
     T: TokenGenerator + Send + Sync,
+    E: EmailSender + Send + Sync,
+    R: RefreshTokenRepository + Send + Sync,
+    V: EmailVerificationRepository + Send + Sync,
 >(
-    State(state): State<AppState<C, U, impl UserRegistrationRepository, P, T>>,
+    State(state): State<
+        AppState<
+            C,
+            U,
+            impl UnitOfWork,
+            P,
+            T,
+            E,
+            impl SpamChecker,
+            impl LegalDocumentRepository,
+            impl MailDomainVerifier,
+            R,
+            V,
+        >,
+    >,
     Json(payload): Json<LoginRequest>,
 ) -> impl IntoResponse {
     match state
@@ -150,23 +244,87 @@ async fn login<
         .await
     {
         Ok(result) => {
-            let response = LoginResponse {
-                token: result.token,
-                user: result.user.into(),
-            };
+            let response = LoginResponse::from(result);
             (StatusCode::OK, Json(response)).into_response()
         }
-        Err(_) => (StatusCode::UNAUTHORIZED, Json("Authentication failed")).into_response(),
+        Err(
+            e @ (DomainError::Repository(RepositoryError::Unavailable)
+            | DomainError::AccountDisabled
+            | DomainError::EmailNotVerified
+            | DomainError::PendingDeletion(_)),
+        ) => error_response(e),
+        Err(e) => {
+            tracing::info!(error = %e, "login rejected");
+            error_response(DomainError::AuthenticationFailed)
+        }
+    }
+}
+
+/// handler function for the exchange of a refresh token
+#[instrument(skip_all)]
+#[allow(clippy::type_complexity)]
+async fn refresh<
+    C: CredentialRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    P: PasswordHasher + Send + Sync,
+    T: TokenGenerator + Send + Sync,
+    E: EmailSender + Send + Sync,
+    R: RefreshTokenRepository + Send + Sync,
+    V: EmailVerificationRepository + Send + Sync,
+>(
+    State(state): State<
+        AppState<
+            C,
+            U,
+            impl UnitOfWork,
+            P,
+            T,
+            E,
+            impl SpamChecker,
+            impl LegalDocumentRepository,
+            impl MailDomainVerifier,
+            R,
+            V,
+        >,
+    >,
+    Json(payload): Json<RefreshRequest>,
+) -> impl IntoResponse {
+    match state.login_service.refresh(&payload.refresh_token).await {
+        Ok(result) => {
+            let response = LoginResponse::from(result);
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => error_response(e),
     }
 }
 
 /// handler function for register
+#[instrument(skip_all)]
+#[allow(clippy::type_complexity)]
 async fn register<
-    R: UserRegistrationRepository + Send + Sync,
+    W: UnitOfWork + Send + Sync,
     P: PasswordHasher + Send + Sync,
-    T: TokenGenerator + Send + Sync,
+    E: EmailSender + Send + Sync,
+    S: SpamChecker,
+    D: LegalDocumentRepository + Send + Sync,
+    M: MailDomainVerifier,
 >(
-    State(state): State<AppState<impl CredentialRepository, impl UserRepository, R, P, T>>,
+    State(state): State<
+        AppState<
+            impl CredentialRepository,
+            impl UserRepository,
+            W,
+            P,
+            impl TokenGenerator,
+            E,
+            S,
+            D,
+            M,
+            impl RefreshTokenRepository,
+            impl EmailVerificationRepository,
+        >,
+    >,
+    approval: Option<Extension<ApprovalRequired>>,
     Json(payload): Json<RegisterRequest>,
 ) -> impl IntoResponse {
     match state
@@ -176,16 +334,19 @@ async fn register<
             payload.display_name,
             payload.password,
             payload.mail_address,
+            payload.agreement,
+            approval.is_some(),
         )
         .await
     {
-        Ok(result) => {
-            let response = LoginResponse {
-                token: result.token,
-                user: result.user.into(),
-            };
-            (StatusCode::CREATED, Json(response)).into_response()
+        // no token until the address is verified, and the account approved when held
+        Ok(
+            RegistrationOutcome::PendingVerification(user)
+            | RegistrationOutcome::PendingApproval(user),
+        ) => (StatusCode::ACCEPTED, Json(UserInfo::from(user))).into_response(),
+        Err(e) => {
+            tracing::info!(error = %e, "registration rejected");
+            error_response(e)
         }
-        Err(_) => (StatusCode::BAD_REQUEST, Json("Registration failed")).into_response(),
     }
 }
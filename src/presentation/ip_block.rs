@@ -0,0 +1,109 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, Method},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{
+    domain::{
+        error::DomainError,
+        models::ip_block::IpBlockSeverity,
+        repositories::{
+            audit_log_repository::AuditLogRepository, ip_block_repository::IpBlockRepository,
+        },
+    },
+    presentation::error::error_response,
+    usecase::ip_block_usecase::IpBlockUsecase,
+};
+
+/// Path of the sign-up endpoint, the only one affected by sign-up blocks
+const SIGN_UP_PATH: &str = "/api/register";
+
+/// Request extension telling the register handler the account needs moderator approval
+#[derive(Debug, Clone, Copy)]
+pub struct ApprovalRequired;
+
+/// State of the IP block middleware
+#[derive(Clone)]
+pub struct IpBlockGuard<B: IpBlockRepository, A: AuditLogRepository> {
+    pub ip_block_service: Arc<IpBlockUsecase<B, A>>,
+    /// Take the client address from `X-Forwarded-For` (only behind a trusted reverse proxy)
+    pub trust_proxy_headers: bool,
+}
+
+/// middleware function that apply the IP block matching the client address
+pub async fn enforce_ip_blocks<
+    B: IpBlockRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+>(
+    State(guard): State<IpBlockGuard<B, A>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(ip) = client_ip(&request, guard.trust_proxy_headers) else {
+        return next.run(request).await;
+    };
+
+    let found = match guard.ip_block_service.evaluate(ip).await {
+        Ok(found) => found,
+        Err(e) => {
+            // fail open, an unreachable database must not lock everybody out
+            tracing::error!(error = %e, "failed to evaluate ip blocks");
+            None
+        }
+    };
+    let Some(found) = found else {
+        return next.run(request).await;
+    };
+
+    let sign_up = request.method() == Method::POST && request.uri().path() == SIGN_UP_PATH;
+    if found.severity == IpBlockSeverity::NoAccess || sign_up {
+        tracing::warn!(
+            ip_block_id = %found.id,
+            severity = found.severity.as_str(),
+            %ip,
+            path = request.uri().path(),
+            "request matched ip block"
+        );
+    }
+
+    match found.severity {
+        IpBlockSeverity::NoAccess => error_response(DomainError::Forbidden),
+        IpBlockSeverity::SignUpBlock if sign_up => error_response(DomainError::Forbidden),
+        IpBlockSeverity::SignUpRequiresApproval if sign_up => {
+            request.extensions_mut().insert(ApprovalRequired);
+            next.run(request).await
+        }
+        _ => next.run(request).await,
+    }
+}
+
+/// helper function that find the address of the client
+fn client_ip(request: &Request, trust_proxy_headers: bool) -> Option<IpAddr> {
+    if trust_proxy_headers && let Some(ip) = forwarded_for(request.headers()) {
+        return Some(ip);
+    }
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(peer)| peer.ip())
+}
+
+/// helper function that read the original client of an `X-Forwarded-For` header
+fn forwarded_for(headers: &HeaderMap) -> Option<IpAddr> {
+    headers
+        .get("x-forwarded-for")?
+        .to_str()
+        .ok()?
+        .split(',')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
@@ -0,0 +1,244 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderValue, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use opentelemetry::{KeyValue, global, metrics::Counter};
+
+use crate::{
+    domain::services::load_service::LoadGauge,
+    presentation::error::{Problem, ProblemType},
+};
+
+/// Seconds a refused client is told to wait before retrying
+const RETRY_AFTER_SECS: &str = "1";
+
+/// Why a request was refused, the `reason` attribute of the shed metric
+#[derive(Debug, Clone, Copy)]
+enum ShedReason {
+    InFlight,
+    DatabasePool,
+    JobQueue,
+}
+
+impl ShedReason {
+    fn as_str(self) -> &'static str {
+        match self {
+            ShedReason::InFlight => "in_flight",
+            ShedReason::DatabasePool => "database_pool",
+            ShedReason::JobQueue => "job_queue",
+        }
+    }
+}
+
+/// State of the load shedding middleware
+#[derive(Clone)]
+pub struct LoadShedGuard<D: LoadGauge, Q: LoadGauge> {
+    database: D,
+    job_queue: Q,
+    max_in_flight: usize,
+    max_in_flight_db_saturated: usize,
+    in_flight: Arc<AtomicUsize>,
+    shed_requests: Counter<u64>,
+}
+
+impl<D: LoadGauge, Q: LoadGauge> LoadShedGuard<D, Q> {
+    /// Up to `max_in_flight` requests are handled at once,
+    /// `max_in_flight_db_saturated` while every database connection is busy
+    pub fn new(
+        database: D,
+        job_queue: Q,
+        max_in_flight: usize,
+        max_in_flight_db_saturated: usize,
+    ) -> Self {
+        let shed_requests = global::meter("cascade")
+            .u64_counter("http.server.shed_requests")
+            .with_description("Requests answered 503 because the server was saturated")
+            .build();
+        Self {
+            database,
+            job_queue,
+            max_in_flight,
+            max_in_flight_db_saturated,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            shed_requests,
+        }
+    }
+
+    /// `in_flight` counts the request being checked
+    fn shed_reason(&self, in_flight: usize, request: &Request) -> Option<ShedReason> {
+        if in_flight > self.max_in_flight {
+            Some(ShedReason::InFlight)
+        } else if in_flight > self.max_in_flight_db_saturated && self.database.is_saturated() {
+            Some(ShedReason::DatabasePool)
+        } else if !request.method().is_safe() && self.job_queue.is_saturated() {
+            // reads never enqueue a job
+            Some(ShedReason::JobQueue)
+        } else {
+            None
+        }
+    }
+}
+
+/// Counts a request in flight until it completes or is cancelled
+struct InFlight(Arc<AtomicUsize>);
+
+impl InFlight {
+    /// Returns the guard and the number of requests in flight, this one included
+    fn enter(counter: &Arc<AtomicUsize>) -> (Self, usize) {
+        let count = counter.fetch_add(1, Ordering::Relaxed) + 1;
+        (Self(counter.clone()), count)
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// middleware function that refuse requests right away while the server or a backend is saturated
+pub async fn shed_load<D: LoadGauge, Q: LoadGauge>(
+    State(guard): State<LoadShedGuard<D, Q>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (_in_flight, count) = InFlight::enter(&guard.in_flight);
+
+    if let Some(reason) = guard.shed_reason(count, &request) {
+        guard
+            .shed_requests
+            .add(1, &[KeyValue::new("reason", reason.as_str())]);
+        tracing::debug!(
+            reason = reason.as_str(),
+            path = request.uri().path(),
+            "request shed"
+        );
+        let mut response = Problem::new(ProblemType::Overloaded).into_response();
+        response.headers_mut().insert(
+            header::RETRY_AFTER,
+            HeaderValue::from_static(RETRY_AFTER_SECS),
+        );
+        return response;
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::Body,
+        http::{Method, StatusCode},
+        middleware,
+        routing::get,
+    };
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    /// Gauge stuck at the given state
+    #[derive(Clone)]
+    struct StubGauge(bool);
+
+    impl LoadGauge for StubGauge {
+        fn is_saturated(&self) -> bool {
+            self.0
+        }
+    }
+
+    /// # Description
+    /// Guard letting 10 requests in, 2 while the database is saturated
+    fn stub_guard(
+        database_saturated: bool,
+        queue_saturated: bool,
+    ) -> LoadShedGuard<StubGauge, StubGauge> {
+        LoadShedGuard::new(
+            StubGauge(database_saturated),
+            StubGauge(queue_saturated),
+            10,
+            2,
+        )
+    }
+
+    /// # Description
+    /// Request to `/` with the given method
+    fn request(method: Method) -> Request {
+        Request::builder()
+            .method(method)
+            .uri("/")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_shed_reason_idle_positive() {
+        let guard = stub_guard(false, false);
+
+        assert!(guard.shed_reason(10, &request(Method::POST)).is_none());
+    }
+
+    #[test]
+    fn test_shed_reason_in_flight_negative() {
+        let guard = stub_guard(false, false);
+
+        assert!(matches!(
+            guard.shed_reason(11, &request(Method::GET)),
+            Some(ShedReason::InFlight)
+        ));
+    }
+
+    #[test]
+    fn test_shed_reason_database_saturated_negative() {
+        let guard = stub_guard(true, false);
+
+        // the smaller cap applies only while the pool is saturated
+        assert!(guard.shed_reason(2, &request(Method::GET)).is_none());
+        assert!(matches!(
+            guard.shed_reason(3, &request(Method::GET)),
+            Some(ShedReason::DatabasePool)
+        ));
+        assert!(
+            stub_guard(false, false)
+                .shed_reason(3, &request(Method::GET))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_shed_reason_job_queue_negative() {
+        let guard = stub_guard(false, true);
+
+        assert!(matches!(
+            guard.shed_reason(1, &request(Method::POST)),
+            Some(ShedReason::JobQueue)
+        ));
+        // reads never enqueue a job
+        assert!(guard.shed_reason(1, &request(Method::GET)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_shed_load_response_negative() {
+        // no request fits under a cap of zero
+        let guard = LoadShedGuard::new(StubGauge(false), StubGauge(false), 0, 0);
+        let shed = middleware::from_fn_with_state(guard, shed_load::<StubGauge, StubGauge>);
+        let router = Router::new().route("/", get(|| async { "ok" })).layer(shed);
+
+        let response = router.oneshot(request(Method::GET)).await.unwrap();
+
+        assert_eq!(StatusCode::SERVICE_UNAVAILABLE, response.status());
+        assert_eq!("1", response.headers()[header::RETRY_AFTER]);
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let problem: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!("/problems/overloaded", problem["type"]);
+        assert_eq!(503, problem["status"]);
+    }
+}
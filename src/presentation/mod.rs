@@ -1 +1,5 @@
+pub mod auth;
+pub mod error;
+pub mod ip_block;
+pub mod load_shed;
 pub mod handlers;
@@ -0,0 +1,97 @@
+use opentelemetry::{KeyValue, global, trace::TracerProvider as _};
+use opentelemetry_otlp::{MetricExporter, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    Resource,
+    metrics::{PeriodicReader, SdkMeterProvider},
+    propagation::TraceContextPropagator,
+    runtime,
+    trace::TracerProvider,
+};
+use tracing_subscriber::{EnvFilter, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+
+use crate::config::{Config, LogFormat};
+
+const SERVICE_NAME: &str = "cascade";
+
+/// Providers kept alive for the lifetime of the process
+/// Call `shutdown` before exiting so buffered spans and metrics are exported
+pub struct Telemetry {
+    tracer_provider: Option<TracerProvider>,
+    meter_provider: Option<SdkMeterProvider>,
+}
+
+impl Telemetry {
+    pub fn shutdown(self) {
+        if let Some(provider) = self.tracer_provider
+            && let Err(e) = provider.shutdown()
+        {
+            eprintln!("Failed to shut down tracer provider: {}", e);
+        }
+        if let Some(provider) = self.meter_provider
+            && let Err(e) = provider.shutdown()
+        {
+            eprintln!("Failed to shut down meter provider: {}", e);
+        }
+    }
+}
+
+/// Install the global tracing subscriber
+/// Verbosity is controlled by `RUST_LOG` (default: `info`, SQL statements at `debug`)
+/// When an OTLP endpoint is configured, spans and metrics are also exported there
+pub fn init(config: &Config) -> Result<Telemetry, Box<dyn std::error::Error>> {
+    let (tracer_provider, meter_provider) = match &config.otlp_endpoint {
+        Some(endpoint) => {
+            let (tracer_provider, meter_provider) = otlp_providers(endpoint)?;
+            (Some(tracer_provider), Some(meter_provider))
+        }
+        None => (None, None),
+    };
+
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let otel_layer = tracer_provider
+        .as_ref()
+        .map(|provider| tracing_opentelemetry::layer().with_tracer(provider.tracer(SERVICE_NAME)));
+    let registry = tracing_subscriber::registry().with(filter).with(otel_layer);
+
+    match config.log_format {
+        LogFormat::Pretty => registry.with(fmt::layer().pretty()).init(),
+        LogFormat::Json => registry.with(fmt::layer().json()).init(),
+    }
+
+    Ok(Telemetry {
+        tracer_provider,
+        meter_provider,
+    })
+}
+
+/// helper function that build OTLP (gRPC) exporters and register them globally
+fn otlp_providers(
+    endpoint: &str,
+) -> Result<(TracerProvider, SdkMeterProvider), Box<dyn std::error::Error>> {
+    let resource = Resource::new(vec![KeyValue::new("service.name", SERVICE_NAME)]);
+
+    let span_exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+    let tracer_provider = TracerProvider::builder()
+        .with_batch_exporter(span_exporter, runtime::Tokio)
+        .with_resource(resource.clone())
+        .build();
+
+    let metric_exporter = MetricExporter::builder()
+        .with_tonic()
+        .with_endpoint(endpoint)
+        .build()?;
+    let meter_provider = SdkMeterProvider::builder()
+        .with_reader(PeriodicReader::builder(metric_exporter, runtime::Tokio).build())
+        .with_resource(resource)
+        .build();
+
+    // W3C trace context, so outbound requests can carry the current span
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    global::set_tracer_provider(tracer_provider.clone());
+    global::set_meter_provider(meter_provider.clone());
+
+    Ok((tracer_provider, meter_provider))
+}
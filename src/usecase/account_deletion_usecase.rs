@@ -0,0 +1,205 @@
+use chrono::{DateTime, Utc};
+use tracing::instrument;
+
+use crate::domain::{
+    error::DomainError,
+    models::user::User,
+    repositories::{credential_repository::CredentialRepository, user_repository::UserRepository},
+    services::password_service::PasswordHasher,
+};
+
+/// Deletion of an account by its owner, reversible for `DELETION_GRACE_DAYS`
+/// The account is hidden like any deleted account until purged or reactivated
+pub struct AccountDeletionUsecase<C: CredentialRepository, U: UserRepository, P: PasswordHasher> {
+    credential_repository: C,
+    user_repository: U,
+    password_hasher: P,
+}
+
+impl<
+    C: CredentialRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    P: PasswordHasher + Send + Sync,
+> AccountDeletionUsecase<C, U, P>
+{
+    pub fn new(credential_repository: C, user_repository: U, password_hasher: P) -> Self {
+        Self {
+            credential_repository,
+            user_repository,
+            password_hasher,
+        }
+    }
+
+    /// Delete the caller's account once the password is confirmed, returning when it is purged
+    #[instrument(skip(self, user, password), fields(user_id = %user.id()))]
+    pub async fn request(&self, user: &User, password: &str) -> Result<DateTime<Utc>, DomainError> {
+        self.verify_password(user, password).await?;
+
+        let mut user = user.clone();
+        user.request_deletion()?;
+        self.user_repository.mark_deleted(&user).await?;
+
+        let purge_at = user.purge_at().ok_or(DomainError::Forbidden)?;
+        tracing::info!(%purge_at, "account deletion requested");
+        Ok(purge_at)
+    }
+
+    /// Undo the deletion of `username` during the grace period
+    #[instrument(skip(self, password))]
+    pub async fn reactivate(&self, username: &str, password: &str) -> Result<User, DomainError> {
+        // same answer, and time, as a wrong password, whether the account exists is not disclosed
+        let mut user = match self.user_repository.find_by_username(username).await? {
+            Some(user) if user.can_reactivate() => user,
+            _ => {
+                self.password_hasher.verify_dummy(password);
+                return Err(DomainError::AuthenticationFailed);
+            }
+        };
+        self.verify_password(&user, password).await?;
+
+        user.reactivate()?;
+        self.user_repository.mark_deleted(&user).await?;
+
+        tracing::info!(user_id = %user.id(), "account reactivated");
+        Ok(user)
+    }
+
+    /// helper function that check the password of the account
+    async fn verify_password(&self, user: &User, password: &str) -> Result<(), DomainError> {
+        let credential = self
+            .credential_repository
+            .get_credential(user.activity_id())
+            .await?;
+        if credential.user_id() != user.id() {
+            return Err(DomainError::AuthenticationFailed);
+        }
+        let is_valid = self
+            .password_hasher
+            .verify(password, credential.password_hash())?;
+        credential.validate(is_valid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::{
+            fixtures::{CredentialBuilder, UserBuilder},
+            user::DELETION_GRACE_DAYS,
+        },
+        infrastructure::{
+            argon2_password_hasher::Argon2PasswordHasher,
+            in_memory::{
+                credential_repository::InMemoryCredentialRepository,
+                user_repository::InMemoryUserRepository,
+            },
+        },
+    };
+
+    type TestAccountDeletionUsecase = AccountDeletionUsecase<
+        InMemoryCredentialRepository,
+        InMemoryUserRepository,
+        Argon2PasswordHasher,
+    >;
+
+    /// # Description
+    /// Usecase over in-memory repositories seeded with "test_user" / "test_password"
+    fn setup() -> (TestAccountDeletionUsecase, InMemoryUserRepository, User) {
+        let password_hasher = Argon2PasswordHasher::new();
+        let user = UserBuilder::default().build();
+        let credential = CredentialBuilder::for_user(&user)
+            .password_hash(password_hasher.hash("test_password").unwrap())
+            .build();
+
+        let user_repository = InMemoryUserRepository::new();
+        user_repository.insert(user.clone()).unwrap();
+        let credential_repository = InMemoryCredentialRepository::new();
+        credential_repository.insert(credential).unwrap();
+
+        let usecase = AccountDeletionUsecase::new(
+            credential_repository,
+            user_repository.clone(),
+            password_hasher,
+        );
+        (usecase, user_repository, user)
+    }
+
+    #[tokio::test]
+    async fn test_request_deletion_positive() {
+        let (usecase, user_repository, user) = setup();
+
+        let purge_at = usecase.request(&user, "test_password").await.unwrap();
+
+        let stored = user_repository
+            .find_by_id(user.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.is_deleted());
+        assert!(stored.can_reactivate());
+        assert_eq!(Some(purge_at), stored.purge_at());
+    }
+
+    #[tokio::test]
+    async fn test_request_deletion_wrong_password_negative() {
+        let (usecase, user_repository, user) = setup();
+
+        let result = usecase.request(&user, "wrong_password").await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+        let stored = user_repository
+            .find_by_id(user.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.is_deleted());
+    }
+
+    #[tokio::test]
+    async fn test_reactivate_positive() {
+        let (usecase, user_repository, user) = setup();
+        usecase.request(&user, "test_password").await.unwrap();
+
+        let reactivated = usecase
+            .reactivate("test_user", "test_password")
+            .await
+            .unwrap();
+
+        assert!(!reactivated.is_deleted());
+        let stored = user_repository
+            .find_by_id(user.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.is_deleted());
+    }
+
+    #[tokio::test]
+    async fn test_reactivate_moderator_deletion_negative() {
+        let (usecase, user_repository, user) = setup();
+        let mut deleted = user.clone();
+        deleted.delete();
+        user_repository.mark_deleted(&deleted).await.unwrap();
+
+        let result = usecase.reactivate("test_user", "test_password").await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_reactivate_after_grace_period_negative() {
+        let (usecase, user_repository, user) = setup();
+        let expired = user
+            .clone()
+            .with_deleted_at(Some(
+                Utc::now() - chrono::Duration::days(DELETION_GRACE_DAYS + 1),
+            ))
+            .with_self_deleted(true);
+        user_repository.mark_deleted(&expired).await.unwrap();
+
+        let result = usecase.reactivate("test_user", "test_password").await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+}
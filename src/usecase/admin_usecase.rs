@@ -0,0 +1,366 @@
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::DomainError,
+    models::{
+        audit_log::{AuditLogEntry, AuditTarget},
+        credential::HashedPassword,
+        email::EmailAddress,
+        user::{ActivityId, Role, User, Username},
+    },
+    repositories::{
+        audit_log_repository::AuditLogRepository, credential_repository::CredentialRepository,
+        refresh_token_repository::RefreshTokenRepository,
+        user_registration_repository::UserRegistrationRepository,
+    },
+    services::password_service::PasswordHasher,
+};
+
+/// Operational tasks run by the instance operator (admin CLI)
+/// Recorded in the audit log without an actor
+pub struct AdminUsecase<
+    R: UserRegistrationRepository,
+    C: CredentialRepository,
+    P: PasswordHasher,
+    A: AuditLogRepository,
+    T: RefreshTokenRepository,
+> {
+    registration_repository: R,
+    credential_repository: C,
+    password_hasher: P,
+    audit_log_repository: A,
+    refresh_token_repository: T,
+    /// Host the activity ids of local accounts are minted under
+    instance_host: String,
+}
+
+impl<
+    R: UserRegistrationRepository,
+    C: CredentialRepository,
+    P: PasswordHasher,
+    A: AuditLogRepository + Send + Sync,
+    T: RefreshTokenRepository + Send + Sync,
+> AdminUsecase<R, C, P, A, T>
+{
+    pub fn new(
+        registration_repository: R,
+        credential_repository: C,
+        password_hasher: P,
+        audit_log_repository: A,
+        refresh_token_repository: T,
+        instance_host: String,
+    ) -> Self {
+        Self {
+            registration_repository,
+            credential_repository,
+            password_hasher,
+            audit_log_repository,
+            refresh_token_repository,
+            instance_host,
+        }
+    }
+
+    /// Create a local account with the given role
+    #[instrument(skip(self, password, email))]
+    pub async fn create_user(
+        &self,
+        user_id: String,
+        display_name: String,
+        password: String,
+        email: String,
+        role: Role,
+    ) -> Result<User, DomainError>
+    where
+        R: Send + Sync,
+        C: Send + Sync,
+        P: Send + Sync,
+    {
+        let activity_id = self.local_activity_id(Username::new(&user_id)?.as_str())?;
+        let email = EmailAddress::new(email)?;
+        let password_hash = self.password_hasher.hash(&password)?;
+
+        let user = self
+            .registration_repository
+            .register_user_with_credentials(
+                &activity_id,
+                &display_name,
+                role,
+                false,
+                password_hash,
+                email,
+            )
+            .await?;
+        self.audit_log_repository
+            .append(&AuditLogEntry::new(
+                None,
+                "create",
+                AuditTarget::Account(user.id()),
+                Some(format!("role: {}", role.as_str())),
+            ))
+            .await?;
+
+        Ok(user)
+    }
+
+    /// Create a local account migrated from another server
+    /// The password hash is kept when its format is supported (bcrypt, Argon2id) and upgraded at
+    /// the first login, without one a random password is set and the owner has to reset it
+    #[instrument(skip(self, password_hash, email))]
+    pub async fn import_user(
+        &self,
+        user_id: String,
+        display_name: String,
+        email: String,
+        password_hash: Option<HashedPassword>,
+    ) -> Result<User, DomainError>
+    where
+        R: Send + Sync,
+        C: Send + Sync,
+        P: Send + Sync,
+    {
+        let activity_id = self.local_activity_id(Username::new(&user_id)?.as_str())?;
+        let email = EmailAddress::new(email)?;
+        let password_hash = match password_hash {
+            Some(hash) if hash.algorithm().is_some() => hash,
+            Some(_) => return Err(DomainError::InvalidCredentials),
+            None => self.password_hasher.hash(&Uuid::new_v4().to_string())?,
+        };
+
+        let user = self
+            .registration_repository
+            .register_user_with_credentials(
+                &activity_id,
+                &display_name,
+                Role::User,
+                false,
+                password_hash,
+                email,
+            )
+            .await?;
+        self.audit_log_repository
+            .append(&AuditLogEntry::new(
+                None,
+                "import",
+                AuditTarget::Account(user.id()),
+                None,
+            ))
+            .await?;
+
+        Ok(user)
+    }
+
+    /// Replace the password of a local account, signing it out everywhere
+    #[instrument(skip(self, new_password))]
+    pub async fn reset_password(&self, user_id: String, new_password: String) -> Result<(), DomainError>
+    where
+        R: Send + Sync,
+        C: Send + Sync,
+        P: Send + Sync,
+    {
+        let activity_id = self.local_activity_id(&user_id)?;
+        let mut credential = self.credential_repository.get_credential(&activity_id).await?;
+
+        let password_hash = self.password_hasher.hash(&new_password)?;
+        credential.change_password(password_hash);
+        self.credential_repository.update_credential(&credential).await?;
+        // a session opened by whoever knew the old password ends with it
+        self.refresh_token_repository
+            .revoke_all_for_user(credential.user_id())
+            .await?;
+        self.audit_log_repository
+            .append(&AuditLogEntry::new(
+                None,
+                "reset_password",
+                AuditTarget::Account(credential.user_id()),
+                None,
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    /// helper function that build the activity id of a local user
+    fn local_activity_id(&self, user_id: &str) -> Result<ActivityId, DomainError> {
+        ActivityId::new(format!("https://{}/users/{}", self.instance_host, user_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            error::RepositoryError,
+            models::{fixtures::TEST_HOST, refresh_token::RefreshToken},
+        },
+        infrastructure::{
+            argon2_password_hasher::Argon2PasswordHasher,
+            in_memory::{
+                audit_log_repository::InMemoryAuditLogRepository,
+                credential_repository::InMemoryCredentialRepository,
+                refresh_token_repository::InMemoryRefreshTokenRepository,
+                user_registration_repository::InMemoryUserRegistrationRepository,
+                user_repository::InMemoryUserRepository,
+            },
+        },
+    };
+
+    type TestAdminUsecase = AdminUsecase<
+        InMemoryUserRegistrationRepository,
+        InMemoryCredentialRepository,
+        Argon2PasswordHasher,
+        InMemoryAuditLogRepository,
+        InMemoryRefreshTokenRepository,
+    >;
+
+    fn setup() -> (
+        TestAdminUsecase,
+        InMemoryCredentialRepository,
+        InMemoryRefreshTokenRepository,
+    ) {
+        let credential_repository = InMemoryCredentialRepository::new();
+        let registration_repository = InMemoryUserRegistrationRepository::new(
+            InMemoryUserRepository::new(),
+            credential_repository.clone(),
+        );
+        let refresh_token_repository = InMemoryRefreshTokenRepository::new();
+        let usecase = AdminUsecase::new(
+            registration_repository,
+            credential_repository.clone(),
+            Argon2PasswordHasher::new(),
+            InMemoryAuditLogRepository::new(),
+            refresh_token_repository.clone(),
+            TEST_HOST.to_string(),
+        );
+        (usecase, credential_repository, refresh_token_repository)
+    }
+
+    #[tokio::test]
+    async fn test_create_admin_positive() {
+        let (usecase, _, _) = setup();
+
+        let user = usecase
+            .create_user(
+                "admin".to_string(),
+                "管理者".to_string(),
+                "admin_password".to_string(),
+                "admin@example.com".to_string(),
+                Role::Admin,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(Role::Admin, user.role());
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_positive() {
+        let (usecase, credential_repository, _) = setup();
+        let user = usecase
+            .create_user(
+                "test_user".to_string(),
+                "テスト".to_string(),
+                "old_password".to_string(),
+                "test@example.com".to_string(),
+                Role::User,
+            )
+            .await
+            .unwrap();
+
+        usecase
+            .reset_password("test_user".to_string(), "new_password".to_string())
+            .await
+            .unwrap();
+
+        let credential = credential_repository
+            .get_credential(user.activity_id())
+            .await
+            .unwrap();
+        let hasher = Argon2PasswordHasher::new();
+        assert!(hasher.verify("new_password", credential.password_hash()).unwrap());
+        assert!(!hasher.verify("old_password", credential.password_hash()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_revokes_sessions_positive() {
+        let (usecase, _, refresh_token_repository) = setup();
+        let user = usecase
+            .create_user(
+                "test_user".to_string(),
+                "テスト".to_string(),
+                "old_password".to_string(),
+                "test@example.com".to_string(),
+                Role::User,
+            )
+            .await
+            .unwrap();
+        let (token, _) = RefreshToken::issue(user.id());
+        refresh_token_repository.create(&token).await.unwrap();
+
+        usecase
+            .reset_password("test_user".to_string(), "new_password".to_string())
+            .await
+            .unwrap();
+
+        let stored = refresh_token_repository
+            .find_by_hash(&token.token_hash)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(stored.revoked_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_unknown_user_negative() {
+        let (usecase, _, _) = setup();
+
+        let result = usecase
+            .reset_password("invalid_user".to_string(), "new_password".to_string())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::NotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_import_user_with_bcrypt_hash_positive() {
+        let (usecase, credential_repository, _) = setup();
+        let hash = HashedPassword::new(bcrypt::hash("test_password", 4).unwrap());
+
+        let user = usecase
+            .import_user(
+                "test_user".to_string(),
+                "テスト".to_string(),
+                "test@example.com".to_string(),
+                Some(hash.clone()),
+            )
+            .await
+            .unwrap();
+
+        let credential = credential_repository
+            .get_credential(user.activity_id())
+            .await
+            .unwrap();
+        assert_eq!(&hash, credential.password_hash());
+    }
+
+    #[tokio::test]
+    async fn test_import_user_unsupported_hash_negative() {
+        let (usecase, credential_repository, _) = setup();
+
+        let result = usecase
+            .import_user(
+                "test_user".to_string(),
+                "テスト".to_string(),
+                "test@example.com".to_string(),
+                Some(HashedPassword::new("5f4dcc3b5aa765d61d8327deb882cf99".to_string())),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidCredentials)));
+        assert!(!credential_repository.contains_email("test@example.com"));
+    }
+}
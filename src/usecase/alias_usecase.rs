@@ -0,0 +1,125 @@
+use tracing::instrument;
+
+use crate::domain::{
+    error::DomainError,
+    models::{
+        alias::{AccountAlias, MAX_ALIASES},
+        user::{ActivityId, User},
+    },
+    repositories::account_alias_repository::AccountAliasRepository,
+};
+
+/// Aliases (`alsoKnownAs`) of the calling user
+pub struct AliasUsecase<L: AccountAliasRepository> {
+    alias_repository: L,
+}
+
+impl<L: AccountAliasRepository + Send + Sync> AliasUsecase<L> {
+    pub fn new(alias_repository: L) -> Self {
+        Self { alias_repository }
+    }
+
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn list(&self, user: &User) -> Result<Vec<AccountAlias>, DomainError> {
+        Ok(self.alias_repository.list(user.id()).await?)
+    }
+
+    /// List `uri` as another account of the caller
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn add(&self, user: &User, uri: String) -> Result<AccountAlias, DomainError> {
+        let uri = ActivityId::new(uri).map_err(|_| DomainError::InvalidAlias)?;
+        let alias = AccountAlias::new(user, uri)?;
+        if self.alias_repository.list(user.id()).await?.len() >= MAX_ALIASES {
+            return Err(DomainError::InvalidAlias);
+        }
+        self.alias_repository.add(&alias).await?;
+
+        Ok(alias)
+    }
+
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn remove(&self, user: &User, uri: String) -> Result<(), DomainError> {
+        let uri = ActivityId::new(uri).map_err(|_| DomainError::InvalidAlias)?;
+        Ok(self.alias_repository.remove(user.id(), &uri).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            error::RepositoryError,
+            models::fixtures::{UserBuilder, activity_id},
+        },
+        infrastructure::in_memory::account_alias_repository::InMemoryAccountAliasRepository,
+    };
+
+    const OLD_ACCOUNT: &str = "https://old.example/users/alice";
+
+    /// # Description
+    /// Usecase over an empty in-memory repository, and the calling user
+    fn setup() -> (AliasUsecase<InMemoryAccountAliasRepository>, User) {
+        (
+            AliasUsecase::new(InMemoryAccountAliasRepository::new()),
+            UserBuilder::default().build(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_add_and_remove_alias_positive() {
+        let (usecase, user) = setup();
+
+        usecase.add(&user, OLD_ACCOUNT.to_string()).await.unwrap();
+        let aliases = usecase.list(&user).await.unwrap();
+        assert_eq!(
+            vec![OLD_ACCOUNT],
+            aliases.iter().map(|a| a.uri.as_str()).collect::<Vec<_>>()
+        );
+
+        usecase
+            .remove(&user, OLD_ACCOUNT.to_string())
+            .await
+            .unwrap();
+        assert!(usecase.list(&user).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_duplicate_alias_negative() {
+        let (usecase, user) = setup();
+        usecase.add(&user, OLD_ACCOUNT.to_string()).await.unwrap();
+
+        let result = usecase.add(&user, OLD_ACCOUNT.to_string()).await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::Conflict {
+                field: "alias"
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_add_self_alias_negative() {
+        let (usecase, user) = setup();
+
+        let result = usecase
+            .add(&user, user.activity_id().as_str().to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidAlias)));
+    }
+
+    #[tokio::test]
+    async fn test_add_too_many_aliases_negative() {
+        let (usecase, user) = setup();
+        for i in 0..MAX_ALIASES {
+            let uri = activity_id("old.example", &format!("alice{i}"));
+            usecase.add(&user, uri.as_str().to_string()).await.unwrap();
+        }
+
+        let result = usecase.add(&user, OLD_ACCOUNT.to_string()).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidAlias)));
+    }
+}
@@ -0,0 +1,91 @@
+use tracing::instrument;
+
+use crate::domain::{
+    error::DomainError,
+    models::{
+        audit_log::{AuditLogEntry, AuditLogFilter},
+        user::User,
+    },
+    repositories::audit_log_repository::AuditLogRepository,
+};
+
+/// Largest page of the audit log
+pub const MAX_AUDIT_LOG_LIMIT: u64 = 200;
+
+/// Browse the moderation audit trail (admins only)
+pub struct AuditLogUsecase<A: AuditLogRepository> {
+    audit_log_repository: A,
+}
+
+impl<A: AuditLogRepository + Send + Sync> AuditLogUsecase<A> {
+    pub fn new(audit_log_repository: A) -> Self {
+        Self {
+            audit_log_repository,
+        }
+    }
+
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn list(
+        &self,
+        admin: &User,
+        mut filter: AuditLogFilter,
+    ) -> Result<Vec<AuditLogEntry>, DomainError> {
+        admin.ensure_admin()?;
+        filter.limit = filter.limit.clamp(1, MAX_AUDIT_LOG_LIMIT);
+        Ok(self.audit_log_repository.list(&filter).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        domain::models::{
+            audit_log::AuditTarget,
+            fixtures::UserBuilder,
+            user::{Role, UserId},
+        },
+        infrastructure::in_memory::audit_log_repository::InMemoryAuditLogRepository,
+    };
+
+    #[tokio::test]
+    async fn test_list_filtered_by_action_positive() {
+        let repository = InMemoryAuditLogRepository::new();
+        let target = AuditTarget::Account(UserId::new(Uuid::new_v4()));
+        for verb in ["silence", "suspend", "unsilence"] {
+            repository
+                .append(&AuditLogEntry::new(None, verb, target, None))
+                .await
+                .unwrap();
+        }
+        let usecase = AuditLogUsecase::new(repository);
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+
+        let entries = usecase
+            .list(
+                &admin,
+                AuditLogFilter {
+                    action: Some("account.suspend".to_string()),
+                    limit: 10,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(1, entries.len());
+        assert_eq!(target.id(), entries[0].target_id);
+    }
+
+    #[tokio::test]
+    async fn test_list_as_moderator_negative() {
+        let usecase = AuditLogUsecase::new(InMemoryAuditLogRepository::new());
+        let moderator = UserBuilder::default().build().with_role(Role::Moderator);
+
+        let result = usecase.list(&moderator, AuditLogFilter::default()).await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+}
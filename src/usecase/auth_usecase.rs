@@ -0,0 +1,211 @@
+use tracing::instrument;
+
+use crate::domain::{
+    error::DomainError,
+    models::user::User,
+    repositories::user_repository::UserRepository,
+    services::token_service::{DEFAULT_SCOPES, Token, TokenVerifier},
+};
+
+/// Token currently usable, with the account it acts as
+#[derive(Debug, Clone)]
+pub struct ActiveToken {
+    pub token: Token,
+    pub user: User,
+}
+
+/// Resolve bearer tokens into the account they were issued to
+#[derive(Clone)]
+pub struct AuthUsecase<U: UserRepository, V: TokenVerifier> {
+    user_repository: U,
+    token_verifier: V,
+}
+
+impl<U: UserRepository, V: TokenVerifier> AuthUsecase<U, V> {
+    pub fn new(user_repository: U, token_verifier: V) -> Self {
+        Self {
+            user_repository,
+            token_verifier,
+        }
+    }
+
+    /// Resolve a session token, refusing with `Forbidden` one without the login scopes
+    /// Service credentials only carry their own scope, so they never act as the account
+    #[instrument(skip_all)]
+    pub async fn authenticate(&self, token: &str) -> Result<User, DomainError>
+    where
+        U: Send + Sync,
+    {
+        let active = self.resolve(token).await?;
+        if !DEFAULT_SCOPES
+            .iter()
+            .all(|scope| active.token.has_scope(scope))
+        {
+            return Err(DomainError::Forbidden);
+        }
+        Ok(active.user)
+    }
+
+    /// Like `authenticate`, refusing with `Forbidden` a token that was not granted `scope`
+    #[instrument(skip_all)]
+    pub async fn authorize(&self, token: &str, scope: &str) -> Result<User, DomainError>
+    where
+        U: Send + Sync,
+    {
+        let active = self.resolve(token).await?;
+        if !active.token.has_scope(scope) {
+            return Err(DomainError::Forbidden);
+        }
+        Ok(active.user)
+    }
+
+    /// Whether the token is active (RFC 7662), `None` when it would be refused by `authenticate`
+    #[instrument(skip_all)]
+    pub async fn introspect(&self, token: &str) -> Result<Option<ActiveToken>, DomainError>
+    where
+        U: Send + Sync,
+    {
+        match self.resolve(token).await {
+            Ok(active) => Ok(Some(active)),
+            Err(DomainError::AuthenticationFailed | DomainError::AccountDisabled) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// helper function that verify the token and load the account, refusing deleted and suspended ones
+    async fn resolve(&self, token: &str) -> Result<ActiveToken, DomainError>
+    where
+        U: Send + Sync,
+    {
+        let token = self.token_verifier.verify(token)?;
+
+        let user = self
+            .user_repository
+            .find_by_id(token.user_id)
+            .await?
+            .ok_or(DomainError::AuthenticationFailed)?;
+        if user.is_deleted() {
+            return Err(DomainError::AuthenticationFailed);
+        }
+
+        // tokens issued before a suspension stop working immediately
+        if !user.moderation().can_login() {
+            return Err(DomainError::AccountDisabled);
+        }
+        Ok(ActiveToken { token, user })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            models::fixtures::UserBuilder,
+            services::token_service::{INTROSPECT_SCOPE, TokenGenerator},
+        },
+        infrastructure::{
+            in_memory::user_repository::InMemoryUserRepository,
+            jwt_token_generator::JwtTokenGenerator,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_authenticate_positive() {
+        let user_repository = InMemoryUserRepository::new();
+        let user = UserBuilder::default().build();
+        user_repository.insert(user.clone()).unwrap();
+        let tokens = JwtTokenGenerator::new("secret".to_string());
+        let usecase = AuthUsecase::new(user_repository, tokens.clone());
+
+        let token = tokens.generate(&user).unwrap();
+        let authenticated = usecase.authenticate(&token.value).await.unwrap();
+
+        assert_eq!(user.id(), authenticated.id());
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_deleted_user_negative() {
+        let tokens = JwtTokenGenerator::new("secret".to_string());
+        let usecase = AuthUsecase::new(InMemoryUserRepository::new(), tokens.clone());
+
+        let token = tokens.generate(&UserBuilder::default().build()).unwrap();
+        let result = usecase.authenticate(&token.value).await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_service_token_negative() {
+        let user_repository = InMemoryUserRepository::new();
+        let user = UserBuilder::default().build();
+        user_repository.insert(user.clone()).unwrap();
+        let tokens = JwtTokenGenerator::new("secret".to_string());
+        let usecase = AuthUsecase::new(user_repository, tokens.clone());
+
+        let token = tokens
+            .generate_with_scopes(&user, &[INTROSPECT_SCOPE])
+            .unwrap();
+        let result = usecase.authenticate(&token.value).await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn test_authorize_positive() {
+        let user_repository = InMemoryUserRepository::new();
+        let user = UserBuilder::default().build();
+        user_repository.insert(user.clone()).unwrap();
+        let tokens = JwtTokenGenerator::new("secret".to_string());
+        let usecase = AuthUsecase::new(user_repository, tokens.clone());
+
+        let token = tokens
+            .generate_with_scopes(&user, &[INTROSPECT_SCOPE])
+            .unwrap();
+        let authorized = usecase
+            .authorize(&token.value, INTROSPECT_SCOPE)
+            .await
+            .unwrap();
+
+        assert_eq!(user.id(), authorized.id());
+    }
+
+    #[tokio::test]
+    async fn test_authorize_login_token_negative() {
+        let user_repository = InMemoryUserRepository::new();
+        let user = UserBuilder::default().build();
+        user_repository.insert(user.clone()).unwrap();
+        let tokens = JwtTokenGenerator::new("secret".to_string());
+        let usecase = AuthUsecase::new(user_repository, tokens.clone());
+
+        let token = tokens.generate(&user).unwrap();
+        let result = usecase.authorize(&token.value, INTROSPECT_SCOPE).await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn test_introspect_positive() {
+        let user_repository = InMemoryUserRepository::new();
+        let user = UserBuilder::default().build();
+        user_repository.insert(user.clone()).unwrap();
+        let tokens = JwtTokenGenerator::new("secret".to_string());
+        let usecase = AuthUsecase::new(user_repository, tokens.clone());
+
+        let token = tokens.generate(&user).unwrap();
+        let active = usecase.introspect(&token.value).await.unwrap().unwrap();
+
+        assert_eq!(user.id(), active.user.id());
+        assert_eq!(token.scopes, active.token.scopes);
+    }
+
+    #[tokio::test]
+    async fn test_introspect_invalid_token_negative() {
+        let tokens = JwtTokenGenerator::new("secret".to_string());
+        let usecase = AuthUsecase::new(InMemoryUserRepository::new(), tokens);
+
+        let active = usecase.introspect("not-a-token").await.unwrap();
+
+        assert!(active.is_none());
+    }
+}
@@ -0,0 +1,335 @@
+use std::sync::Arc;
+
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::{DomainError, RepositoryError},
+        models::{
+            audit_log::{AuditLogEntry, AuditTarget},
+            federation::{BlocklistSubscription, blocklist_url, parse_blocklist},
+            user::User,
+        },
+        repositories::{
+            audit_log_repository::AuditLogRepository,
+            blocklist_subscription_repository::BlocklistSubscriptionRepository,
+            federation_domain_repository::FederationDomainRepository,
+        },
+        services::blocklist_service::BlocklistFetcher,
+    },
+    usecase::federation_usecase::{BlocklistImport, FederationUsecase},
+};
+
+/// Shared blocklists read from a URL, once or through a subscription synced periodically
+pub struct BlocklistSubscriptionUsecase<
+    S: BlocklistSubscriptionRepository,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository,
+    A: AuditLogRepository,
+> {
+    blocklist_subscription_repository: S,
+    fetcher: F,
+    /// Entries are applied through the domain list, which keeps its cache in step
+    federation: Arc<FederationUsecase<D, A>>,
+    audit_log_repository: A,
+}
+
+impl<
+    S: BlocklistSubscriptionRepository + Send + Sync,
+    F: BlocklistFetcher,
+    D: FederationDomainRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+> BlocklistSubscriptionUsecase<S, F, D, A>
+{
+    pub fn new(
+        blocklist_subscription_repository: S,
+        fetcher: F,
+        federation: Arc<FederationUsecase<D, A>>,
+        audit_log_repository: A,
+    ) -> Self {
+        Self {
+            blocklist_subscription_repository,
+            fetcher,
+            federation,
+            audit_log_repository,
+        }
+    }
+
+    /// Import the blocklist served at `url`, like an uploaded one
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn import_url(
+        &self,
+        admin: &User,
+        url: &str,
+        preview: bool,
+    ) -> Result<BlocklistImport, DomainError> {
+        admin.ensure_admin()?;
+
+        let entries = self.fetch_entries(&blocklist_url(url)?).await?;
+        self.federation.import_blocks(admin, entries, preview).await
+    }
+
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn list(&self, admin: &User) -> Result<Vec<BlocklistSubscription>, DomainError> {
+        admin.ensure_admin()?;
+        Ok(self.blocklist_subscription_repository.list().await?)
+    }
+
+    /// Subscribe to the blocklist at `url`, picked up by the next periodic sync
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn subscribe(
+        &self,
+        admin: &User,
+        url: &str,
+        review: bool,
+    ) -> Result<BlocklistSubscription, DomainError> {
+        admin.ensure_admin()?;
+
+        let subscription = BlocklistSubscription::new(url, review)?;
+        self.blocklist_subscription_repository
+            .create(&subscription)
+            .await?;
+
+        let entry = AuditLogEntry::new(
+            Some(admin.id()),
+            "create",
+            AuditTarget::BlocklistSubscription(subscription.id),
+            Some(subscription.url.clone()),
+        );
+        self.audit_log_repository.append(&entry).await?;
+
+        Ok(subscription)
+    }
+
+    /// Domains blocked through the subscription stay blocked
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn unsubscribe(&self, admin: &User, id: Uuid) -> Result<(), DomainError> {
+        admin.ensure_admin()?;
+
+        self.blocklist_subscription_repository.delete(id).await?;
+
+        let entry = AuditLogEntry::new(
+            Some(admin.id()),
+            "delete",
+            AuditTarget::BlocklistSubscription(id),
+            None,
+        );
+        Ok(self.audit_log_repository.append(&entry).await?)
+    }
+
+    /// Sync one subscription on behalf of an admin, which is how entries held for review get applied
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn sync(
+        &self,
+        admin: &User,
+        id: Uuid,
+        preview: bool,
+    ) -> Result<BlocklistImport, DomainError> {
+        admin.ensure_admin()?;
+
+        let mut subscription = self
+            .blocklist_subscription_repository
+            .find(id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+
+        let result = match self.fetch_entries(&subscription.url).await {
+            Ok(entries) => self.federation.import_blocks(admin, entries, preview).await,
+            Err(e) => Err(e),
+        };
+        match &result {
+            Ok(import) if preview => subscription.record_sync(import.added.len()),
+            Ok(_) => subscription.record_sync(0),
+            Err(e) => subscription.record_failure(e.to_string()),
+        }
+        self.blocklist_subscription_repository
+            .update_sync(&subscription)
+            .await?;
+
+        result
+    }
+
+    /// Sync every subscription, run by the periodic task
+    /// A subscription under review only records how many entries wait for an admin,
+    /// one failing is recorded on it without holding back the others
+    #[instrument(skip(self))]
+    pub async fn sync_all(&self) -> Result<(), DomainError> {
+        let subscriptions = self.blocklist_subscription_repository.list().await?;
+        for mut subscription in subscriptions {
+            let result = match self.fetch_entries(&subscription.url).await {
+                Ok(entries) => {
+                    self.federation
+                        .apply_blocklist(None, entries, subscription.review)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(import) if subscription.review => subscription.record_sync(import.added.len()),
+                Ok(_) => subscription.record_sync(0),
+                Err(e) => {
+                    tracing::warn!(error = %e, url = %subscription.url, "Failed to sync blocklist");
+                    subscription.record_failure(e.to_string());
+                }
+            }
+            self.blocklist_subscription_repository
+                .update_sync(&subscription)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// helper function that download and read the blocklist at `url`
+    async fn fetch_entries(&self, url: &str) -> Result<Vec<(String, String)>, DomainError> {
+        let body = self.fetcher.fetch(url).await?;
+        parse_blocklist(&body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::{
+        domain::models::{federation::FederationMode, fixtures::UserBuilder, user::Role},
+        infrastructure::in_memory::{
+            audit_log_repository::InMemoryAuditLogRepository,
+            blocklist_subscription_repository::InMemoryBlocklistSubscriptionRepository,
+            federation_domain_repository::InMemoryFederationDomainRepository,
+        },
+    };
+
+    type TestBlocklistSubscriptionUsecase = BlocklistSubscriptionUsecase<
+        InMemoryBlocklistSubscriptionRepository,
+        StaticFetcher,
+        InMemoryFederationDomainRepository,
+        InMemoryAuditLogRepository,
+    >;
+
+    /// # Description
+    /// Fetcher answering every URL with the same blocklist, or failing when there is none
+    struct StaticFetcher(Option<&'static str>);
+
+    #[async_trait]
+    impl BlocklistFetcher for StaticFetcher {
+        async fn fetch(&self, _url: &str) -> Result<String, DomainError> {
+            self.0
+                .map(str::to_string)
+                .ok_or_else(|| DomainError::RemoteFetch("connection refused".to_string()))
+        }
+    }
+
+    /// # Description
+    /// build the usecase over empty in-memory repositories, with the fetcher answering `body`
+    fn setup(
+        body: Option<&'static str>,
+    ) -> (
+        TestBlocklistSubscriptionUsecase,
+        Arc<FederationUsecase<InMemoryFederationDomainRepository, InMemoryAuditLogRepository>>,
+        User,
+    ) {
+        let federation = Arc::new(FederationUsecase::new(
+            FederationMode::Blocklist,
+            InMemoryFederationDomainRepository::new(),
+            InMemoryAuditLogRepository::new(),
+        ));
+        let usecase = BlocklistSubscriptionUsecase::new(
+            InMemoryBlocklistSubscriptionRepository::new(),
+            StaticFetcher(body),
+            federation.clone(),
+            InMemoryAuditLogRepository::new(),
+        );
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+        (usecase, federation, admin)
+    }
+
+    const BLOCKLIST: &str = "#domain,#severity,#public_comment\nspam.example,suspend,spam\n";
+    const URL: &str = "https://lists.example/blocklist.csv";
+
+    #[tokio::test]
+    async fn test_import_url_positive() {
+        let (usecase, federation, admin) = setup(Some(BLOCKLIST));
+
+        let import = usecase.import_url(&admin, URL, false).await.unwrap();
+
+        assert_eq!(1, import.added.len());
+        assert!(matches!(
+            federation.ensure_federates_with("spam.example").await,
+            Err(DomainError::DomainNotFederated(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_import_url_not_http_negative() {
+        let (usecase, _, admin) = setup(Some(BLOCKLIST));
+
+        let result = usecase
+            .import_url(&admin, "file:///etc/blocklist.csv", false)
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidBlocklistUrl)));
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_applies_subscription_positive() {
+        let (usecase, federation, admin) = setup(Some(BLOCKLIST));
+        usecase.subscribe(&admin, URL, false).await.unwrap();
+
+        usecase.sync_all().await.unwrap();
+
+        assert!(matches!(
+            federation.ensure_federates_with("spam.example").await,
+            Err(DomainError::DomainNotFederated(_))
+        ));
+        let subscription = usecase.list(&admin).await.unwrap().remove(0);
+        assert!(subscription.last_synced_at.is_some());
+        assert_eq!(0, subscription.pending);
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_holds_reviewed_subscription_positive() {
+        let (usecase, federation, admin) = setup(Some(BLOCKLIST));
+        let subscription = usecase.subscribe(&admin, URL, true).await.unwrap();
+
+        usecase.sync_all().await.unwrap();
+
+        assert!(
+            federation
+                .ensure_federates_with("spam.example")
+                .await
+                .is_ok()
+        );
+        assert_eq!(1, usecase.list(&admin).await.unwrap()[0].pending);
+
+        usecase.sync(&admin, subscription.id, false).await.unwrap();
+
+        assert!(matches!(
+            federation.ensure_federates_with("spam.example").await,
+            Err(DomainError::DomainNotFederated(_))
+        ));
+        assert_eq!(0, usecase.list(&admin).await.unwrap()[0].pending);
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_records_failure_negative() {
+        let (usecase, _, admin) = setup(None);
+        usecase.subscribe(&admin, URL, false).await.unwrap();
+
+        usecase.sync_all().await.unwrap();
+
+        let subscription = usecase.list(&admin).await.unwrap().remove(0);
+        assert!(subscription.last_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_as_moderator_negative() {
+        let (usecase, _, _) = setup(Some(BLOCKLIST));
+        let moderator = UserBuilder::default().build().with_role(Role::Moderator);
+
+        let result = usecase.subscribe(&moderator, URL, false).await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+}
@@ -0,0 +1,60 @@
+use tracing::instrument;
+
+use crate::domain::{
+    error::DomainError,
+    models::{secret, user::UserId},
+    repositories::email_verification_repository::EmailVerificationRepository,
+};
+
+/// Confirmation of the address given at sign-up, through the link sent by email
+pub struct EmailVerificationUsecase<V: EmailVerificationRepository> {
+    email_verification_repository: V,
+}
+
+impl<V: EmailVerificationRepository + Send + Sync> EmailVerificationUsecase<V> {
+    pub fn new(email_verification_repository: V) -> Self {
+        Self {
+            email_verification_repository,
+        }
+    }
+
+    /// Mark the address as verified, the account can log in afterwards
+    /// An unknown or already used token fails with `NotFound`
+    #[instrument(skip_all)]
+    pub async fn verify(&self, token: &str) -> Result<UserId, DomainError> {
+        let user_id = self
+            .email_verification_repository
+            .confirm(&secret::digest(token))
+            .await?;
+
+        tracing::info!(%user_id, "email address verified");
+        Ok(user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{error::RepositoryError, models::email_verification::EmailVerification},
+        infrastructure::in_memory::email_verification_repository::InMemoryEmailVerificationRepository,
+    };
+
+    #[tokio::test]
+    async fn test_verify_positive() {
+        let repository = InMemoryEmailVerificationRepository::new();
+        let user_id = UserId::from(uuid::Uuid::new_v4());
+        let (verification, token) = EmailVerification::issue(user_id);
+        repository.create(&verification).await.unwrap();
+        let usecase = EmailVerificationUsecase::new(repository.clone());
+
+        let verified = usecase.verify(&token).await.unwrap();
+
+        assert_eq!(user_id, verified);
+        assert!(!repository.is_pending(user_id).await.unwrap());
+        assert!(matches!(
+            usecase.verify(&token).await,
+            Err(DomainError::Repository(RepositoryError::NotFound))
+        ));
+    }
+}
@@ -0,0 +1,165 @@
+use tracing::instrument;
+
+use crate::domain::{
+    error::{DomainError, RepositoryError},
+    models::{
+        endorsement::{Endorsement, MAX_ENDORSEMENTS},
+        user::{User, UserId},
+    },
+    repositories::{
+        account_endorsement_repository::AccountEndorsementRepository,
+        user_repository::UserRepository,
+    },
+};
+
+/// Accounts featured on a user's profile
+pub struct EndorsementUsecase<E: AccountEndorsementRepository, U: UserRepository> {
+    endorsement_repository: E,
+    user_repository: U,
+}
+
+impl<E: AccountEndorsementRepository + Send + Sync, U: UserRepository + Send + Sync>
+    EndorsementUsecase<E, U>
+{
+    pub fn new(endorsement_repository: E, user_repository: U) -> Self {
+        Self {
+            endorsement_repository,
+            user_repository,
+        }
+    }
+
+    /// Accounts featured by `user_id`, oldest first, deleted ones are left out
+    #[instrument(skip(self))]
+    pub async fn list(&self, user_id: UserId) -> Result<Vec<User>, DomainError> {
+        let user = self.find_account(user_id).await?;
+
+        let mut featured = Vec::new();
+        for endorsement in self.endorsement_repository.list(user.id()).await? {
+            match self
+                .user_repository
+                .find_by_id(endorsement.target_id)
+                .await?
+            {
+                Some(target) if !target.is_deleted() => featured.push(target),
+                _ => {}
+            }
+        }
+        Ok(featured)
+    }
+
+    /// Feature `target_id` on the caller's profile
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn endorse(&self, user: &User, target_id: UserId) -> Result<(), DomainError> {
+        let target = self.find_account(target_id).await?;
+        let endorsement = Endorsement::new(user, &target)?;
+        if self.endorsement_repository.list(user.id()).await?.len() >= MAX_ENDORSEMENTS {
+            return Err(DomainError::InvalidEndorsement);
+        }
+        Ok(self.endorsement_repository.add(&endorsement).await?)
+    }
+
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn unendorse(&self, user: &User, target_id: UserId) -> Result<(), DomainError> {
+        Ok(self
+            .endorsement_repository
+            .remove(user.id(), target_id)
+            .await?)
+    }
+
+    /// helper function that load an account, failing with its tombstone when deleted
+    async fn find_account(&self, id: UserId) -> Result<User, DomainError> {
+        let user = self
+            .user_repository
+            .find_by_id(id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+        user.ensure_not_deleted()?;
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::fixtures::{TEST_HOST, UserBuilder, activity_id},
+        infrastructure::in_memory::{
+            account_endorsement_repository::InMemoryAccountEndorsementRepository,
+            user_repository::InMemoryUserRepository,
+        },
+    };
+
+    type TestEndorsementUsecase =
+        EndorsementUsecase<InMemoryAccountEndorsementRepository, InMemoryUserRepository>;
+
+    /// # Description
+    /// Usecase over in-memory repositories holding the calling user and another account
+    fn setup() -> (TestEndorsementUsecase, InMemoryUserRepository, User, User) {
+        let user_repository = InMemoryUserRepository::new();
+        let user = UserBuilder::default().build();
+        let other = UserBuilder::default()
+            .activity_id(activity_id(TEST_HOST, "other"))
+            .build();
+        for account in [&user, &other] {
+            user_repository.insert(account.clone()).unwrap();
+        }
+
+        let usecase = EndorsementUsecase::new(
+            InMemoryAccountEndorsementRepository::new(),
+            user_repository.clone(),
+        );
+        (usecase, user_repository, user, other)
+    }
+
+    #[tokio::test]
+    async fn test_endorse_and_unendorse_positive() {
+        let (usecase, _, user, other) = setup();
+
+        usecase.endorse(&user, other.id()).await.unwrap();
+        let featured = usecase.list(user.id()).await.unwrap();
+        assert_eq!(
+            vec![other.id()],
+            featured.iter().map(User::id).collect::<Vec<_>>()
+        );
+
+        usecase.unendorse(&user, other.id()).await.unwrap();
+        assert!(usecase.list(user.id()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_endorse_self_negative() {
+        let (usecase, _, user, _) = setup();
+
+        let result = usecase.endorse(&user, user.id()).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidEndorsement)));
+    }
+
+    #[tokio::test]
+    async fn test_endorse_twice_negative() {
+        let (usecase, _, user, other) = setup();
+        usecase.endorse(&user, other.id()).await.unwrap();
+
+        let result = usecase.endorse(&user, other.id()).await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::Conflict {
+                field: "endorsement"
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_skips_deleted_accounts_positive() {
+        let (usecase, user_repository, user, other) = setup();
+        usecase.endorse(&user, other.id()).await.unwrap();
+        let mut deleted = other.clone();
+        deleted.delete();
+        user_repository.mark_deleted(&deleted).await.unwrap();
+
+        let featured = usecase.list(user.id()).await.unwrap();
+
+        assert!(featured.is_empty());
+    }
+}
@@ -0,0 +1,360 @@
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::DomainError,
+    models::{
+        audit_log::{AuditLogEntry, AuditTarget},
+        federation::{DomainPolicy, FederationDomain, FederationMode},
+        user::{User, UserId},
+    },
+    repositories::{
+        audit_log_repository::AuditLogRepository,
+        federation_domain_repository::FederationDomainRepository,
+    },
+};
+
+/// How long the domain list checked before every remote request is reused before being reloaded
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Outcome of a blocklist import, `added` lists what would be added when previewing
+#[derive(Debug, Default)]
+pub struct BlocklistImport {
+    pub added: Vec<FederationDomain>,
+    /// Domains left out, already blocked (directly or through a parent domain) or invalid
+    pub skipped: Vec<String>,
+}
+
+/// Admin-managed block and allow lists, and the check applied before talking to a remote host
+pub struct FederationUsecase<D: FederationDomainRepository, A: AuditLogRepository> {
+    mode: FederationMode,
+    federation_domain_repository: D,
+    audit_log_repository: A,
+    cache: RwLock<Option<(Instant, Arc<Vec<FederationDomain>>)>>,
+}
+
+impl<D: FederationDomainRepository + Send + Sync, A: AuditLogRepository + Send + Sync>
+    FederationUsecase<D, A>
+{
+    pub fn new(
+        mode: FederationMode,
+        federation_domain_repository: D,
+        audit_log_repository: A,
+    ) -> Self {
+        Self {
+            mode,
+            federation_domain_repository,
+            audit_log_repository,
+            cache: RwLock::new(None),
+        }
+    }
+
+    pub fn mode(&self) -> FederationMode {
+        self.mode
+    }
+
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn list(&self, admin: &User) -> Result<Vec<FederationDomain>, DomainError> {
+        admin.ensure_admin()?;
+        Ok(self.federation_domain_repository.list().await?)
+    }
+
+    #[instrument(skip(self, admin, comment), fields(admin_id = %admin.id()))]
+    pub async fn create(
+        &self,
+        admin: &User,
+        domain: &str,
+        policy: DomainPolicy,
+        comment: String,
+    ) -> Result<FederationDomain, DomainError> {
+        admin.ensure_admin()?;
+
+        let domain = FederationDomain::new(domain, policy, comment)?;
+        self.federation_domain_repository.create(&domain).await?;
+        self.invalidate();
+
+        let entry = AuditLogEntry::new(
+            Some(admin.id()),
+            "create",
+            AuditTarget::FederationDomain(domain.id),
+            Some(format!("{} {}", domain.domain, policy.as_str())),
+        );
+        self.audit_log_repository.append(&entry).await?;
+
+        Ok(domain)
+    }
+
+    /// Block every `(domain, comment)` not blocked yet, typically a shared blocklist
+    /// With `preview`, nothing is written so the admin can review the entries first
+    #[instrument(skip(self, admin, entries), fields(admin_id = %admin.id(), count = entries.len()))]
+    pub async fn import_blocks(
+        &self,
+        admin: &User,
+        entries: Vec<(String, String)>,
+        preview: bool,
+    ) -> Result<BlocklistImport, DomainError> {
+        admin.ensure_admin()?;
+        self.apply_blocklist(Some(admin.id()), entries, preview).await
+    }
+
+    /// Same as `import_blocks`, recorded under `actor` in the audit log
+    /// `None` stands for the instance itself, when a subscribed blocklist is synced
+    #[instrument(skip(self, entries), fields(count = entries.len()))]
+    pub async fn apply_blocklist(
+        &self,
+        actor: Option<UserId>,
+        entries: Vec<(String, String)>,
+        preview: bool,
+    ) -> Result<BlocklistImport, DomainError> {
+        let existing = self.federation_domain_repository.list().await?;
+        let mut import = BlocklistImport::default();
+        for (domain, comment) in entries {
+            let Ok(block) = FederationDomain::new(&domain, DomainPolicy::Block, comment) else {
+                import.skipped.push(domain);
+                continue;
+            };
+            let blocked = existing
+                .iter()
+                .chain(&import.added)
+                .any(|listed| listed.policy == DomainPolicy::Block && listed.covers(&block.domain));
+            if blocked {
+                import.skipped.push(block.domain);
+                continue;
+            }
+            import.added.push(block);
+        }
+        if preview {
+            return Ok(import);
+        }
+
+        for block in &import.added {
+            self.federation_domain_repository.create(block).await?;
+            let entry = AuditLogEntry::new(
+                actor,
+                "create",
+                AuditTarget::FederationDomain(block.id),
+                Some(format!(
+                    "{} {} (import)",
+                    block.domain,
+                    block.policy.as_str()
+                )),
+            );
+            self.audit_log_repository.append(&entry).await?;
+        }
+        self.invalidate();
+
+        Ok(import)
+    }
+
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn delete(&self, admin: &User, id: Uuid) -> Result<(), DomainError> {
+        admin.ensure_admin()?;
+
+        self.federation_domain_repository.delete(id).await?;
+        self.invalidate();
+
+        let entry = AuditLogEntry::new(
+            Some(admin.id()),
+            "delete",
+            AuditTarget::FederationDomain(id),
+            None,
+        );
+        Ok(self.audit_log_repository.append(&entry).await?)
+    }
+
+    /// Fails with `DomainNotFederated` when the mode and the domain list refuse `host`
+    pub async fn ensure_federates_with(&self, host: &str) -> Result<(), DomainError> {
+        let domains = self.domains().await?;
+        match self.mode.allows(host, &domains) {
+            true => Ok(()),
+            false => Err(DomainError::DomainNotFederated(host.to_string())),
+        }
+    }
+
+    /// helper function that return the cached domain list, reloading it once stale
+    async fn domains(&self) -> Result<Arc<Vec<FederationDomain>>, DomainError> {
+        if let Some((loaded_at, domains)) = self.cache.read().unwrap().as_ref()
+            && loaded_at.elapsed() < CACHE_TTL
+        {
+            return Ok(domains.clone());
+        }
+
+        let domains = Arc::new(self.federation_domain_repository.list().await?);
+        *self.cache.write().unwrap() = Some((Instant::now(), domains.clone()));
+        Ok(domains)
+    }
+
+    fn invalidate(&self) {
+        *self.cache.write().unwrap() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            error::RepositoryError,
+            models::{fixtures::UserBuilder, user::Role},
+        },
+        infrastructure::in_memory::{
+            audit_log_repository::InMemoryAuditLogRepository,
+            federation_domain_repository::InMemoryFederationDomainRepository,
+        },
+    };
+
+    type TestFederationUsecase =
+        FederationUsecase<InMemoryFederationDomainRepository, InMemoryAuditLogRepository>;
+
+    /// # Description
+    /// build the usecase in `mode` over empty in-memory repositories, with an admin to manage domains
+    fn setup(mode: FederationMode) -> (TestFederationUsecase, InMemoryAuditLogRepository, User) {
+        let audit_log_repository = InMemoryAuditLogRepository::new();
+        let usecase = FederationUsecase::new(
+            mode,
+            InMemoryFederationDomainRepository::new(),
+            audit_log_repository.clone(),
+        );
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+        (usecase, audit_log_repository, admin)
+    }
+
+    #[tokio::test]
+    async fn test_blocklist_refuses_blocked_domain_positive() {
+        let (usecase, audit_log_repository, admin) = setup(FederationMode::Blocklist);
+        assert!(usecase.ensure_federates_with("spam.example").await.is_ok());
+
+        let domain = usecase
+            .create(&admin, "spam.example", DomainPolicy::Block, String::new())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            usecase.ensure_federates_with("cdn.spam.example").await,
+            Err(DomainError::DomainNotFederated(_))
+        ));
+
+        usecase.delete(&admin, domain.id).await.unwrap();
+
+        assert!(
+            usecase
+                .ensure_federates_with("cdn.spam.example")
+                .await
+                .is_ok()
+        );
+        assert_eq!(
+            vec!["federation_domain.create", "federation_domain.delete"],
+            audit_log_repository.actions()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_allowlist_refuses_unlisted_domain_negative() {
+        let (usecase, _, admin) = setup(FederationMode::Allowlist);
+        usecase
+            .create(
+                &admin,
+                "friends.example",
+                DomainPolicy::Allow,
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        assert!(
+            usecase
+                .ensure_federates_with("friends.example")
+                .await
+                .is_ok()
+        );
+        assert!(matches!(
+            usecase.ensure_federates_with("stranger.example").await,
+            Err(DomainError::DomainNotFederated(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_duplicate_negative() {
+        let (usecase, _, admin) = setup(FederationMode::Blocklist);
+        usecase
+            .create(&admin, "spam.example", DomainPolicy::Block, String::new())
+            .await
+            .unwrap();
+
+        let result = usecase
+            .create(&admin, "SPAM.example", DomainPolicy::Block, String::new())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::Conflict {
+                field: "domain"
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_as_moderator_negative() {
+        let (usecase, _, _) = setup(FederationMode::Blocklist);
+        let moderator = UserBuilder::default().build().with_role(Role::Moderator);
+
+        let result = usecase
+            .create(
+                &moderator,
+                "spam.example",
+                DomainPolicy::Block,
+                String::new(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn test_import_blocks_skips_listed_domains_positive() {
+        let (usecase, audit_log_repository, admin) = setup(FederationMode::Blocklist);
+        usecase
+            .create(&admin, "spam.example", DomainPolicy::Block, String::new())
+            .await
+            .unwrap();
+        let entries = vec![
+            ("cdn.spam.example".to_string(), String::new()),
+            ("abuse.example".to_string(), "harassment".to_string()),
+            ("abuse.example".to_string(), String::new()),
+            ("not a domain".to_string(), String::new()),
+        ];
+
+        let preview = usecase
+            .import_blocks(&admin, entries.clone(), true)
+            .await
+            .unwrap();
+        assert!(usecase.ensure_federates_with("abuse.example").await.is_ok());
+
+        let import = usecase.import_blocks(&admin, entries, false).await.unwrap();
+
+        for result in [&preview, &import] {
+            assert_eq!(
+                vec!["abuse.example"],
+                result
+                    .added
+                    .iter()
+                    .map(|d| d.domain.as_str())
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                vec!["cdn.spam.example", "abuse.example", "not a domain"],
+                result.skipped
+            );
+        }
+        assert!(matches!(
+            usecase.ensure_federates_with("abuse.example").await,
+            Err(DomainError::DomainNotFederated(_))
+        ));
+        assert_eq!(2, audit_log_repository.actions().len());
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    net::IpAddr,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::DomainError,
+    models::{
+        audit_log::{AuditLogEntry, AuditTarget},
+        ip_block::{IpBlock, IpBlockSeverity},
+        user::User,
+    },
+    repositories::{
+        audit_log_repository::AuditLogRepository, ip_block_repository::IpBlockRepository,
+    },
+};
+
+/// How long the rules evaluated on every request are reused before being reloaded
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Active block matching an address
+#[derive(Debug, Clone)]
+pub struct IpBlockMatch {
+    pub id: Uuid,
+    pub severity: IpBlockSeverity,
+}
+
+/// Admin-managed IP blocks, and their evaluation against incoming requests
+pub struct IpBlockUsecase<B: IpBlockRepository, A: AuditLogRepository> {
+    ip_block_repository: B,
+    audit_log_repository: A,
+    cache: RwLock<Option<(Instant, Arc<Vec<IpBlock>>)>>,
+}
+
+impl<B: IpBlockRepository + Send + Sync, A: AuditLogRepository + Send + Sync> IpBlockUsecase<B, A> {
+    pub fn new(ip_block_repository: B, audit_log_repository: A) -> Self {
+        Self {
+            ip_block_repository,
+            audit_log_repository,
+            cache: RwLock::new(None),
+        }
+    }
+
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn list(&self, admin: &User) -> Result<Vec<IpBlock>, DomainError> {
+        admin.ensure_admin()?;
+        Ok(self.ip_block_repository.list().await?)
+    }
+
+    #[instrument(skip(self, admin, comment), fields(admin_id = %admin.id()))]
+    pub async fn create(
+        &self,
+        admin: &User,
+        network: &str,
+        severity: IpBlockSeverity,
+        comment: String,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<IpBlock, DomainError> {
+        admin.ensure_admin()?;
+
+        let block = IpBlock::new(network, severity, comment, expires_at)?;
+        self.ip_block_repository.create(&block).await?;
+        self.invalidate();
+
+        let entry = AuditLogEntry::new(
+            Some(admin.id()),
+            "create",
+            AuditTarget::IpBlock(block.id),
+            Some(format!("{} {}", block.network, severity.as_str())),
+        );
+        self.audit_log_repository.append(&entry).await?;
+
+        Ok(block)
+    }
+
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn delete(&self, admin: &User, id: Uuid) -> Result<(), DomainError> {
+        admin.ensure_admin()?;
+
+        self.ip_block_repository.delete(id).await?;
+        self.invalidate();
+
+        let entry = AuditLogEntry::new(Some(admin.id()), "delete", AuditTarget::IpBlock(id), None);
+        Ok(self.audit_log_repository.append(&entry).await?)
+    }
+
+    /// Strictest active block covering the address, if any
+    pub async fn evaluate(&self, ip: IpAddr) -> Result<Option<IpBlockMatch>, DomainError> {
+        let blocks = self.blocks().await?;
+        let now = Utc::now();
+
+        Ok(blocks
+            .iter()
+            .filter(|block| block.matches(ip, now))
+            .max_by_key(|block| block.severity)
+            .map(|block| IpBlockMatch {
+                id: block.id,
+                severity: block.severity,
+            }))
+    }
+
+    /// helper function that return the cached blocks, reloading them once stale
+    async fn blocks(&self) -> Result<Arc<Vec<IpBlock>>, DomainError> {
+        if let Some((loaded_at, blocks)) = self.cache.read().unwrap().as_ref()
+            && loaded_at.elapsed() < CACHE_TTL
+        {
+            return Ok(blocks.clone());
+        }
+
+        let blocks = Arc::new(self.ip_block_repository.list().await?);
+        *self.cache.write().unwrap() = Some((Instant::now(), blocks.clone()));
+        Ok(blocks)
+    }
+
+    fn invalidate(&self) {
+        *self.cache.write().unwrap() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::{fixtures::UserBuilder, user::Role},
+        infrastructure::in_memory::{
+            audit_log_repository::InMemoryAuditLogRepository,
+            ip_block_repository::InMemoryIpBlockRepository,
+        },
+    };
+
+    type TestIpBlockUsecase = IpBlockUsecase<InMemoryIpBlockRepository, InMemoryAuditLogRepository>;
+
+    /// # Description
+    /// build the usecase over empty in-memory repositories, with an admin to manage blocks
+    fn setup() -> (TestIpBlockUsecase, InMemoryAuditLogRepository, User) {
+        let audit_log_repository = InMemoryAuditLogRepository::new();
+        let usecase = IpBlockUsecase::new(
+            InMemoryIpBlockRepository::new(),
+            audit_log_repository.clone(),
+        );
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+        (usecase, audit_log_repository, admin)
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_strictest_match_positive() {
+        let (usecase, audit_log_repository, admin) = setup();
+        usecase
+            .create(
+                &admin,
+                "203.0.113.0/24",
+                IpBlockSeverity::SignUpRequiresApproval,
+                String::new(),
+                None,
+            )
+            .await
+            .unwrap();
+        let block = usecase
+            .create(
+                &admin,
+                "203.0.113.7",
+                IpBlockSeverity::NoAccess,
+                String::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let found = usecase
+            .evaluate("203.0.113.7".parse().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(block.id, found.id);
+        assert_eq!(IpBlockSeverity::NoAccess, found.severity);
+        assert_eq!(
+            vec!["ip_block.create", "ip_block.create"],
+            audit_log_repository.actions()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_after_delete_negative() {
+        let (usecase, _, admin) = setup();
+        let block = usecase
+            .create(
+                &admin,
+                "2001:db8::/32",
+                IpBlockSeverity::SignUpBlock,
+                String::new(),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(
+            usecase
+                .evaluate("2001:db8::1".parse().unwrap())
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        usecase.delete(&admin, block.id).await.unwrap();
+
+        assert!(
+            usecase
+                .evaluate("2001:db8::1".parse().unwrap())
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_as_moderator_negative() {
+        let (usecase, _, _) = setup();
+        let moderator = UserBuilder::default().build().with_role(Role::Moderator);
+
+        let result = usecase
+            .create(
+                &moderator,
+                "203.0.113.0/24",
+                IpBlockSeverity::NoAccess,
+                String::new(),
+                None,
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+}
@@ -0,0 +1,194 @@
+use tracing::instrument;
+
+use crate::domain::{
+    error::{DomainError, RepositoryError},
+    models::{
+        audit_log::{AuditLogEntry, AuditTarget},
+        legal::{LegalAcceptance, LegalDocument, LegalDocumentKind},
+        user::User,
+    },
+    repositories::{
+        audit_log_repository::AuditLogRepository,
+        legal_document_repository::LegalDocumentRepository,
+    },
+};
+
+/// Terms of service and privacy policy, published by admins and accepted by users
+pub struct LegalUsecase<D: LegalDocumentRepository, A: AuditLogRepository> {
+    legal_document_repository: D,
+    audit_log_repository: A,
+}
+
+impl<D: LegalDocumentRepository + Send + Sync, A: AuditLogRepository + Send + Sync>
+    LegalUsecase<D, A>
+{
+    pub fn new(legal_document_repository: D, audit_log_repository: A) -> Self {
+        Self {
+            legal_document_repository,
+            audit_log_repository,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn current(&self, kind: LegalDocumentKind) -> Result<LegalDocument, DomainError> {
+        Ok(self
+            .legal_document_repository
+            .current(kind)
+            .await?
+            .ok_or(RepositoryError::NotFound)?)
+    }
+
+    /// Publish a new version, every user is asked to accept it again
+    #[instrument(skip(self, admin, content), fields(admin_id = %admin.id()))]
+    pub async fn publish(
+        &self,
+        admin: &User,
+        kind: LegalDocumentKind,
+        content: String,
+    ) -> Result<LegalDocument, DomainError> {
+        admin.ensure_admin()?;
+
+        let previous = self.legal_document_repository.current(kind).await?;
+        let document = LegalDocument::publish(kind, content, previous.as_ref())?;
+        self.legal_document_repository.publish(&document).await?;
+
+        let entry = AuditLogEntry::new(
+            Some(admin.id()),
+            "publish",
+            AuditTarget::LegalDocument(document.id),
+            Some(format!("{} v{}", kind.as_str(), document.version)),
+        );
+        self.audit_log_repository.append(&entry).await?;
+
+        Ok(document)
+    }
+
+    /// Current documents the user has not accepted yet (or only in an older version)
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn pending(&self, user: &User) -> Result<Vec<LegalDocument>, DomainError> {
+        let acceptances = self
+            .legal_document_repository
+            .acceptances(user.id())
+            .await?;
+
+        let mut pending = Vec::new();
+        for kind in LegalDocumentKind::ALL {
+            let Some(document) = self.legal_document_repository.current(kind).await? else {
+                continue;
+            };
+            let accepted = acceptances.iter().any(|acceptance| {
+                acceptance.kind == kind && acceptance.version >= document.version
+            });
+            if !accepted {
+                pending.push(document);
+            }
+        }
+        Ok(pending)
+    }
+
+    /// Accept `version` of a document, which must be the current one
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn accept(
+        &self,
+        user: &User,
+        kind: LegalDocumentKind,
+        version: i32,
+    ) -> Result<(), DomainError> {
+        let document = self.current(kind).await?;
+        if document.version != version {
+            return Err(DomainError::OutdatedAgreement);
+        }
+
+        Ok(self
+            .legal_document_repository
+            .accept(&LegalAcceptance::of(user.id(), &document))
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::{fixtures::UserBuilder, user::Role},
+        infrastructure::in_memory::{
+            audit_log_repository::InMemoryAuditLogRepository,
+            legal_document_repository::InMemoryLegalDocumentRepository,
+        },
+    };
+
+    fn setup() -> LegalUsecase<InMemoryLegalDocumentRepository, InMemoryAuditLogRepository> {
+        LegalUsecase::new(
+            InMemoryLegalDocumentRepository::new(),
+            InMemoryAuditLogRepository::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_new_version_is_pending_again_positive() {
+        let usecase = setup();
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+        let user = UserBuilder::default().build();
+
+        let first = usecase
+            .publish(&admin, LegalDocumentKind::TermsOfService, "v1".to_string())
+            .await
+            .unwrap();
+        usecase
+            .accept(&user, LegalDocumentKind::TermsOfService, first.version)
+            .await
+            .unwrap();
+        assert!(usecase.pending(&user).await.unwrap().is_empty());
+
+        let second = usecase
+            .publish(&admin, LegalDocumentKind::TermsOfService, "v2".to_string())
+            .await
+            .unwrap();
+
+        let pending = usecase.pending(&user).await.unwrap();
+        assert_eq!(2, second.version);
+        assert_eq!(
+            vec![2],
+            pending.iter().map(|d| d.version).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_accept_outdated_version_negative() {
+        let usecase = setup();
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+        let user = UserBuilder::default().build();
+        for content in ["v1", "v2"] {
+            usecase
+                .publish(
+                    &admin,
+                    LegalDocumentKind::PrivacyPolicy,
+                    content.to_string(),
+                )
+                .await
+                .unwrap();
+        }
+
+        let result = usecase
+            .accept(&user, LegalDocumentKind::PrivacyPolicy, 1)
+            .await;
+
+        assert!(matches!(result, Err(DomainError::OutdatedAgreement)));
+    }
+
+    #[tokio::test]
+    async fn test_publish_as_moderator_negative() {
+        let usecase = setup();
+        let moderator = UserBuilder::default().build().with_role(Role::Moderator);
+
+        let result = usecase
+            .publish(
+                &moderator,
+                LegalDocumentKind::TermsOfService,
+                "v1".to_string(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+}
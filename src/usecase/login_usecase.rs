@@ -1,8 +1,19 @@
+use chrono::Utc;
+use tracing::instrument;
+
 use crate::domain::{
-    error::{DomainError, RepositoryError},
-    models::user::{ActivityId, User},
-    repositories::{credential_repository::CredentialRepository, user_repository::UserRepository},
+    error::DomainError,
+    models::{
+        credential::Credential, email::EmailAddress, refresh_token::RefreshToken, secret,
+        user::User,
+    },
+    repositories::{
+        credential_repository::CredentialRepository,
+        email_verification_repository::EmailVerificationRepository,
+        refresh_token_repository::RefreshTokenRepository, user_repository::UserRepository,
+    },
     services::{
+        email_service::{EmailSender, EmailTemplate},
         password_service::PasswordHasher,
         token_service::{Token, TokenGenerator},
     },
@@ -11,6 +22,8 @@ use crate::domain::{
 #[derive(Debug)]
 pub struct LoginResult {
     pub token: Token,
+    /// Exchanged for the next access token
+    pub refresh_token: String,
     pub user: User,
 }
 
@@ -19,44 +32,82 @@ pub struct LoginUsecase<
     U: UserRepository,
     P: PasswordHasher,
     T: TokenGenerator,
+    E: EmailSender,
+    R: RefreshTokenRepository,
+    V: EmailVerificationRepository,
 > {
     credential_repository: C,
     user_repository: U,
     password_hasher: P,
     token_generator: T,
+    email_sender: E,
+    refresh_token_repository: R,
+    email_verification_repository: V,
 }
 
-impl<C: CredentialRepository, U: UserRepository, P: PasswordHasher, T: TokenGenerator>
-    LoginUsecase<C, U, P, T>
+impl<
+    C: CredentialRepository,
+    U: UserRepository,
+    P: PasswordHasher,
+    T: TokenGenerator,
+    E: EmailSender,
+    R: RefreshTokenRepository,
+    V: EmailVerificationRepository,
+> LoginUsecase<C, U, P, T, E, R, V>
 {
     pub fn new(
         credential_repository: C,
         user_repository: U,
         password_hasher: P,
         token_generator: T,
+        email_sender: E,
+        refresh_token_repository: R,
+        email_verification_repository: V,
     ) -> Self {
         Self {
             credential_repository,
             user_repository,
             password_hasher,
             token_generator,
+            email_sender,
+            refresh_token_repository,
+            email_verification_repository,
         }
     }
 
+    #[instrument(skip(self, password))]
     pub async fn login(&self, user_id: String, password: String) -> Result<LoginResult, DomainError>
     where
         C: Send + Sync,
         U: Send + Sync,
         P: Send + Sync,
         T: Send + Sync,
+        R: Send + Sync,
+        V: Send + Sync,
     {
-        // Get credential from repository
-        let instance_host = dotenvy::var("INSTANCE_HOST").unwrap();
-        let activity_id = ActivityId::new(format!("https://{}/users/{}", instance_host, user_id))?;
-        let credential = self
+        // deleted accounts keep their row as a tombstone, they cannot log in
+        // unless the owner may still reactivate it, which is told once the password checks out
+        let user = match self.find_user(&user_id).await? {
+            Some(user) if !user.is_deleted() || user.can_reactivate() => user,
+            // a password is still hashed, so the response time does not tell the account is missing
+            _ => {
+                self.password_hasher.verify_dummy(&password);
+                return Err(DomainError::AuthenticationFailed);
+            }
+        };
+        let mut credential = self
             .credential_repository
-            .get_credential(activity_id)
+            .get_credential(user.activity_id())
             .await?;
+        // both rows are written together, a mismatch means the data is corrupt
+        if credential.user_id() != user.id() {
+            tracing::error!(
+                user_id = %user.id(),
+                credential_user_id = %credential.user_id(),
+                "credential belongs to another user"
+            );
+            return Err(DomainError::AuthenticationFailed);
+        }
 
         // Verify password using PasswordHasher
         let is_valid = self
@@ -64,16 +115,519 @@ impl<C: CredentialRepository, U: UserRepository, P: PasswordHasher, T: TokenGene
             .verify(&password, credential.password_hash())?;
         credential.validate(is_valid)?;
 
-        // Get user from repository
-        let user = self
-            .user_repository
-            .find_by_id(credential.id())
+        // imported hashes (bcrypt, ...) are replaced while the plain password is at hand
+        if self.password_hasher.needs_rehash(credential.password_hash()) {
+            self.rehash(&mut credential, &password).await;
+        }
+
+        if let Some(purge_at) = user.purge_at() {
+            return Err(DomainError::PendingDeletion(purge_at));
+        }
+        if self
+            .email_verification_repository
+            .is_pending(user.id())
             .await?
-            .ok_or(RepositoryError::NotFound)?;
+        {
+            return Err(DomainError::EmailNotVerified);
+        }
+        if !user.moderation().can_login() {
+            return Err(DomainError::AccountDisabled);
+        }
 
         // Generate token
         let token = self.token_generator.generate(&user)?;
+        let (refresh_token, refresh_value) = RefreshToken::issue(user.id());
+        self.refresh_token_repository.create(&refresh_token).await?;
+
+        // Notify the owner, a mail failure must not block the login
+        let notification = EmailTemplate::NewLogin {
+            display_name: user.display_name().to_string(),
+            logged_in_at: Utc::now(),
+        };
+        if let Err(e) = self
+            .email_sender
+            .send(notification.to_message(credential.email().as_str()))
+            .await
+        {
+            tracing::warn!(error = %e, "failed to queue new login notification");
+        }
+
+        Ok(LoginResult {
+            token,
+            refresh_token: refresh_value,
+            user,
+        })
+    }
+
+    /// Exchange a refresh token for a new access token and the next refresh token
+    /// A token presented twice was likely stolen, the whole session is then revoked
+    #[instrument(skip_all)]
+    pub async fn refresh(&self, refresh_token: &str) -> Result<LoginResult, DomainError>
+    where
+        U: Send + Sync,
+        T: Send + Sync,
+        R: Send + Sync,
+    {
+        let current = self
+            .refresh_token_repository
+            .find_by_hash(&secret::digest(refresh_token))
+            .await?
+            .ok_or(DomainError::AuthenticationFailed)?;
+        if current.is_expired() {
+            return Err(DomainError::AuthenticationFailed);
+        }
+        // revoked already, or by a concurrent exchange of the same token
+        let reused = current.revoked_at.is_some()
+            || !self.refresh_token_repository.revoke(current.id).await?;
+        if reused {
+            tracing::warn!(
+                user_id = %current.user_id,
+                family_id = %current.family_id,
+                "refresh token reused, revoking the session"
+            );
+            self.refresh_token_repository
+                .revoke_family(current.family_id)
+                .await?;
+            return Err(DomainError::AuthenticationFailed);
+        }
+
+        let user = match self.user_repository.find_by_id(current.user_id).await? {
+            Some(user) if !user.is_deleted() => user,
+            _ => return Err(DomainError::AuthenticationFailed),
+        };
+        if !user.moderation().can_login() {
+            return Err(DomainError::AccountDisabled);
+        }
+
+        let token = self.token_generator.generate(&user)?;
+        let (next, next_value) = current.rotate();
+        self.refresh_token_repository.create(&next).await?;
+
+        Ok(LoginResult {
+            token,
+            refresh_token: next_value,
+            user,
+        })
+    }
+
+    /// helper function that replace the stored hash with one in the current format
+    /// A failure is only logged, the old hash keeps working
+    async fn rehash(&self, credential: &mut Credential, password: &str)
+    where
+        C: Send + Sync,
+    {
+        let result = match self.password_hasher.hash(password) {
+            Ok(password_hash) => {
+                credential.change_password(password_hash);
+                self.credential_repository
+                    .update_credential(credential)
+                    .await
+                    .map_err(DomainError::from)
+            }
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => tracing::info!(user_id = %credential.user_id(), "password hash upgraded"),
+            Err(e) => tracing::warn!(error = %e, "failed to upgrade password hash"),
+        }
+    }
+
+    /// helper function that find the account by username, or else by registered email
+    async fn find_user(&self, identifier: &str) -> Result<Option<User>, DomainError>
+    where
+        C: Send + Sync,
+        U: Send + Sync,
+    {
+        // Usernames are matched regardless of case
+        if let Some(user) = self.user_repository.find_by_username(identifier).await? {
+            return Ok(Some(user));
+        }
+        let Ok(email) = EmailAddress::new(identifier.to_string()) else {
+            return Ok(None);
+        };
+        match self.credential_repository.find_by_email(&email).await? {
+            Some(credential) => Ok(self.user_repository.find_by_id(credential.user_id()).await?),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            models::{
+                credential::{HashAlgorithm, HashedPassword},
+                email_verification::EmailVerification,
+                fixtures::{CredentialBuilder, TEST_HOST, UserBuilder, activity_id},
+                moderation::{Moderation, ModerationAction},
+                user::Role,
+            },
+            repositories::user_registration_repository::UserRegistrationRepository,
+        },
+        infrastructure::{
+            argon2_password_hasher::Argon2PasswordHasher,
+            in_memory::{
+                credential_repository::InMemoryCredentialRepository,
+                email_verification_repository::InMemoryEmailVerificationRepository,
+                refresh_token_repository::InMemoryRefreshTokenRepository,
+                user_registration_repository::InMemoryUserRegistrationRepository,
+                user_repository::InMemoryUserRepository,
+            },
+            jwt_token_generator::JwtTokenGenerator,
+            log_email_sender::LogEmailSender,
+        },
+    };
+
+    type TestLoginUsecase = LoginUsecase<
+        InMemoryCredentialRepository,
+        InMemoryUserRepository,
+        Argon2PasswordHasher,
+        JwtTokenGenerator,
+        LogEmailSender,
+        InMemoryRefreshTokenRepository,
+        InMemoryEmailVerificationRepository,
+    >;
+
+    /// # Description
+    ///
+    /// Build the usecase over in-memory repositories
+    /// seeded with "test_user" / "test_password"
+    fn setup() -> TestLoginUsecase {
+        setup_with_moderation(Moderation::default())
+    }
+
+    /// # Description
+    ///
+    /// Same as `setup`, with "test_user" under the given moderation state
+    fn setup_with_moderation(moderation: Moderation) -> TestLoginUsecase {
+        setup_with_user(|user| user.with_moderation(moderation))
+    }
+
+    /// # Description
+    ///
+    /// Same as `setup`, with "test_user" passed through `customize` before it is stored
+    fn setup_with_user(customize: impl FnOnce(User) -> User) -> TestLoginUsecase {
+        let password_hasher = Argon2PasswordHasher::new();
+        let user = customize(
+            UserBuilder::default()
+                .activity_id(activity_id(TEST_HOST, "test_user"))
+                .build(),
+        );
+        let credential = CredentialBuilder::for_user(&user)
+            .password_hash(password_hasher.hash("test_password").unwrap())
+            .build();
+
+        let user_repository = InMemoryUserRepository::new();
+        user_repository.insert(user).unwrap();
+        let credential_repository = InMemoryCredentialRepository::new();
+        credential_repository.insert(credential).unwrap();
+
+        LoginUsecase::new(
+            credential_repository,
+            user_repository,
+            password_hasher,
+            JwtTokenGenerator::new("testtoken".to_string()),
+            LogEmailSender,
+            InMemoryRefreshTokenRepository::new(),
+            InMemoryEmailVerificationRepository::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_login_positive() {
+        let usecase = setup();
+
+        let result = usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await
+            .unwrap();
+
+        assert!(!result.token.value.is_empty());
+        assert_eq!("テスト", result.user.display_name());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotates_token_positive() {
+        let usecase = setup();
+        let login = usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await
+            .unwrap();
+        let first = login.refresh_token;
+
+        let refreshed = usecase.refresh(&first).await.unwrap();
+
+        let second = refreshed.refresh_token;
+        assert_ne!(first, second);
+        assert!(!refreshed.token.value.is_empty());
+        assert!(usecase.refresh(&second).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reuse_revokes_session_negative() {
+        let usecase = setup();
+        let login = usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await
+            .unwrap();
+        let first = login.refresh_token;
+        let second = usecase.refresh(&first).await.unwrap().refresh_token;
+
+        let reused = usecase.refresh(&first).await;
+
+        assert!(matches!(reused, Err(DomainError::AuthenticationFailed)));
+        assert!(matches!(
+            usecase.refresh(&second).await,
+            Err(DomainError::AuthenticationFailed)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_unknown_token_negative() {
+        let usecase = setup();
+
+        let result = usecase.refresh("unknown").await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_login_username_case_insensitive_positive() {
+        let usecase = setup();
+
+        let result = usecase
+            .login("Test_User".to_string(), "test_password".to_string())
+            .await
+            .unwrap();
+
+        assert!(result.user.activity_id().as_str().ends_with("/users/test_user"));
+    }
+
+    #[tokio::test]
+    async fn test_login_with_email_positive() {
+        let usecase = setup();
+
+        let result = usecase
+            .login("Test@Example.com".to_string(), "test_password".to_string())
+            .await
+            .unwrap();
+
+        assert!(result.user.activity_id().as_str().ends_with("/users/test_user"));
+    }
+
+    #[tokio::test]
+    async fn test_login_with_unknown_email_negative() {
+        let usecase = setup();
+
+        let result = usecase
+            .login("other@example.com".to_string(), "test_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_login_invalid_password_negative() {
+        let usecase = setup();
+
+        let result = usecase
+            .login("test_user".to_string(), "invalid_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_login_invalid_user_negative() {
+        let usecase = setup();
+
+        let result = usecase
+            .login("invalid_user".to_string(), "test_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_registered_credential_maps_to_user_positive() {
+        let users = InMemoryUserRepository::new();
+        let credentials = InMemoryCredentialRepository::new();
+        let activity_id = activity_id("example.com", "new_user");
+        let user = InMemoryUserRegistrationRepository::new(users.clone(), credentials.clone())
+            .register_user_with_credentials(
+                &activity_id,
+                "テスト",
+                Role::User,
+                false,
+                HashedPassword::new(String::new()),
+                EmailAddress::new("new@example.com".to_string()).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let credential = credentials.get_credential(&activity_id).await.unwrap();
+
+        assert_eq!(user.id(), credential.user_id());
+        assert_eq!(user.activity_id(), credential.activity_id());
+    }
+
+    #[tokio::test]
+    async fn test_login_credential_of_other_user_negative() {
+        let password_hasher = Argon2PasswordHasher::new();
+        let user = UserBuilder::default()
+            .activity_id(activity_id(TEST_HOST, "test_user"))
+            .build();
+        // same activity id, but owned by another account
+        let credential = CredentialBuilder::default()
+            .activity_id(user.activity_id().clone())
+            .password_hash(password_hasher.hash("test_password").unwrap())
+            .build();
+        let user_repository = InMemoryUserRepository::new();
+        user_repository.insert(user).unwrap();
+        let credential_repository = InMemoryCredentialRepository::new();
+        credential_repository.insert(credential).unwrap();
+        let usecase = LoginUsecase::new(
+            credential_repository,
+            user_repository,
+            password_hasher,
+            JwtTokenGenerator::new("testtoken".to_string()),
+            LogEmailSender,
+            InMemoryRefreshTokenRepository::new(),
+            InMemoryEmailVerificationRepository::new(),
+        );
+
+        let result = usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_login_suspended_negative() {
+        let mut moderation = Moderation::default();
+        moderation.apply(ModerationAction::Suspend);
+        let usecase = setup_with_moderation(moderation);
+
+        let result = usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::AccountDisabled)));
+    }
+
+    #[tokio::test]
+    async fn test_login_pending_deletion_negative() {
+        let usecase = setup_with_user(|mut user| {
+            user.request_deletion().unwrap();
+            user
+        });
+
+        let result = usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::PendingDeletion(_))));
+    }
+
+    #[tokio::test]
+    async fn test_login_pending_deletion_invalid_password_negative() {
+        let usecase = setup_with_user(|mut user| {
+            user.request_deletion().unwrap();
+            user
+        });
+
+        let result = usecase
+            .login("test_user".to_string(), "invalid_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_login_unverified_email_negative() {
+        let password_hasher = Argon2PasswordHasher::new();
+        let user = UserBuilder::default().build();
+        let credential = CredentialBuilder::for_user(&user)
+            .password_hash(password_hasher.hash("test_password").unwrap())
+            .build();
+        let user_repository = InMemoryUserRepository::new();
+        user_repository.insert(user.clone()).unwrap();
+        let credential_repository = InMemoryCredentialRepository::new();
+        credential_repository.insert(credential).unwrap();
+        let email_verification_repository = InMemoryEmailVerificationRepository::new();
+        let (verification, _) = EmailVerification::issue(user.id());
+        email_verification_repository
+            .create(&verification)
+            .await
+            .unwrap();
+        let usecase = LoginUsecase::new(
+            credential_repository,
+            user_repository,
+            password_hasher,
+            JwtTokenGenerator::new("testtoken".to_string()),
+            LogEmailSender,
+            InMemoryRefreshTokenRepository::new(),
+            email_verification_repository,
+        );
+
+        let result = usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::EmailNotVerified)));
+    }
+
+    #[tokio::test]
+    async fn test_login_deleted_by_moderator_negative() {
+        let usecase = setup_with_user(|mut user| {
+            user.delete();
+            user
+        });
+
+        let result = usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::AuthenticationFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_login_rehashes_bcrypt_password_positive() {
+        let user = UserBuilder::default().build();
+        let credential = CredentialBuilder::for_user(&user)
+            .password_hash(HashedPassword::new(
+                bcrypt::hash("test_password", 4).unwrap(),
+            ))
+            .build();
+        let user_repository = InMemoryUserRepository::new();
+        user_repository.insert(user.clone()).unwrap();
+        let credential_repository = InMemoryCredentialRepository::new();
+        credential_repository.insert(credential).unwrap();
+        let usecase = LoginUsecase::new(
+            credential_repository.clone(),
+            user_repository,
+            Argon2PasswordHasher::new(),
+            JwtTokenGenerator::new("testtoken".to_string()),
+            LogEmailSender,
+            InMemoryRefreshTokenRepository::new(),
+            InMemoryEmailVerificationRepository::new(),
+        );
+
+        usecase
+            .login("test_user".to_string(), "test_password".to_string())
+            .await
+            .unwrap();
 
-        Ok(LoginResult { token, user })
+        let stored = credential_repository
+            .get_credential(user.activity_id())
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(HashAlgorithm::Argon2id),
+            stored.password_hash().algorithm()
+        );
     }
 }
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use tracing::instrument;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        repositories::{
+            audit_log_repository::AuditLogRepository,
+            federation_domain_repository::FederationDomainRepository,
+        },
+        services::media_proxy_service::{MediaFetcher, RemoteMedia, UrlSigner},
+    },
+    usecase::federation_usecase::FederationUsecase,
+};
+
+pub struct MediaProxyUsecase<
+    S: UrlSigner,
+    F: MediaFetcher,
+    D: FederationDomainRepository,
+    A: AuditLogRepository,
+> {
+    signer: S,
+    fetcher: F,
+    /// Remote files are only relayed from hosts the instance federates with
+    federation: Arc<FederationUsecase<D, A>>,
+}
+
+impl<S: UrlSigner, F: MediaFetcher, D: FederationDomainRepository, A: AuditLogRepository>
+    MediaProxyUsecase<S, F, D, A>
+{
+    pub fn new(signer: S, fetcher: F, federation: Arc<FederationUsecase<D, A>>) -> Self {
+        Self {
+            signer,
+            fetcher,
+            federation,
+        }
+    }
+
+    /// Fetch the remote file behind a signed path
+    #[instrument(skip_all)]
+    pub async fn fetch(&self, signed_path: &str) -> Result<RemoteMedia, DomainError>
+    where
+        S: Send + Sync,
+        F: Send + Sync,
+        D: Send + Sync,
+        A: Send + Sync,
+    {
+        let url = self.signer.verify(signed_path)?;
+        let host = url::Url::parse(&url)
+            .ok()
+            .and_then(|parsed| parsed.host_str().map(str::to_string))
+            .ok_or(DomainError::InvalidSignature)?;
+        self.federation.ensure_federates_with(&host).await?;
+
+        tracing::debug!(%url, "proxying remote media");
+        self.fetcher.fetch(&url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::{
+        domain::models::{
+            federation::{DomainPolicy, FederationMode},
+            fixtures::UserBuilder,
+            user::Role,
+        },
+        infrastructure::in_memory::{
+            audit_log_repository::InMemoryAuditLogRepository,
+            federation_domain_repository::InMemoryFederationDomainRepository,
+        },
+    };
+
+    type TestMediaProxyUsecase = MediaProxyUsecase<
+        PrefixSigner,
+        StaticFetcher,
+        InMemoryFederationDomainRepository,
+        InMemoryAuditLogRepository,
+    >;
+
+    /// # Description
+    /// Signer accepting any URL behind a `signed:` prefix
+    #[derive(Clone)]
+    struct PrefixSigner;
+
+    impl UrlSigner for PrefixSigner {
+        fn verify(&self, signed: &str) -> Result<String, DomainError> {
+            signed
+                .strip_prefix("signed:")
+                .map(str::to_string)
+                .ok_or(DomainError::InvalidSignature)
+        }
+    }
+
+    /// # Description
+    /// Fetcher answering every URL with a fixed PNG body
+    #[derive(Clone)]
+    struct StaticFetcher;
+
+    #[async_trait]
+    impl MediaFetcher for StaticFetcher {
+        async fn fetch(&self, _url: &str) -> Result<RemoteMedia, DomainError> {
+            Ok(RemoteMedia {
+                content_type: "image/png".to_string(),
+                body: vec![0x89, b'P', b'N', b'G'],
+            })
+        }
+    }
+
+    /// # Description
+    /// Proxy federating with every host, along with its domain list to block some
+    fn setup() -> (
+        TestMediaProxyUsecase,
+        Arc<FederationUsecase<InMemoryFederationDomainRepository, InMemoryAuditLogRepository>>,
+    ) {
+        let federation = Arc::new(FederationUsecase::new(
+            FederationMode::Blocklist,
+            InMemoryFederationDomainRepository::new(),
+            InMemoryAuditLogRepository::new(),
+        ));
+        let usecase = MediaProxyUsecase::new(PrefixSigner, StaticFetcher, federation.clone());
+        (usecase, federation)
+    }
+
+    fn usecase() -> TestMediaProxyUsecase {
+        setup().0
+    }
+
+    #[tokio::test]
+    async fn test_fetch_positive() {
+        let media = usecase()
+            .fetch("signed:https://remote.example/cat.png")
+            .await
+            .unwrap();
+
+        assert_eq!("image/png", media.content_type);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_unsigned_negative() {
+        let result = usecase().fetch("aHR0cHM6Ly9yZW1vdGUuZXhhbXBsZS9jYXQucG5n").await;
+
+        assert!(matches!(result, Err(DomainError::InvalidSignature)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_blocked_domain_negative() {
+        let (usecase, federation) = setup();
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+        federation
+            .create(&admin, "remote.example", DomainPolicy::Block, String::new())
+            .await
+            .unwrap();
+        let result = usecase
+            .fetch("signed:https://media.remote.example/cat.png")
+            .await;
+
+        assert!(matches!(result, Err(DomainError::DomainNotFederated(_))));
+    }
+}
@@ -1,2 +1,19 @@
+pub mod account_deletion_usecase;
+pub mod admin_usecase;
+pub mod alias_usecase;
+pub mod audit_log_usecase;
+pub mod blocklist_subscription_usecase;
+pub mod email_verification_usecase;
+pub mod endorsement_usecase;
+pub mod auth_usecase;
+pub mod federation_usecase;
+pub mod ip_block_usecase;
+pub mod legal_usecase;
 pub mod register_user_usecase;
 pub mod login_usecase;
+pub mod media_proxy_usecase;
+pub mod moderation_usecase;
+pub mod notification_preferences_usecase;
+pub mod profile_usecase;
+pub mod report_usecase;
+pub mod rule_usecase;
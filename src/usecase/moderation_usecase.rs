@@ -0,0 +1,275 @@
+use chrono::{Duration, Utc};
+use tracing::instrument;
+
+use crate::domain::{
+    error::{DomainError, RepositoryError},
+    models::{
+        audit_log::{AuditLogEntry, AuditTarget},
+        moderation::ModerationAction,
+        user::{Role, User, UserId},
+    },
+    repositories::{audit_log_repository::AuditLogRepository, user_repository::UserRepository},
+};
+
+/// Restrictions placed on accounts by moderators (disable, silence, suspend)
+pub struct ModerationUsecase<U: UserRepository, A: AuditLogRepository> {
+    user_repository: U,
+    audit_log_repository: A,
+}
+
+impl<U: UserRepository + Send + Sync, A: AuditLogRepository + Send + Sync> ModerationUsecase<U, A> {
+    pub fn new(user_repository: U, audit_log_repository: A) -> Self {
+        Self {
+            user_repository,
+            audit_log_repository,
+        }
+    }
+
+    /// Apply `action` to the account `target_id`
+    #[instrument(skip(self, moderator, reason), fields(moderator_id = %moderator.id()))]
+    pub async fn apply(
+        &self,
+        moderator: &User,
+        target_id: UserId,
+        action: ModerationAction,
+        reason: Option<String>,
+    ) -> Result<User, DomainError> {
+        let mut target = self.find_target(moderator, target_id).await?;
+
+        target.moderation_mut().apply(action);
+        self.user_repository.update_moderation(&target).await?;
+        self.audit_log_repository
+            .append(&AuditLogEntry::new(
+                Some(moderator.id()),
+                action.as_str(),
+                AuditTarget::Account(target.id()),
+                reason,
+            ))
+            .await?;
+
+        tracing::info!(target_id = %target.id(), action = action.as_str(), "moderation action applied");
+        Ok(target)
+    }
+
+    /// Delete the account `target_id`, it answers 410 Gone until purged
+    #[instrument(skip(self, moderator, reason), fields(moderator_id = %moderator.id()))]
+    pub async fn delete(
+        &self,
+        moderator: &User,
+        target_id: UserId,
+        reason: Option<String>,
+    ) -> Result<User, DomainError> {
+        let mut target = self.find_target(moderator, target_id).await?;
+
+        target.delete();
+        self.user_repository.mark_deleted(&target).await?;
+        self.audit_log_repository
+            .append(&AuditLogEntry::new(
+                Some(moderator.id()),
+                "delete",
+                AuditTarget::Account(target.id()),
+                reason,
+            ))
+            .await?;
+
+        tracing::info!(target_id = %target.id(), "account deleted");
+        Ok(target)
+    }
+
+    /// Grant or revoke the verified badge of `target_id`, admins only
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn set_verified(
+        &self,
+        admin: &User,
+        target_id: UserId,
+        verified: bool,
+    ) -> Result<User, DomainError> {
+        admin.ensure_admin()?;
+
+        let mut target = self
+            .user_repository
+            .find_by_id(target_id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+        target.ensure_not_deleted()?;
+
+        target.set_verified(verified);
+        self.user_repository.update_verification(&target).await?;
+        self.audit_log_repository
+            .append(&AuditLogEntry::new(
+                Some(admin.id()),
+                if verified { "verify" } else { "unverify" },
+                AuditTarget::Account(target.id()),
+                None,
+            ))
+            .await?;
+
+        Ok(target)
+    }
+
+    /// Remove the accounts deleted more than `retention` ago, for the cleanup job
+    #[instrument(skip(self))]
+    pub async fn purge_deleted(&self, retention: Duration) -> Result<u64, DomainError> {
+        let purged = self
+            .user_repository
+            .purge_deleted(Utc::now() - retention)
+            .await?;
+        tracing::info!(purged, "deleted accounts purged");
+        Ok(purged)
+    }
+
+    /// helper function that load an account the moderator may act on
+    /// Moderators cannot act on staff accounts, nobody can act on their own account
+    async fn find_target(&self, moderator: &User, target_id: UserId) -> Result<User, DomainError> {
+        moderator.ensure_staff()?;
+
+        let target = self
+            .user_repository
+            .find_by_id(target_id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+        target.ensure_not_deleted()?;
+        if target.id() == moderator.id()
+            || (target.role().is_staff() && moderator.role() != Role::Admin)
+        {
+            return Err(DomainError::Forbidden);
+        }
+        Ok(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::fixtures::{TEST_HOST, UserBuilder, activity_id},
+        infrastructure::in_memory::{
+            audit_log_repository::InMemoryAuditLogRepository,
+            user_repository::InMemoryUserRepository,
+        },
+    };
+
+    type TestModerationUsecase =
+        ModerationUsecase<InMemoryUserRepository, InMemoryAuditLogRepository>;
+
+    /// # Description
+    /// Usecase over an in-memory repository holding a user, a moderator and an admin
+    fn setup() -> (TestModerationUsecase, InMemoryAuditLogRepository, User, User, User) {
+        let user_repository = InMemoryUserRepository::new();
+        let user = UserBuilder::default().build();
+        let moderator = UserBuilder::default()
+            .activity_id(activity_id(TEST_HOST, "moderator"))
+            .build()
+            .with_role(Role::Moderator);
+        let admin = UserBuilder::default()
+            .activity_id(activity_id(TEST_HOST, "admin"))
+            .build()
+            .with_role(Role::Admin);
+        for account in [&user, &moderator, &admin] {
+            user_repository.insert(account.clone()).unwrap();
+        }
+
+        let audit_log_repository = InMemoryAuditLogRepository::new();
+        let usecase = ModerationUsecase::new(user_repository, audit_log_repository.clone());
+        (usecase, audit_log_repository, user, moderator, admin)
+    }
+
+    #[tokio::test]
+    async fn test_suspend_user_positive() {
+        let (usecase, audit_log, user, moderator, _) = setup();
+
+        let suspended = usecase
+            .apply(&moderator, user.id(), ModerationAction::Suspend, None)
+            .await
+            .unwrap();
+
+        assert!(suspended.moderation().is_suspended());
+        assert!(!suspended.moderation().can_login());
+        assert_eq!(vec!["account.suspend".to_string()], audit_log.actions());
+    }
+
+    #[tokio::test]
+    async fn test_moderator_suspend_admin_negative() {
+        let (usecase, audit_log, _, moderator, admin) = setup();
+
+        let result = usecase
+            .apply(&moderator, admin.id(), ModerationAction::Suspend, None)
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+        assert!(audit_log.actions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_user_silence_negative() {
+        let (usecase, _, user, moderator, _) = setup();
+
+        let result = usecase
+            .apply(&user, moderator.id(), ModerationAction::Silence, None)
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_positive() {
+        let (usecase, audit_log, user, moderator, _) = setup();
+
+        let deleted = usecase.delete(&moderator, user.id(), None).await.unwrap();
+        assert!(deleted.is_deleted());
+        assert_eq!(vec!["account.delete".to_string()], audit_log.actions());
+
+        let result = usecase
+            .apply(&moderator, user.id(), ModerationAction::Silence, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(DomainError::Gone(tombstone)) if tombstone.id == user.activity_id().as_str()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_purge_deleted_keeps_recent_positive() {
+        let (usecase, _, user, moderator, _) = setup();
+        usecase.delete(&moderator, user.id(), None).await.unwrap();
+
+        assert_eq!(0, usecase.purge_deleted(Duration::days(30)).await.unwrap());
+        assert_eq!(1, usecase.purge_deleted(Duration::zero()).await.unwrap());
+
+        let result = usecase
+            .apply(&moderator, user.id(), ModerationAction::Silence, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::NotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_admin_verify_user_positive() {
+        let (usecase, audit_log, user, _, admin) = setup();
+
+        let verified = usecase.set_verified(&admin, user.id(), true).await.unwrap();
+        assert!(verified.verified_at().is_some());
+
+        let unverified = usecase
+            .set_verified(&admin, user.id(), false)
+            .await
+            .unwrap();
+        assert!(unverified.verified_at().is_none());
+        assert_eq!(
+            vec!["account.verify".to_string(), "account.unverify".to_string()],
+            audit_log.actions()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_moderator_verify_user_negative() {
+        let (usecase, audit_log, user, moderator, _) = setup();
+
+        let result = usecase.set_verified(&moderator, user.id(), true).await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+        assert!(audit_log.actions().is_empty());
+    }
+}
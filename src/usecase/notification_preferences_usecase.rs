@@ -0,0 +1,102 @@
+use tracing::instrument;
+
+use crate::domain::{
+    error::DomainError,
+    models::{
+        notification_preferences::{NotificationPreferences, NotificationPreferencesUpdate},
+        user::User,
+    },
+    repositories::notification_preferences_repository::NotificationPreferencesRepository,
+};
+
+/// Email notification settings of the calling user
+pub struct NotificationPreferencesUsecase<N: NotificationPreferencesRepository> {
+    preferences_repository: N,
+}
+
+impl<N: NotificationPreferencesRepository + Send + Sync> NotificationPreferencesUsecase<N> {
+    pub fn new(preferences_repository: N) -> Self {
+        Self {
+            preferences_repository,
+        }
+    }
+
+    /// Stored preferences, or the defaults when the user never changed them
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn get(&self, user: &User) -> Result<NotificationPreferences, DomainError> {
+        Ok(self
+            .preferences_repository
+            .find(user.id())
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::default_for(user.id())))
+    }
+
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn update(
+        &self,
+        user: &User,
+        update: NotificationPreferencesUpdate,
+    ) -> Result<NotificationPreferences, DomainError> {
+        let mut preferences = self.get(user).await?;
+        preferences.apply(update);
+        self.preferences_repository.save(&preferences).await?;
+
+        Ok(preferences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::{fixtures::UserBuilder, notification_preferences::DigestFrequency},
+        infrastructure::in_memory::notification_preferences_repository::InMemoryNotificationPreferencesRepository,
+    };
+
+    fn setup() -> NotificationPreferencesUsecase<InMemoryNotificationPreferencesRepository> {
+        NotificationPreferencesUsecase::new(InMemoryNotificationPreferencesRepository::new())
+    }
+
+    #[tokio::test]
+    async fn test_defaults_positive() {
+        let usecase = setup();
+        let user = UserBuilder::default().build();
+
+        let preferences = usecase.get(&user).await.unwrap();
+
+        assert!(preferences.email_on_mention);
+        assert_eq!(DigestFrequency::Never, preferences.digest);
+    }
+
+    #[tokio::test]
+    async fn test_update_keeps_omitted_fields_positive() {
+        let usecase = setup();
+        let user = UserBuilder::default().build();
+
+        usecase
+            .update(
+                &user,
+                NotificationPreferencesUpdate {
+                    email_on_follow: Some(false),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        usecase
+            .update(
+                &user,
+                NotificationPreferencesUpdate {
+                    digest: Some(DigestFrequency::Weekly),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let preferences = usecase.get(&user).await.unwrap();
+        assert!(!preferences.email_on_follow);
+        assert!(preferences.email_on_report_resolved);
+        assert_eq!(DigestFrequency::Weekly, preferences.digest);
+    }
+}
@@ -0,0 +1,265 @@
+use chrono::{DateTime, Utc};
+use tracing::instrument;
+
+use crate::domain::{
+    error::{DomainError, RepositoryError},
+    models::{
+        posting_preferences::{PostingPreferences, PostingPreferencesUpdate},
+        user::{ProfileUpdate, User},
+    },
+    repositories::{
+        posting_preferences_repository::PostingPreferencesRepository,
+        user_repository::UserRepository,
+    },
+};
+
+/// Profile and posting defaults of the calling user
+pub struct ProfileUsecase<U: UserRepository, P: PostingPreferencesRepository> {
+    user_repository: U,
+    posting_preferences_repository: P,
+}
+
+impl<U: UserRepository + Send + Sync, P: PostingPreferencesRepository + Send + Sync>
+    ProfileUsecase<U, P>
+{
+    pub fn new(user_repository: U, posting_preferences_repository: P) -> Self {
+        Self {
+            user_repository,
+            posting_preferences_repository,
+        }
+    }
+
+    /// Stored posting defaults, or the defaults when the user never changed them
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn posting_preferences(
+        &self,
+        user: &User,
+    ) -> Result<PostingPreferences, DomainError> {
+        Ok(self
+            .posting_preferences_repository
+            .find(user.id())
+            .await?
+            .unwrap_or_else(|| PostingPreferences::default_for(user.id())))
+    }
+
+    /// Change the settings applied to new statuses that omit them
+    #[instrument(skip(self, user), fields(user_id = %user.id()))]
+    pub async fn update_posting_preferences(
+        &self,
+        user: &User,
+        update: PostingPreferencesUpdate,
+    ) -> Result<PostingPreferences, DomainError> {
+        let mut preferences = self.posting_preferences(user).await?;
+        preferences.apply(update)?;
+        self.posting_preferences_repository
+            .save(&preferences)
+            .await?;
+
+        Ok(preferences)
+    }
+
+    /// Edit the caller's profile
+    /// `expected_updated_at` is the version the client edited, the update fails with
+    /// `Outdated` when `user` (loaded for this request) changed since, instead of
+    /// overwriting the other edit
+    #[instrument(skip(self, user, update), fields(user_id = %user.id()))]
+    pub async fn update(
+        &self,
+        user: &User,
+        update: ProfileUpdate,
+        expected_updated_at: DateTime<Utc>,
+    ) -> Result<User, DomainError> {
+        if expected_updated_at != user.updated_at() {
+            return Err(RepositoryError::Outdated.into());
+        }
+
+        let mut updated = user.clone();
+        updated.update_profile(update)?;
+        self.user_repository
+            .update_profile(&updated, expected_updated_at)
+            .await?;
+
+        Ok(updated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::{
+            fixtures::UserBuilder, posting_preferences::Visibility, user::ThemeColor,
+        },
+        infrastructure::in_memory::{
+            posting_preferences_repository::InMemoryPostingPreferencesRepository,
+            user_repository::InMemoryUserRepository,
+        },
+    };
+
+    type TestProfileUsecase =
+        ProfileUsecase<InMemoryUserRepository, InMemoryPostingPreferencesRepository>;
+
+    /// # Description
+    /// Usecase over in-memory repositories holding one user
+    fn setup() -> (TestProfileUsecase, User) {
+        let user_repository = InMemoryUserRepository::new();
+        let user = UserBuilder::default().build();
+        user_repository.insert(user.clone()).unwrap();
+        let usecase =
+            ProfileUsecase::new(user_repository, InMemoryPostingPreferencesRepository::new());
+        (usecase, user)
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_positive() {
+        let (usecase, user) = setup();
+
+        let updated = usecase
+            .update(
+                &user,
+                ProfileUpdate {
+                    summary: Some("hello".to_string()),
+                    ..Default::default()
+                },
+                user.updated_at(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!("hello", updated.summary());
+        assert_eq!(user.display_name(), updated.display_name());
+        assert_ne!(user.updated_at(), updated.updated_at());
+    }
+
+    #[tokio::test]
+    async fn test_update_theme_color_positive() {
+        let (usecase, user) = setup();
+
+        let updated = usecase
+            .update(
+                &user,
+                ProfileUpdate {
+                    theme_color: Some(Some(ThemeColor::new("#FF8800").unwrap())),
+                    ..Default::default()
+                },
+                user.updated_at(),
+            )
+            .await
+            .unwrap();
+        assert_eq!("#ff8800", updated.theme_color().unwrap().as_str());
+
+        let cleared = usecase
+            .update(
+                &updated,
+                ProfileUpdate {
+                    theme_color: Some(None),
+                    ..Default::default()
+                },
+                updated.updated_at(),
+            )
+            .await
+            .unwrap();
+        assert!(cleared.theme_color().is_none());
+        assert!(matches!(
+            ThemeColor::new("orange"),
+            Err(DomainError::InvalidThemeColor)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_concurrent_edit_negative() {
+        let (usecase, user) = setup();
+        // both clients loaded the same version, the first one wins
+        let loaded_at = user.updated_at();
+        usecase
+            .update(
+                &user,
+                ProfileUpdate {
+                    display_name: Some("first".to_string()),
+                    ..Default::default()
+                },
+                loaded_at,
+            )
+            .await
+            .unwrap();
+
+        // the second request authenticates, so it edits the stored user
+        let reloaded = usecase
+            .user_repository
+            .find_by_id(user.id())
+            .await
+            .unwrap()
+            .unwrap();
+        let result = usecase
+            .update(
+                &reloaded,
+                ProfileUpdate {
+                    display_name: Some("second".to_string()),
+                    ..Default::default()
+                },
+                loaded_at,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::Outdated))
+        ));
+        let stored = usecase
+            .user_repository
+            .find_by_id(user.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!("first", stored.display_name());
+    }
+
+    #[tokio::test]
+    async fn test_update_profile_stale_version_negative() {
+        let (usecase, user) = setup();
+
+        let result = usecase
+            .update(
+                &user,
+                ProfileUpdate::default(),
+                user.updated_at() - chrono::Duration::seconds(1),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::Outdated))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_posting_preferences_keep_omitted_fields_positive() {
+        let (usecase, user) = setup();
+        usecase
+            .update_posting_preferences(
+                &user,
+                PostingPreferencesUpdate {
+                    default_visibility: Some(Visibility::Unlisted),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        usecase
+            .update_posting_preferences(
+                &user,
+                PostingPreferencesUpdate {
+                    default_sensitive: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let preferences = usecase.posting_preferences(&user).await.unwrap();
+        assert_eq!(Visibility::Unlisted, preferences.default_visibility);
+        assert!(preferences.default_sensitive);
+        assert!(!preferences.mark_media_sensitive);
+    }
+}
@@ -1,64 +1,554 @@
-use crate::{
-    domain::{
-        error::DomainError,
-        models::user::ActivityId,
-        repositories::user_registration_repository::UserRegistrationRepository,
-        services::{password_service::PasswordHasher, token_service::TokenGenerator},
+use tracing::instrument;
+
+use crate::domain::{
+    error::DomainError,
+    models::{
+        audit_log::{AuditLogEntry, AuditTarget},
+        email::EmailAddress,
+        email_verification::EmailVerification,
+        legal::{LegalAcceptance, LegalDocumentKind},
+        user::{ActivityId, Role, User, Username},
+    },
+    repositories::{
+        audit_log_repository::AuditLogRepository,
+        email_verification_repository::EmailVerificationRepository,
+        legal_document_repository::LegalDocumentRepository,
+        unit_of_work::{Transaction, UnitOfWork},
+        user_registration_repository::UserRegistrationRepository,
+    },
+    services::{
+        email_service::{EmailSender, EmailTemplate, MailDomainVerifier},
+        password_service::PasswordHasher,
+        spam_service::{RegistrationSubmission, SpamChecker, SpamVerdict},
     },
-    usecase::login_usecase::LoginResult,
 };
 
-pub struct RegisterUserUsecase<R: UserRegistrationRepository, P: PasswordHasher, T: TokenGenerator> {
-    registration_repository: R,
+/// Result of a registration, no token is issued until the address is verified
+pub enum RegistrationOutcome {
+    /// The account can log in once the link sent by email is opened
+    PendingVerification(User),
+    /// The account is also disabled until a moderator approves it
+    PendingApproval(User),
+}
+
+/// The account, its agreements and the spam review are written in one transaction
+pub struct RegisterUserUsecase<
+    W: UnitOfWork,
+    P: PasswordHasher,
+    E: EmailSender,
+    S: SpamChecker,
+    D: LegalDocumentRepository,
+    M: MailDomainVerifier,
+> {
+    unit_of_work: W,
     password_hasher: P,
-    token_generator: T,
+    email_sender: E,
+    spam_checker: S,
+    legal_document_repository: D,
+    mail_domain_verifier: M,
+    /// Host the activity ids and confirmation links of new accounts are minted under
+    instance_host: String,
 }
 
-impl<R: UserRegistrationRepository, P: PasswordHasher, T: TokenGenerator>
-    RegisterUserUsecase<R, P, T>
+impl<
+    W: UnitOfWork,
+    P: PasswordHasher,
+    E: EmailSender,
+    S: SpamChecker,
+    D: LegalDocumentRepository,
+    M: MailDomainVerifier,
+> RegisterUserUsecase<W, P, E, S, D, M>
 {
     pub fn new(
-        registration_repository: R,
+        unit_of_work: W,
         password_hasher: P,
-        token_generator: T,
+        email_sender: E,
+        spam_checker: S,
+        legal_document_repository: D,
+        mail_domain_verifier: M,
+        instance_host: String,
     ) -> Self {
         Self {
-            registration_repository,
+            unit_of_work,
             password_hasher,
-            token_generator,
+            email_sender,
+            spam_checker,
+            legal_document_repository,
+            mail_domain_verifier,
+            instance_host,
         }
     }
 
+    /// `agreement` tells the user accepted the current legal documents, required once any is published
+    /// `requires_approval` holds the account for review regardless of the spam checks
+    #[instrument(skip(self, password, email))]
     pub async fn create_user(
         &self,
         user_id: String,
         display_name: String,
         password: String,
         email: String,
-    ) -> Result<LoginResult, DomainError>
+        agreement: bool,
+        requires_approval: bool,
+    ) -> Result<RegistrationOutcome, DomainError>
     where
-        R: Send + Sync,
+        W: Send + Sync,
         P: Send + Sync,
-        T: Send + Sync,
+        D: Send + Sync,
     {
+        let mut documents = Vec::new();
+        for kind in LegalDocumentKind::ALL {
+            documents.extend(self.legal_document_repository.current(kind).await?);
+        }
+        if !documents.is_empty() && !agreement {
+            return Err(DomainError::AgreementRequired);
+        }
+
         // Generate ActivityId from username
-        let instance_host = std::env::var("INSTANCE_HOST")
-            .unwrap_or_else(|_| "example.com".to_string());
-        let activity_id_str = format!("https://{}/users/{}", instance_host, user_id);
+        let username = Username::new(&user_id)?;
+        let activity_id_str = format!("https://{}/users/{}", self.instance_host, username.as_str());
         let activity_id = ActivityId::new(activity_id_str)?;
 
+        let email = EmailAddress::new(email)?;
+        if !self.mail_domain_verifier.accepts_mail(email.domain()).await {
+            return Err(DomainError::InvalidEmail);
+        }
+
         // Hash password
         let password_hash = self.password_hasher.hash(&password)?;
 
-        // Register user with credentials atomically
-        let user = self
-            .registration_repository
-            .register_user_with_credentials(&activity_id, &display_name, password_hash, email)
+        // Suspicious sign-ups are kept for moderators rather than rejected
+        let verdict = self
+            .spam_checker
+            .check_registration(&RegistrationSubmission {
+                display_name: &display_name,
+                email: email.as_str(),
+            });
+        let held = requires_approval || matches!(verdict, SpamVerdict::Hold(_));
+
+        // rolled back on any error below, so a failed sign-up leaves nothing behind
+        let transaction = self.unit_of_work.begin().await?;
+        let user = transaction
+            .registrations()
+            .register_user_with_credentials(
+                &activity_id,
+                &display_name,
+                Role::User,
+                held,
+                password_hash,
+                email.clone(),
+            )
+            .await?;
+        let (verification, token) = EmailVerification::issue(user.id());
+        transaction
+            .email_verifications()
+            .create(&verification)
             .await?;
 
-        // Generate token
-        let token = self.token_generator.generate(&user)?;
+        // record which versions were agreed to, newer ones are prompted for later
+        for document in &documents {
+            transaction
+                .legal_documents()
+                .accept(&LegalAcceptance::of(user.id(), document))
+                .await?;
+        }
+
+        let review = match verdict {
+            SpamVerdict::Clean => None,
+            SpamVerdict::Flag(reason) => Some(("flag", reason)),
+            SpamVerdict::Hold(reason) => Some(("hold", reason)),
+        };
+        if let Some((verb, reason)) = review {
+            tracing::warn!(user_id = %user.id(), %reason, verb, "registration caught by spam check");
+            transaction
+                .audit_log()
+                .append(&AuditLogEntry::new(
+                    None,
+                    verb,
+                    AuditTarget::Account(user.id()),
+                    Some(reason),
+                ))
+                .await?;
+        }
+
+        transaction.commit().await?;
+
+        // queued, a delivery failure is only logged
+        let confirmation = EmailTemplate::Confirmation {
+            display_name: user.display_name().to_string(),
+            confirm_url: format!(
+                "https://{}/api/verify_email?token={}",
+                self.instance_host, token
+            ),
+        };
+        if let Err(e) = self
+            .email_sender
+            .send(confirmation.to_message(email.as_str()))
+            .await
+        {
+            tracing::warn!(error = %e, "failed to queue email confirmation");
+        }
+
+        if held {
+            return Ok(RegistrationOutcome::PendingApproval(user));
+        }
+        Ok(RegistrationOutcome::PendingVerification(user))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            error::RepositoryError,
+            models::{fixtures::TEST_HOST, legal::LegalDocument},
+        },
+        infrastructure::{
+            argon2_password_hasher::Argon2PasswordHasher,
+            in_memory::{
+                audit_log_repository::InMemoryAuditLogRepository,
+                legal_document_repository::InMemoryLegalDocumentRepository,
+                unit_of_work::InMemoryUnitOfWork,
+            },
+            log_email_sender::LogEmailSender,
+            mail_domain_verifier::AnyMailDomain,
+            spam_checker::{EmailDomainChecker, SpamPipeline},
+        },
+    };
+
+    type TestRegisterUserUsecase = RegisterUserUsecase<
+        InMemoryUnitOfWork,
+        Argon2PasswordHasher,
+        LogEmailSender,
+        SpamPipeline,
+        InMemoryLegalDocumentRepository,
+        AnyMailDomain,
+    >;
+
+    fn setup() -> TestRegisterUserUsecase {
+        setup_with_spam_checker(SpamPipeline::new()).0
+    }
+
+    /// # Description
+    /// build the usecase with the given spam checks, returning the audit log to inspect reviews
+    fn setup_with_spam_checker(
+        spam_checker: SpamPipeline,
+    ) -> (TestRegisterUserUsecase, InMemoryAuditLogRepository) {
+        let unit_of_work = InMemoryUnitOfWork::default();
+        let audit_log_repository = unit_of_work.audit_log.clone();
+        let usecase = RegisterUserUsecase::new(
+            unit_of_work.clone(),
+            Argon2PasswordHasher::new(),
+            LogEmailSender,
+            spam_checker,
+            unit_of_work.legal_documents,
+            AnyMailDomain,
+            TEST_HOST.to_string(),
+        );
+        (usecase, audit_log_repository)
+    }
+
+    #[tokio::test]
+    async fn test_create_user_positive() {
+        let usecase = setup();
+
+        let result = usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@example.com".to_string(),
+                false,
+                false,
+            )
+            .await
+            .unwrap();
+
+        let RegistrationOutcome::PendingVerification(user) = result else {
+            panic!("account should wait for verification");
+        };
+        assert!(user.activity_id().as_str().ends_with("/users/new_user"));
+        assert_eq!("テスト", user.display_name());
+    }
+
+    #[tokio::test]
+    async fn test_create_user_pending_verification_positive() {
+        let unit_of_work = InMemoryUnitOfWork::default();
+        let usecase = RegisterUserUsecase::new(
+            unit_of_work.clone(),
+            Argon2PasswordHasher::new(),
+            LogEmailSender,
+            SpamPipeline::new(),
+            unit_of_work.legal_documents.clone(),
+            AnyMailDomain,
+            TEST_HOST.to_string(),
+        );
+
+        let result = usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@example.com".to_string(),
+                false,
+                false,
+            )
+            .await
+            .unwrap();
+
+        let RegistrationOutcome::PendingVerification(user) = result else {
+            panic!("account should wait for verification");
+        };
+        assert!(
+            unit_of_work
+                .email_verifications
+                .is_pending(user.id())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_user_duplicated_email_negative() {
+        let usecase = setup();
+        usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@example.com".to_string(),
+                false,
+                false,
+            )
+            .await
+            .unwrap();
+
+        let result = usecase
+            .create_user(
+                "other_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@example.com".to_string(),
+                false,
+                false,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::Conflict {
+                field: "email"
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_user_username_differing_in_case_negative() {
+        let usecase = setup();
+        usecase
+            .create_user(
+                "alice".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "alice@example.com".to_string(),
+                false,
+                false,
+            )
+            .await
+            .unwrap();
+
+        let result = usecase
+            .create_user(
+                "Alice".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "other@example.com".to_string(),
+                false,
+                false,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::Conflict {
+                field: "username"
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_user_invalid_email_negative() {
+        let usecase = setup();
+
+        let result = usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "not-an-email".to_string(),
+                false,
+                false,
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidEmail)));
+    }
+
+    #[tokio::test]
+    async fn test_create_user_invalid_username_negative() {
+        let usecase = setup();
+
+        let result = usecase
+            .create_user(
+                "new_user/admin".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@example.com".to_string(),
+                false,
+                false,
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidUsername)));
+    }
+
+    #[tokio::test]
+    async fn test_create_user_weak_password_negative() {
+        let usecase = setup();
+
+        let result = usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "short".to_string(),
+                "new@example.com".to_string(),
+                false,
+                false,
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::WeakPassword)));
+    }
+
+    #[tokio::test]
+    async fn test_create_user_requires_approval_positive() {
+        let usecase = setup();
+
+        let result = usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@example.com".to_string(),
+                false,
+                true,
+            )
+            .await
+            .unwrap();
+
+        let RegistrationOutcome::PendingApproval(user) = result else {
+            panic!("account should wait for approval");
+        };
+        assert!(!user.moderation().can_login());
+    }
+
+    #[tokio::test]
+    async fn test_create_user_held_by_spam_check_negative() {
+        let (usecase, audit_log_repository) = setup_with_spam_checker(
+            SpamPipeline::new().with(EmailDomainChecker::new(vec!["spam.example".to_string()])),
+        );
+
+        let result = usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@spam.example".to_string(),
+                false,
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(result, RegistrationOutcome::PendingApproval(_)));
+        assert_eq!(vec!["account.hold"], audit_log_repository.actions());
+    }
+
+    #[tokio::test]
+    async fn test_create_user_records_agreement_positive() {
+        let legal_document_repository = InMemoryLegalDocumentRepository::new();
+        let terms =
+            LegalDocument::publish(LegalDocumentKind::TermsOfService, "v1".to_string(), None)
+                .unwrap();
+        legal_document_repository.publish(&terms).await.unwrap();
+        let usecase = RegisterUserUsecase::new(
+            InMemoryUnitOfWork {
+                legal_documents: legal_document_repository.clone(),
+                ..Default::default()
+            },
+            Argon2PasswordHasher::new(),
+            LogEmailSender,
+            SpamPipeline::new(),
+            legal_document_repository.clone(),
+            AnyMailDomain,
+            TEST_HOST.to_string(),
+        );
+
+        let result = usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@example.com".to_string(),
+                true,
+                false,
+            )
+            .await
+            .unwrap();
+
+        let RegistrationOutcome::PendingVerification(user) = result else {
+            panic!("account should wait for verification");
+        };
+        let acceptances = legal_document_repository
+            .acceptances(user.id())
+            .await
+            .unwrap();
+        assert_eq!(1, acceptances.len());
+        assert_eq!(terms.version, acceptances[0].version);
+    }
+
+    #[tokio::test]
+    async fn test_create_user_without_agreement_negative() {
+        let legal_document_repository = InMemoryLegalDocumentRepository::new();
+        let terms =
+            LegalDocument::publish(LegalDocumentKind::TermsOfService, "v1".to_string(), None)
+                .unwrap();
+        legal_document_repository.publish(&terms).await.unwrap();
+        let usecase = RegisterUserUsecase::new(
+            InMemoryUnitOfWork {
+                legal_documents: legal_document_repository.clone(),
+                ..Default::default()
+            },
+            Argon2PasswordHasher::new(),
+            LogEmailSender,
+            SpamPipeline::new(),
+            legal_document_repository,
+            AnyMailDomain,
+            TEST_HOST.to_string(),
+        );
+
+        let result = usecase
+            .create_user(
+                "new_user".to_string(),
+                "テスト".to_string(),
+                "new_password".to_string(),
+                "new@example.com".to_string(),
+                false,
+                false,
+            )
+            .await;
 
-        Ok(LoginResult { token, user })
+        assert!(matches!(result, Err(DomainError::AgreementRequired)));
     }
 }
@@ -0,0 +1,335 @@
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::{DomainError, RepositoryError},
+    models::{
+        audit_log::{AuditLogEntry, AuditTarget},
+        report::{Report, ReportCategory, ReportNote},
+        user::{User, UserId},
+    },
+    repositories::{
+        audit_log_repository::AuditLogRepository, report_repository::ReportRepository,
+        rule_repository::RuleRepository, user_repository::UserRepository,
+    },
+};
+
+/// Assignment change requested by a moderator
+#[derive(Debug, Clone, Copy)]
+pub enum Assignment {
+    ToSelf,
+    Unassigned,
+}
+
+/// Users file reports, moderators (and admins) triage them
+/// Every moderator action is recorded in the audit log
+pub struct ReportUsecase<
+    R: ReportRepository,
+    U: UserRepository,
+    A: AuditLogRepository,
+    L: RuleRepository,
+> {
+    report_repository: R,
+    user_repository: U,
+    audit_log_repository: A,
+    rule_repository: L,
+}
+
+impl<
+    R: ReportRepository + Send + Sync,
+    U: UserRepository + Send + Sync,
+    A: AuditLogRepository + Send + Sync,
+    L: RuleRepository + Send + Sync,
+> ReportUsecase<R, U, A, L>
+{
+    pub fn new(
+        report_repository: R,
+        user_repository: U,
+        audit_log_repository: A,
+        rule_repository: L,
+    ) -> Self {
+        Self {
+            report_repository,
+            user_repository,
+            audit_log_repository,
+            rule_repository,
+        }
+    }
+
+    /// File a report against an account, citing the violated rules if any
+    #[instrument(skip(self, reporter, comment), fields(reporter_id = %reporter.id()))]
+    pub async fn create(
+        &self,
+        reporter: &User,
+        target_account_id: UserId,
+        category: ReportCategory,
+        comment: String,
+        rule_ids: Vec<Uuid>,
+    ) -> Result<Report, DomainError> {
+        self.user_repository
+            .find_by_id(target_account_id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+
+        if !rule_ids.is_empty() {
+            let rules = self.rule_repository.list().await?;
+            if !rule_ids
+                .iter()
+                .all(|id| rules.iter().any(|rule| rule.id == *id))
+            {
+                return Err(DomainError::InvalidReport);
+            }
+        }
+
+        let report = Report::new(reporter.id(), target_account_id, category, comment, rule_ids)?;
+        self.report_repository.create(&report).await?;
+
+        Ok(report)
+    }
+
+    #[instrument(skip(self, moderator), fields(moderator_id = %moderator.id()))]
+    pub async fn list(
+        &self,
+        moderator: &User,
+        resolved: Option<bool>,
+    ) -> Result<Vec<Report>, DomainError> {
+        moderator.ensure_staff()?;
+        Ok(self.report_repository.list(resolved).await?)
+    }
+
+    /// A report with its notes
+    #[instrument(skip(self, moderator), fields(moderator_id = %moderator.id()))]
+    pub async fn get(
+        &self,
+        moderator: &User,
+        id: Uuid,
+    ) -> Result<(Report, Vec<ReportNote>), DomainError> {
+        moderator.ensure_staff()?;
+        let report = self.find(id).await?;
+        let notes = self.report_repository.notes(id).await?;
+        Ok((report, notes))
+    }
+
+    #[instrument(skip(self, moderator), fields(moderator_id = %moderator.id()))]
+    pub async fn assign(
+        &self,
+        moderator: &User,
+        id: Uuid,
+        assignment: Assignment,
+    ) -> Result<Report, DomainError> {
+        moderator.ensure_staff()?;
+        let mut report = self.find(id).await?;
+        let verb = match assignment {
+            Assignment::ToSelf => {
+                report.assign(Some(moderator.id()));
+                "assign_to_self"
+            }
+            Assignment::Unassigned => {
+                report.assign(None);
+                "unassign"
+            }
+        };
+        self.report_repository.update(&report).await?;
+        self.record(moderator, verb, report.id).await?;
+        Ok(report)
+    }
+
+    #[instrument(skip(self, moderator), fields(moderator_id = %moderator.id()))]
+    pub async fn resolve(&self, moderator: &User, id: Uuid) -> Result<Report, DomainError> {
+        moderator.ensure_staff()?;
+        let mut report = self.find(id).await?;
+        report.resolve(moderator.id());
+        self.report_repository.update(&report).await?;
+        self.record(moderator, "resolve", report.id).await?;
+        Ok(report)
+    }
+
+    #[instrument(skip(self, moderator), fields(moderator_id = %moderator.id()))]
+    pub async fn reopen(&self, moderator: &User, id: Uuid) -> Result<Report, DomainError> {
+        moderator.ensure_staff()?;
+        let mut report = self.find(id).await?;
+        report.reopen();
+        self.report_repository.update(&report).await?;
+        self.record(moderator, "reopen", report.id).await?;
+        Ok(report)
+    }
+
+    #[instrument(skip(self, moderator, content), fields(moderator_id = %moderator.id()))]
+    pub async fn add_note(
+        &self,
+        moderator: &User,
+        id: Uuid,
+        content: String,
+    ) -> Result<ReportNote, DomainError> {
+        moderator.ensure_staff()?;
+        self.find(id).await?;
+
+        let note = ReportNote::new(id, moderator.id(), content)?;
+        self.report_repository.add_note(&note).await?;
+        self.record(moderator, "add_note", id).await?;
+        Ok(note)
+    }
+
+    async fn record(&self, moderator: &User, verb: &str, report_id: Uuid) -> Result<(), DomainError> {
+        let entry =
+            AuditLogEntry::new(Some(moderator.id()), verb, AuditTarget::Report(report_id), None);
+        Ok(self.audit_log_repository.append(&entry).await?)
+    }
+
+    async fn find(&self, id: Uuid) -> Result<Report, DomainError> {
+        self.report_repository
+            .find_by_id(id)
+            .await?
+            .ok_or(DomainError::Repository(RepositoryError::NotFound))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::{
+            fixtures::{TEST_HOST, UserBuilder, activity_id},
+            user::Role,
+        },
+        infrastructure::in_memory::{
+            audit_log_repository::InMemoryAuditLogRepository,
+            report_repository::InMemoryReportRepository, rule_repository::InMemoryRuleRepository,
+            user_repository::InMemoryUserRepository,
+        },
+    };
+
+    type TestReportUsecase = ReportUsecase<
+        InMemoryReportRepository,
+        InMemoryUserRepository,
+        InMemoryAuditLogRepository,
+        InMemoryRuleRepository,
+    >;
+
+    /// # Description
+    /// Usecase over in-memory repositories holding a reporter, a target and a moderator
+    fn setup() -> (TestReportUsecase, User, User, User) {
+        let user_repository = InMemoryUserRepository::new();
+        let reporter = UserBuilder::default().build();
+        let target = UserBuilder::default()
+            .activity_id(activity_id(TEST_HOST, "spammer"))
+            .build();
+        let moderator = UserBuilder::default()
+            .activity_id(activity_id(TEST_HOST, "moderator"))
+            .build()
+            .with_role(Role::Moderator);
+        for user in [&reporter, &target, &moderator] {
+            user_repository.insert(user.clone()).unwrap();
+        }
+
+        let usecase = ReportUsecase::new(
+            InMemoryReportRepository::new(),
+            user_repository,
+            InMemoryAuditLogRepository::new(),
+            InMemoryRuleRepository::new(),
+        );
+        (usecase, reporter, target, moderator)
+    }
+
+    #[tokio::test]
+    async fn test_create_report_positive() {
+        let (usecase, reporter, target, moderator) = setup();
+
+        let report = usecase
+            .create(
+                &reporter,
+                target.id(),
+                ReportCategory::Spam,
+                "spam".to_string(),
+                vec![],
+            )
+            .await
+            .unwrap();
+
+        let open = usecase.list(&moderator, Some(false)).await.unwrap();
+        assert_eq!(vec![report.id], open.iter().map(|r| r.id).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_create_report_self_negative() {
+        let (usecase, reporter, _, _) = setup();
+
+        let result = usecase
+            .create(&reporter, reporter.id(), ReportCategory::Other, String::new(), vec![])
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidReport)));
+    }
+
+    #[tokio::test]
+    async fn test_create_report_unknown_account_negative() {
+        let (usecase, reporter, _, _) = setup();
+
+        let result = usecase
+            .create(
+                &reporter,
+                UserId::new(Uuid::new_v4()),
+                ReportCategory::Other,
+                String::new(),
+                vec![],
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Repository(RepositoryError::NotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_triage_report_positive() {
+        let (usecase, reporter, target, moderator) = setup();
+        let report = usecase
+            .create(&reporter, target.id(), ReportCategory::Spam, String::new(), vec![])
+            .await
+            .unwrap();
+
+        let assigned = usecase
+            .assign(&moderator, report.id, Assignment::ToSelf)
+            .await
+            .unwrap();
+        assert_eq!(Some(moderator.id()), assigned.assigned_account_id);
+
+        usecase
+            .add_note(&moderator, report.id, "checked the profile".to_string())
+            .await
+            .unwrap();
+        let resolved = usecase.resolve(&moderator, report.id).await.unwrap();
+        assert_eq!(Some(moderator.id()), resolved.action_taken_by_id);
+
+        let (report, notes) = usecase.get(&moderator, report.id).await.unwrap();
+        assert!(report.is_resolved());
+        assert_eq!(1, notes.len());
+    }
+
+    #[tokio::test]
+    async fn test_create_report_unknown_rule_negative() {
+        let (usecase, reporter, target, _) = setup();
+
+        let result = usecase
+            .create(
+                &reporter,
+                target.id(),
+                ReportCategory::Violation,
+                String::new(),
+                vec![Uuid::new_v4()],
+            )
+            .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidReport)));
+    }
+
+    #[tokio::test]
+    async fn test_list_reports_as_user_negative() {
+        let (usecase, reporter, _, _) = setup();
+
+        let result = usecase.list(&reporter, None).await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+}
@@ -0,0 +1,147 @@
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::{DomainError, RepositoryError},
+    models::{
+        audit_log::{AuditLogEntry, AuditTarget},
+        rule::Rule,
+        user::User,
+    },
+    repositories::{audit_log_repository::AuditLogRepository, rule_repository::RuleRepository},
+};
+
+/// Server rules, readable by anyone and managed by admins
+pub struct RuleUsecase<L: RuleRepository, A: AuditLogRepository> {
+    rule_repository: L,
+    audit_log_repository: A,
+}
+
+impl<L: RuleRepository + Send + Sync, A: AuditLogRepository + Send + Sync> RuleUsecase<L, A> {
+    pub fn new(rule_repository: L, audit_log_repository: A) -> Self {
+        Self {
+            rule_repository,
+            audit_log_repository,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn list(&self) -> Result<Vec<Rule>, DomainError> {
+        Ok(self.rule_repository.list().await?)
+    }
+
+    #[instrument(skip(self, admin, text, hint), fields(admin_id = %admin.id()))]
+    pub async fn create(
+        &self,
+        admin: &User,
+        text: String,
+        hint: String,
+        position: i32,
+    ) -> Result<Rule, DomainError> {
+        admin.ensure_admin()?;
+
+        let rule = Rule::new(text, hint, position)?;
+        self.rule_repository.create(&rule).await?;
+        self.record(admin, "create", rule.id).await?;
+
+        Ok(rule)
+    }
+
+    #[instrument(skip(self, admin, text, hint), fields(admin_id = %admin.id()))]
+    pub async fn update(
+        &self,
+        admin: &User,
+        id: Uuid,
+        text: String,
+        hint: String,
+        position: i32,
+    ) -> Result<Rule, DomainError> {
+        admin.ensure_admin()?;
+
+        let mut rule = self
+            .rule_repository
+            .find_by_id(id)
+            .await?
+            .ok_or(RepositoryError::NotFound)?;
+        rule.update(text, hint, position)?;
+        self.rule_repository.update(&rule).await?;
+        self.record(admin, "update", rule.id).await?;
+
+        Ok(rule)
+    }
+
+    #[instrument(skip(self, admin), fields(admin_id = %admin.id()))]
+    pub async fn delete(&self, admin: &User, id: Uuid) -> Result<(), DomainError> {
+        admin.ensure_admin()?;
+
+        self.rule_repository.delete(id).await?;
+        self.record(admin, "delete", id).await
+    }
+
+    async fn record(&self, admin: &User, verb: &str, rule_id: Uuid) -> Result<(), DomainError> {
+        let entry = AuditLogEntry::new(Some(admin.id()), verb, AuditTarget::Rule(rule_id), None);
+        Ok(self.audit_log_repository.append(&entry).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::models::{fixtures::UserBuilder, user::Role},
+        infrastructure::in_memory::{
+            audit_log_repository::InMemoryAuditLogRepository,
+            rule_repository::InMemoryRuleRepository,
+        },
+    };
+
+    fn setup() -> RuleUsecase<InMemoryRuleRepository, InMemoryAuditLogRepository> {
+        RuleUsecase::new(InMemoryRuleRepository::new(), InMemoryAuditLogRepository::new())
+    }
+
+    #[tokio::test]
+    async fn test_rules_are_listed_by_position_positive() {
+        let usecase = setup();
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+
+        usecase
+            .create(&admin, "No spam".to_string(), String::new(), 2)
+            .await
+            .unwrap();
+        usecase
+            .create(&admin, "Be kind".to_string(), String::new(), 1)
+            .await
+            .unwrap();
+
+        let texts: Vec<String> = usecase
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|rule| rule.text)
+            .collect();
+        assert_eq!(vec!["Be kind", "No spam"], texts);
+    }
+
+    #[tokio::test]
+    async fn test_create_rule_as_moderator_negative() {
+        let usecase = setup();
+        let moderator = UserBuilder::default().build().with_role(Role::Moderator);
+
+        let result = usecase
+            .create(&moderator, "No spam".to_string(), String::new(), 1)
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn test_create_empty_rule_negative() {
+        let usecase = setup();
+        let admin = UserBuilder::default().build().with_role(Role::Admin);
+
+        let result = usecase.create(&admin, " ".to_string(), String::new(), 1).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidRule)));
+    }
+}
@@ -0,0 +1,210 @@
+//! Router and database private to one integration test
+//!
+//! `TestApp::spawn` connects to `TEST_DATABASE_URL`, runs the migrations, seeds the test account
+//! and builds the router over the database. On Postgres every app gets its own schema,
+//! dropped with the app; an in-memory SQLite database is private to its test already.
+
+use argon2::{
+    Argon2,
+    password_hash::{PasswordHasher, SaltString, rand_core::OsRng},
+};
+use axum::{Router, body::Body, http::Request, response::Response};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{
+    ActiveModelTrait, ConnectOptions, ConnectionTrait, Database, DatabaseConnection, Set,
+};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use entity::{credentials, users};
+
+/// Id of the seeded account
+pub const TEST_ID: &str = "00000000-0000-0000-0000-000000000001";
+pub const TEST_USERNAME: &str = "test_user";
+pub const TEST_PASSWORD: &str = "test_password";
+pub const TEST_EMAIL: &str = "test@example.com";
+
+/// Router under test, with the database it runs on
+pub struct TestApp {
+    pub router: Router,
+    pub db: DatabaseConnection,
+    schema: Option<TestSchema>,
+}
+
+/// Postgres schema created for one app
+struct TestSchema {
+    base_url: String,
+    name: String,
+}
+
+impl TestApp {
+    /// Prepare a database for the test, then build the router over it with `build_router`
+    pub async fn spawn(build_router: impl FnOnce(DatabaseConnection) -> Router) -> Self {
+        dotenvy::from_path("../.env").unwrap();
+
+        let base_url = dotenvy::var("TEST_DATABASE_URL").unwrap();
+        let (db, schema) = if base_url.starts_with("sqlite:") {
+            (connect_in_memory().await, None)
+        } else {
+            let (db, name) = connect_test_schema(&base_url).await;
+            (db, Some(TestSchema { base_url, name }))
+        };
+
+        // Create tables in the new schema
+        Migrator::up(&db, None)
+            .await
+            .expect("Failed to run migrations");
+        seed(&db).await;
+
+        Self {
+            router: build_router(db.clone()),
+            db,
+            schema,
+        }
+    }
+
+    /// Send a request through the router
+    pub async fn request(&self, request: Request<Body>) -> Response {
+        self.router.clone().oneshot(request).await.unwrap()
+    }
+}
+
+/// The schema is dropped from a connection of its own, the pool of the app belongs to the
+/// runtime of the test, which is being torn down
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        let Some(schema) = self.schema.take() else {
+            return;
+        };
+        let cleanup = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to start cleanup runtime");
+            runtime.block_on(async {
+                let db = Database::connect(schema.base_url)
+                    .await
+                    .expect("Connection to DB failed");
+                db.execute_unprepared(&format!("DROP SCHEMA {} CASCADE", schema.name))
+                    .await
+                    .expect("Failed to drop schema");
+                let _ = db.close().await;
+            });
+        });
+        // a panic here would abort a test that is already unwinding
+        if cleanup.join().is_err() {
+            eprintln!("Failed to drop test schema");
+        }
+    }
+}
+
+/// helper function that open a private in-memory SQLite database
+async fn connect_in_memory() -> DatabaseConnection {
+    let mut opt = ConnectOptions::new("sqlite::memory:");
+    // every connection to an in-memory database gets its own empty database
+    opt.max_connections(1).sqlx_logging(true);
+
+    Database::connect(opt)
+        .await
+        .expect("Connection to DB failed")
+}
+
+/// helper function that create a unique Postgres schema
+/// and connect with it as the first entry of search_path
+async fn connect_test_schema(base_url: &str) -> (DatabaseConnection, String) {
+    // Create unique schema for this test
+    let schema_name = format!("test_{}", Uuid::new_v4().to_string().replace('-', "_"));
+
+    // Connect to create schema
+    let mut opt = ConnectOptions::new(base_url);
+    opt.max_connections(10)
+        .min_connections(1)
+        .sqlx_logging(true);
+
+    let db_init = Database::connect(opt)
+        .await
+        .expect("Connection to DB failed");
+
+    db_init
+        .execute_unprepared(&format!("CREATE SCHEMA {}", schema_name))
+        .await
+        .expect("Failed to create schema");
+    let _ = db_init.close().await;
+
+    // Reconnect with schema in search_path
+    let url_with_schema = if base_url.contains('?') {
+        format!(
+            "{}&options=-c%20search_path%3D{},public",
+            base_url, schema_name
+        )
+    } else {
+        format!(
+            "{}?options=-c%20search_path%3D{},public",
+            base_url, schema_name
+        )
+    };
+
+    let mut opt_with_schema = ConnectOptions::new(url_with_schema);
+    opt_with_schema
+        .max_connections(10)
+        .min_connections(1)
+        .sqlx_logging(true);
+
+    let db = Database::connect(opt_with_schema)
+        .await
+        .expect("Connection to DB failed");
+
+    (db, schema_name)
+}
+
+/// helper function that insert the test account, which logs in with `TEST_PASSWORD`
+async fn seed(db: &DatabaseConnection) {
+    let test_id = Uuid::parse_str(TEST_ID).unwrap();
+    let instance_host = dotenvy::var("INSTANCE_HOST").unwrap();
+    let activity_id = format!("https://{}/users/{}", instance_host, TEST_USERNAME);
+
+    // Create test user
+    let user = users::ActiveModel {
+        id: Set(test_id),
+        activity_id: Set(activity_id.clone()),
+        username: Set(Some(TEST_USERNAME.to_string())),
+        name: Set("テスト".to_string()),
+        summary: Set("".to_string()),
+        icon: Set(None),
+        header: Set(None),
+        role: Set("user".to_string()),
+        disabled: Set(false),
+        silenced_at: Set(None),
+        suspended_at: Set(None),
+        created_at: Set(chrono::Utc::now().into()),
+        updated_at: Set(chrono::Utc::now().into()),
+        deleted_at: Set(None),
+        self_deleted: Set(false),
+        verified_at: Set(None),
+        theme_color: Set(None),
+        birthday: Set(None),
+        birthday_visibility: Set("hidden".to_string()),
+        location: Set(None),
+        location_visible: Set(false),
+    };
+    user.insert(db).await.expect("Failed to seed test user");
+
+    // Create test credential, hashed like the api does (Argon2id, default parameters)
+    let salt = SaltString::generate(OsRng);
+    let password_hash = Argon2::default()
+        .hash_password(TEST_PASSWORD.as_bytes(), &salt)
+        .expect("Failed to hash test password")
+        .to_string();
+    let credential = credentials::ActiveModel {
+        user_id: Set(test_id),
+        activity_id: Set(activity_id),
+        password_hash: Set(password_hash),
+        email: Set(TEST_EMAIL.to_string()),
+        created_at: Set(chrono::Utc::now().into()),
+        updated_at: Set(chrono::Utc::now().into()),
+    };
+    credential
+        .insert(db)
+        .await
+        .expect("Failed to seed test credential");
+}